@@ -0,0 +1,182 @@
+//! Demosaicing, reconstruction of full RGB images from Bayer buffers
+
+use crate::*;
+use crate::rgb::{ RGBComponent, RGBPixelBuffer };
+use crate::rgb48::RGB48PixelBuffer;
+use crate::color::{ DigitalRGBAColor, DigitalRGBA64Color, narrow_u16 };
+use super::BayerPixelBuffer;
+
+/// Algorithm used to reconstruct the missing color samples of every pixel
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DemosaicAlgorithm {
+    /// Take the missing colors from the samples of the same 2x2 cell, fast but blocky
+    Nearest,
+    /// Average the neighbouring samples of each missing color
+    Bilinear,
+    /// Gradient corrected bilinear interpolation (Malvar, He, Cutler 2004),
+    /// sharper edges with less color fringing than `Bilinear`
+    MalvarHeCutler
+}
+
+/// Convolution kernel, offsets and weights in 1/16
+type Kernel = &'static [(isize, isize, i32)];
+
+const CROSS: Kernel = &[(-1, 0, 4), (1, 0, 4), (0, -1, 4), (0, 1, 4)];
+const DIAGONAL: Kernel = &[(-1, -1, 4), (1, -1, 4), (-1, 1, 4), (1, 1, 4)];
+const HORIZONTAL: Kernel = &[(-1, 0, 8), (1, 0, 8)];
+const VERTICAL: Kernel = &[(0, -1, 8), (0, 1, 8)];
+
+/// Malvar-He-Cutler: green at red or blue samples
+const MHC_GREEN: Kernel = &[
+    (0, 0, 8),
+    (-1, 0, 4), (1, 0, 4), (0, -1, 4), (0, 1, 4),
+    (-2, 0, -2), (2, 0, -2), (0, -2, -2), (0, 2, -2)
+];
+
+/// Malvar-He-Cutler: red or blue at green samples with the wanted color left and right
+const MHC_HORIZONTAL: Kernel = &[
+    (0, 0, 10),
+    (-1, 0, 8), (1, 0, 8),
+    (-2, 0, -2), (2, 0, -2),
+    (-1, -1, -2), (1, -1, -2), (-1, 1, -2), (1, 1, -2),
+    (0, -2, 1), (0, 2, 1)
+];
+
+/// Malvar-He-Cutler: red or blue at green samples with the wanted color above and below
+const MHC_VERTICAL: Kernel = &[
+    (0, 0, 10),
+    (0, -1, 8), (0, 1, 8),
+    (0, -2, -2), (0, 2, -2),
+    (-1, -1, -2), (1, -1, -2), (-1, 1, -2), (1, 1, -2),
+    (-2, 0, 1), (2, 0, 1)
+];
+
+/// Malvar-He-Cutler: red at blue samples or blue at red samples
+const MHC_DIAGONAL: Kernel = &[
+    (0, 0, 12),
+    (-1, -1, 4), (1, -1, 4), (-1, 1, 4), (1, 1, 4),
+    (-2, 0, -3), (2, 0, -3), (0, -2, -3), (0, 2, -3)
+];
+
+/// Mirror a coordinate at the borders, this keeps the parity and thus the
+/// color of the sample at that position
+fn mirror(value: isize, size: usize) -> usize {
+    if size < 2 {
+        return 0;
+    }
+
+    let period = 2 * (size as isize - 1);
+    let value = value.rem_euclid(period);
+
+    if value >= size as isize {
+        (period - value) as usize
+    } else {
+        value as usize
+    }
+}
+
+impl<'a> BayerPixelBuffer<'a> {
+    fn mirrored_sample(&self, x: isize, y: isize) -> i32 {
+        self.sample(mirror(x, self.width), mirror(y, self.height)) as i32
+    }
+
+    fn convolve(&self, x: usize, y: usize, kernel: Kernel) -> i32 {
+        let mut sum: i32 = 0;
+        for (dx, dy, weight) in kernel.iter() {
+            sum += weight * self.mirrored_sample(x as isize + dx, y as isize + dy);
+        }
+
+        (sum + 8) >> 4
+    }
+
+    /// Reconstruct the red, green and blue values of a pixel in 16 bit
+    fn demosaic_pixel(&self, x: usize, y: usize, algorithm: DemosaicAlgorithm) -> [u16; 3] {
+        let channel = self.get_channel(x, y);
+        let mut rgb: [i32; 3] = [0, 0, 0];
+
+        if algorithm == DemosaicAlgorithm::Nearest {
+            let (cx, cy) = (x & !1, y & !1);
+            for i in 0..4 {
+                rgb[self.pattern[i] as usize] = self.mirrored_sample((cx + i % 2) as isize, (cy + i / 2) as isize);
+            }
+            if channel == RGBComponent::Green {
+                rgb[RGBComponent::Green as usize] = self.sample(x, y) as i32;
+            } else {
+                rgb[RGBComponent::Green as usize] = self.mirrored_sample((x ^ 1) as isize, y as isize);
+            }
+
+            return [rgb[0] as u16, rgb[1] as u16, rgb[2] as u16];
+        }
+
+        let (green, diagonal, horizontal, vertical) =
+            if algorithm == DemosaicAlgorithm::MalvarHeCutler {
+                (MHC_GREEN, MHC_DIAGONAL, MHC_HORIZONTAL, MHC_VERTICAL)
+            } else {
+                (CROSS, DIAGONAL, HORIZONTAL, VERTICAL)
+            };
+
+        rgb[channel as usize] = self.sample(x, y) as i32;
+
+        if channel == RGBComponent::Green {
+            // the samples left and right have the same color in the whole line
+            let horizontal_channel = self.get_channel(x ^ 1, y);
+            let vertical_channel = self.get_channel(x, y ^ 1);
+            rgb[horizontal_channel as usize] = self.convolve(x, y, horizontal);
+            rgb[vertical_channel as usize] = self.convolve(x, y, vertical);
+        } else {
+            let other = if channel == RGBComponent::Red { RGBComponent::Blue } else { RGBComponent::Red };
+            rgb[RGBComponent::Green as usize] = self.convolve(x, y, green);
+            rgb[other as usize] = self.convolve(x, y, diagonal);
+        }
+
+        let clamp = |value: i32| value.clamp(0, 65535) as u16;
+
+        [clamp(rgb[0]), clamp(rgb[1]), clamp(rgb[2])]
+    }
+
+    /// Demosaic into an 8 bit `RGB` buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm` - the interpolation algorithm to use
+    ///
+    /// # Returns
+    ///
+    /// A new `RGBPixelBuffer` with the same dimensions
+    pub fn demosaic<'b>(&self, algorithm: DemosaicAlgorithm) -> RGBPixelBuffer<'b> {
+        let mut buffer = RGBPixelBuffer::new(self.width, self.height, None, Some("RGB"));
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let rgb = self.demosaic_pixel(x, y, algorithm);
+                buffer.set_pixel(x, y, DigitalRGBAColor {
+                    r: narrow_u16(rgb[0]), g: narrow_u16(rgb[1]), b: narrow_u16(rgb[2]), a: 255
+                }).unwrap();
+            }
+        }
+
+        buffer
+    }
+
+    /// Demosaic into a 16 bit `RGB48LE` buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm` - the interpolation algorithm to use
+    ///
+    /// # Returns
+    ///
+    /// A new `RGB48PixelBuffer` with the same dimensions
+    pub fn demosaic48<'b>(&self, algorithm: DemosaicAlgorithm) -> RGB48PixelBuffer<'b> {
+        let mut buffer = RGB48PixelBuffer::new(self.width, self.height, None, Some("RGB48LE"));
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let rgb = self.demosaic_pixel(x, y, algorithm);
+                buffer.set_pixel(x, y, DigitalRGBA64Color {
+                    r: rgb[0], g: rgb[1], b: rgb[2], a: 65535
+                }).unwrap();
+            }
+        }
+
+        buffer
+    }
+}
@@ -0,0 +1,53 @@
+//! Create iterator for BayerPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::BayerPixelBuffer;
+use crate::color::DigitalGray16Color;
+
+
+/// Pixel iterator for `BayerPixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalGray16Color>`
+#[derive(Debug, PartialEq)]
+pub struct BayerPixelIterator<'a> {
+    base: BayerPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for BayerPixelIterator<'a> {
+    type Item = Pixel<DigitalGray16Color>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for BayerPixelBuffer<'a> {
+    type Item = Pixel<DigitalGray16Color>;
+    type IntoIter = BayerPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BayerPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
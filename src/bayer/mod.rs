@@ -0,0 +1,235 @@
+//! This module describes a raw Bayer mosaic pixel buffer as delivered by image sensors,
+//! one color sample per pixel in a 2x2 pattern (`RGGB`, `BGGR`, `GRBG`, `GBRG`) with
+//! 8 or 16 bit per sample
+//!
+//! Pixels read and write the raw sample as `DigitalGray16Color`, use `demosaic` or
+//! `demosaic48` to reconstruct full RGB images.
+
+use crate::*;
+pub use crate::color::DigitalGray16Color;
+pub use crate::rgb::RGBComponent;
+use crate::color::{ widen_u8, narrow_u16 };
+
+mod demosaic;
+pub use demosaic::DemosaicAlgorithm;
+
+/// Bayer Pixel buffer, a single raw sample per pixel
+#[derive(Debug, PartialEq)]
+pub struct BayerPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    pattern: [RGBComponent; 4],
+    wide: bool,
+    data: Vec<u8>
+}
+
+impl<'a> BayerPixelBuffer<'a> {
+    /// Color of the samples in the top left 2x2 cell, row by row
+    fn decode_pattern(fourcc:&'a str) -> [RGBComponent; 4] {
+        match fourcc.trim_end_matches("16") {
+            "BGGR" => [RGBComponent::Blue, RGBComponent::Green, RGBComponent::Green, RGBComponent::Red],
+            "GRBG" => [RGBComponent::Green, RGBComponent::Red, RGBComponent::Blue, RGBComponent::Green],
+            "GBRG" => [RGBComponent::Green, RGBComponent::Blue, RGBComponent::Red, RGBComponent::Green],
+            "RGGB" | _ => [RGBComponent::Red, RGBComponent::Green, RGBComponent::Green, RGBComponent::Blue],
+        }
+    }
+
+    fn decode_wide(fourcc:&'a str) -> bool {
+        fourcc.ends_with("16")
+    }
+
+    /// Size of one sample in bytes
+    fn sample_size(&self) -> usize {
+        if self.wide { 2 } else { 1 }
+    }
+
+    /// Color of the sample at `x`, `y`
+    pub fn get_channel(&self, x: usize, y: usize) -> RGBComponent {
+        self.pattern[(y % 2) * 2 + x % 2]
+    }
+
+    /// Raw sample at `x`, `y` scaled to 16 bit, no bounds check
+    fn sample(&self, x: usize, y: usize) -> u16 {
+        let start = x * self.sample_size() + y * self.stride;
+
+        if self.wide {
+            u16::from_le_bytes([self.data[start], self.data[start + 1]])
+        } else {
+            widen_u8(self.data[start])
+        }
+    }
+}
+
+impl<'a> PixelBuffer<'a> for BayerPixelBuffer<'a> {
+    type ColorType = DigitalGray16Color;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `width` for 8 bit, `2 * width` for 16 bit samples
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGGB` (default), `BGGR`, `GRBG`, `GBRG`, 8 bit samples
+    /// * `RGGB16`, `BGGR16`, `GRBG16`, `GBRG16`, 16 bit little endian samples
+    ///
+    /// The code names the colors of the top left 2x2 cell row by row.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `BayerPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("RGGB");
+        let wide = BayerPixelBuffer::decode_wide(f);
+        let line_width = stride.unwrap_or(width * if wide { 2 } else { 1 });
+
+        BayerPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            pattern: BayerPixelBuffer::decode_pattern(f),
+            wide
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `width` for 8 bit, `2 * width` for 16 bit samples
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGGB` (default), `BGGR`, `GRBG`, `GBRG`, 8 bit samples
+    /// * `RGGB16`, `BGGR16`, `GRBG16`, `GBRG16`, 16 bit little endian samples
+    ///
+    /// The code names the colors of the top left 2x2 cell row by row.
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `BayerPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("RGGB");
+        let wide = BayerPixelBuffer::decode_wide(f);
+        let line_width = stride.unwrap_or(width * if wide { 2 } else { 1 });
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            BayerPixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                pattern: BayerPixelBuffer::decode_pattern(f),
+                wide
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `width` for 8 bit, `2 * width` for 16 bit samples
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - raw sample value to fill all pixels with
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGGB` (default), `BGGR`, `GRBG`, `GBRG`, 8 bit samples
+    /// * `RGGB16`, `BGGR16`, `GRBG16`, `GBRG16`, 16 bit little endian samples
+    ///
+    /// The code names the colors of the top left 2x2 cell row by row.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `BayerPixelBuffer` with it's contents set to the
+    /// defined sample value. If stride is bigger than needed width the padding is filled
+    /// with zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = BayerPixelBuffer::new(width, height, stride, fourcc);
+        let representation =
+            if buffer.wide {
+                color.v.to_le_bytes().to_vec()
+            } else {
+                vec![narrow_u16(color.v)]
+            };
+        let pixel_width = buffer.sample_size();
+
+        let mut line = representation.repeat(width);
+        if buffer.stride > width * pixel_width {
+            line.extend([0].repeat(buffer.stride - width * pixel_width));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * self.sample_size() + y * self.stride;
+
+        if self.wide {
+            let bytes = color.v.to_le_bytes();
+            self.data[start] = bytes[0];
+            self.data[start + 1] = bytes[1];
+        } else {
+            self.data[start] = narrow_u16(color.v);
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        Ok(DigitalGray16Color { v: self.sample(x, y) })
+    }
+}
+
+pub mod iter;
+mod tests;
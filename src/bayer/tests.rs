@@ -0,0 +1,262 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::bayer::{ BayerPixelBuffer, DemosaicAlgorithm };
+pub use crate::rgb::{ RGBComponent, RGBPixelBuffer };
+pub use crate::rgb48::RGB48PixelBuffer;
+pub use crate::color::{ DigitalGray16Color, DigitalRGBA64Color };
+pub use grapho_color::DigitalRGBAColor;
+
+/// Mosaic of a single color in the given pattern
+#[cfg(test)]
+fn flat_mosaic<'a>(width: usize, height: usize, color: DigitalRGBAColor, fourcc: &'a str) -> BayerPixelBuffer<'a> {
+    let mut buffer = BayerPixelBuffer::new(width, height, None, Some(fourcc));
+    let rgb: [u8; 4] = color.into();
+
+    for y in 0..height {
+        for x in 0..width {
+            let v = rgb[buffer.get_channel(x, y) as usize] as u16 * 257;
+            buffer.set_pixel(x, y, DigitalGray16Color{ v }).unwrap();
+        }
+    }
+
+    buffer
+}
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalGray16Color{ v: 0x1234 };
+        let buffer = BayerPixelBuffer::new_with_background(3, 2, color, None, Some("GRBG16"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod rggb {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = BayerPixelBuffer::new(4, 2, None, None);
+        assert_eq!(buffer.data.len(), 8);
+        assert_eq!(buffer.width, 4);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 4);
+        assert_eq!(buffer.get_width(), 4);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 4);
+        assert_eq!(buffer.get_fourcc(), "RGGB");
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 7];
+        match BayerPixelBuffer::new_with_data(4, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = BayerPixelBuffer::new_with_background(
+            2, 2,
+            DigitalGray16Color{ v: 0x8080 },
+            Some(3), None
+        );
+
+        assert_eq!(buffer.data, vec![0x80, 0x80, 0, 0x80, 0x80, 0]);
+    }
+
+    #[test]
+    fn channels() {
+        let buffer = BayerPixelBuffer::new(2, 2, None, Some("RGGB"));
+        assert_eq!(buffer.get_channel(0, 0), RGBComponent::Red);
+        assert_eq!(buffer.get_channel(1, 0), RGBComponent::Green);
+        assert_eq!(buffer.get_channel(0, 1), RGBComponent::Green);
+        assert_eq!(buffer.get_channel(3, 3), RGBComponent::Blue);
+
+        let buffer = BayerPixelBuffer::new(2, 2, None, Some("GBRG16"));
+        assert_eq!(buffer.get_channel(1, 0), RGBComponent::Blue);
+        assert_eq!(buffer.get_channel(0, 1), RGBComponent::Red);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = BayerPixelBuffer::new(2, 2, None, None);
+
+        match buffer.set_pixel(1, 1, DigitalGray16Color{ v: 0x4040 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalGray16Color{ v: 0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data, vec![0, 0, 0, 0x40]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![1, 2, 3, 255];
+        let buffer = BayerPixelBuffer::new_with_data(2, 2, data, None, None).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGray16Color{ v: 257 });
+        assert_eq!(buffer.get_pixel(1, 1).unwrap(), DigitalGray16Color{ v: 65535 });
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod rggb16 {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = BayerPixelBuffer::new(2, 1, None, Some("RGGB16"));
+        assert_eq!(buffer.get_stride(), 4);
+        buffer.set_pixel(1, 0, DigitalGray16Color{ v: 0x1234 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0, 0, 0x34, 0x12]);
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalGray16Color{ v: 0x1234 });
+    }
+}
+
+#[cfg(test)]
+mod demosaic {
+    use super::*;
+
+    const COLOR: DigitalRGBAColor = DigitalRGBAColor{ r: 200, g: 100, b: 50, a: 255 };
+
+    #[test]
+    fn flat_color() {
+        for fourcc in ["RGGB", "BGGR", "GRBG", "GBRG"].iter() {
+            let mosaic = flat_mosaic(5, 5, COLOR, fourcc);
+
+            for algorithm in [DemosaicAlgorithm::Nearest, DemosaicAlgorithm::Bilinear, DemosaicAlgorithm::MalvarHeCutler].iter() {
+                for (x, y, color) in mosaic.demosaic(*algorithm) {
+                    assert_eq!(color, COLOR, "{} {:?} x: {}, y: {}", fourcc, algorithm, x, y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nearest() {
+        let data = vec![
+            10, 20, 30, 40,
+            50, 60, 70, 80,
+        ];
+        let mosaic = BayerPixelBuffer::new_with_data(4, 2, data, None, Some("RGGB")).unwrap();
+        let rgb = mosaic.demosaic(DemosaicAlgorithm::Nearest);
+
+        assert_eq!(rgb.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 10, g: 20, b: 60, a: 255 });
+        assert_eq!(rgb.get_pixel(1, 0).unwrap(), DigitalRGBAColor{ r: 10, g: 20, b: 60, a: 255 });
+        assert_eq!(rgb.get_pixel(0, 1).unwrap(), DigitalRGBAColor{ r: 10, g: 50, b: 60, a: 255 });
+        assert_eq!(rgb.get_pixel(3, 1).unwrap(), DigitalRGBAColor{ r: 30, g: 70, b: 80, a: 255 });
+    }
+
+    #[test]
+    fn bilinear() {
+        let data = vec![
+            0, 10, 0, 30,
+            20, 100, 40, 200,
+            0, 50, 0, 70,
+            60, 100, 80, 100,
+        ];
+        let mosaic = BayerPixelBuffer::new_with_data(4, 4, data, None, Some("RGGB")).unwrap();
+        let rgb = mosaic.demosaic(DemosaicAlgorithm::Bilinear);
+
+        // red sample: green from the cross, blue from the diagonals
+        assert_eq!(rgb.get_pixel(2, 2).unwrap(), DigitalRGBAColor{ r: 0, g: 60, b: 125, a: 255 });
+        // green sample on a red line: red from left/right, blue from above/below
+        assert_eq!(rgb.get_pixel(1, 2).unwrap(), DigitalRGBAColor{ r: 0, g: 50, b: 100, a: 255 });
+        // blue sample mirrored at the border
+        assert_eq!(rgb.get_pixel(3, 3).unwrap(), DigitalRGBAColor{ r: 0, g: 75, b: 100, a: 255 });
+    }
+
+    #[test]
+    fn malvar_he_cutler() {
+        // horizontal edge between two flat gray areas
+        let mut data = vec![40; 6 * 3];
+        data.extend(vec![200; 6 * 3]);
+        let mosaic = BayerPixelBuffer::new_with_data(6, 6, data, None, Some("RGGB")).unwrap();
+
+        let bilinear = mosaic.demosaic(DemosaicAlgorithm::Bilinear);
+        let mhc = mosaic.demosaic(DemosaicAlgorithm::MalvarHeCutler);
+
+        // away from the edge both reconstruct the flat gray
+        assert_eq!(mhc.get_pixel(2, 0).unwrap(), DigitalRGBAColor{ r: 40, g: 40, b: 40, a: 255 });
+        assert_eq!(mhc.get_pixel(3, 5).unwrap(), DigitalRGBAColor{ r: 200, g: 200, b: 200, a: 255 });
+
+        // on the edge the gradient correction reduces the color error of the green sample
+        let color = bilinear.get_pixel(1, 2).unwrap();
+        let bilinear_error = (color.b as i32 - color.g as i32).abs();
+        let color = mhc.get_pixel(1, 2).unwrap();
+        let mhc_error = (color.b as i32 - color.g as i32).abs();
+        assert!(mhc_error < bilinear_error, "{} < {}", mhc_error, bilinear_error);
+    }
+
+    #[test]
+    fn demosaic48() {
+        let mosaic = BayerPixelBuffer::new_with_background(2, 2, DigitalGray16Color{ v: 0x1234 }, None, Some("BGGR16"));
+        for (_x, _y, color) in mosaic.demosaic48(DemosaicAlgorithm::MalvarHeCutler) {
+            assert_eq!(color, DigitalRGBA64Color{ r: 0x1234, g: 0x1234, b: 0x1234, a: 65535 });
+        }
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            2, 2,
+            DigitalRGBAColor{ r: 10, g: 20, b: 30, a: 255 },
+            None, None
+        );
+
+        let mosaic: BayerPixelBuffer = rgb.into();
+        assert_eq!(mosaic.get_fourcc(), "RGGB");
+        assert_eq!(mosaic.data, vec![10, 20, 20, 30]);
+
+        let back: RGBPixelBuffer = mosaic.into();
+        for (_x, _y, color) in back {
+            assert_eq!(color, DigitalRGBAColor{ r: 10, g: 20, b: 30, a: 255 });
+        }
+    }
+
+    #[test]
+    fn rgb48() {
+        let rgb = RGB48PixelBuffer::new_with_background(
+            2, 2,
+            DigitalRGBA64Color{ r: 0x1000, g: 0x2000, b: 0x3000, a: 65535 },
+            None, None
+        );
+
+        let mosaic: BayerPixelBuffer = rgb.into();
+        assert_eq!(mosaic.get_fourcc(), "RGGB16");
+
+        let back: RGB48PixelBuffer = mosaic.into();
+        for (_x, _y, color) in back {
+            assert_eq!(color, DigitalRGBA64Color{ r: 0x1000, g: 0x2000, b: 0x3000, a: 65535 });
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! This module describes a packed RGB pixel buffer that is described at runtime by
+//! per-component bit masks, like BMP `BI_BITFIELDS` or DirectDraw pixel formats
+//!
+//! The format is given as the fourcc string `<bits per pixel>:<red mask>:<green mask>:<blue mask>[:<alpha mask>]`
+//! with hexadecimal masks, e.g. `16:f800:07e0:001f` for RGB565 or `32:00ff0000:0000ff00:000000ff:ff000000`
+//! for ARGB8888. Pixels are 8, 16, 24 or 32 bit little endian values.
+
+use crate::*;
+pub use grapho_color::DigitalRGBAColor;
+use crate::color::{ expand_bits, reduce_bits };
+
+/// Default format, 32 bit ARGB
+const DEFAULT_FORMAT: &str = "32:00ff0000:0000ff00:000000ff:ff000000";
+
+/// Bitmask described packed RGB Pixel buffer, with or without alpha channel
+#[derive(Debug, PartialEq)]
+pub struct BitfieldPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    bytes_per_pixel: usize,
+    masks: [u32; 4],
+    data: Vec<u8>
+}
+
+impl<'a> BitfieldPixelBuffer<'a> {
+    /// Decode bytes per pixel and the bit masks indexed by `RGBComponent`, falls back to
+    /// the default format if the description is invalid and returns the effective description
+    fn decode_format(fourcc:&'a str) -> (&'a str, usize, [u32; 4]) {
+        match BitfieldPixelBuffer::parse_format(fourcc) {
+            Some((bytes_per_pixel, masks)) => (fourcc, bytes_per_pixel, masks),
+            None => {
+                let (bytes_per_pixel, masks) = BitfieldPixelBuffer::parse_format(DEFAULT_FORMAT).unwrap();
+                (DEFAULT_FORMAT, bytes_per_pixel, masks)
+            }
+        }
+    }
+
+    fn parse_format(fourcc:&'a str) -> Option<(usize, [u32; 4])> {
+        let parts: Vec<&str> = fourcc.split(':').collect();
+        if parts.len() < 4 || parts.len() > 5 {
+            return None;
+        }
+
+        let bytes_per_pixel = match parts[0] {
+            "8" => 1,
+            "16" => 2,
+            "24" => 3,
+            "32" => 4,
+            _ => return None
+        };
+
+        let mut masks: [u32; 4] = [0, 0, 0, 0];
+        let mut used: u32 = 0;
+        for i in 1..parts.len() {
+            let mask = u32::from_str_radix(parts[i].trim_start_matches("0x"), 16).ok()?;
+            if bytes_per_pixel < 4 && mask >> (bytes_per_pixel * 8) != 0 {
+                return None;
+            }
+
+            // each mask has to be a single run of bits that does not overlap the others
+            let run = mask.checked_shr(mask.trailing_zeros()).unwrap_or(0);
+            if run & run.wrapping_add(1) != 0 || used & mask != 0 {
+                return None;
+            }
+            used |= mask;
+            masks[i - 1] = mask;
+        }
+
+        Some((bytes_per_pixel, masks))
+    }
+
+    /// Whether the buffer format stores an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        self.masks[3] != 0
+    }
+
+    fn pack(&self, color: DigitalRGBAColor) -> u32 {
+        let repr: [u8; 4] = color.into();
+        let mut packed: u32 = 0;
+
+        for i in 0..4 {
+            let mask = self.masks[i];
+            if mask != 0 {
+                packed |= (reduce_bits(repr[i], mask.count_ones()) << mask.trailing_zeros()) & mask;
+            }
+        }
+
+        packed
+    }
+
+    fn unpack(&self, packed: u32) -> DigitalRGBAColor {
+        let mut repr: [u8; 4] = [0, 0, 0, 255];
+
+        for i in 0..4 {
+            let mask = self.masks[i];
+            if mask != 0 {
+                repr[i] = expand_bits((packed & mask) >> mask.trailing_zeros(), mask.count_ones());
+            }
+        }
+
+        DigitalRGBAColor::from(repr)
+    }
+}
+
+impl<'a> PixelBuffer<'a> for BitfieldPixelBuffer<'a> {
+    type ColorType = DigitalRGBAColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<bytes per pixel> * width`
+    /// * `fourcc` - optional, format description
+    ///
+    /// # Format description
+    ///
+    /// `<bits per pixel>:<red mask>:<green mask>:<blue mask>[:<alpha mask>]`, bits per pixel is
+    /// one of `8`, `16`, `24` or `32`, masks are hexadecimal, each a single run of bits that
+    /// does not overlap the others. The default is `32:00ff0000:0000ff00:000000ff:ff000000`,
+    /// invalid descriptions fall back to the default which is then returned by `get_fourcc`.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `BitfieldPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let (f, bytes_per_pixel, masks) = BitfieldPixelBuffer::decode_format(fourcc.unwrap_or(DEFAULT_FORMAT));
+        let line_width = stride.unwrap_or(width * bytes_per_pixel);
+
+        BitfieldPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            bytes_per_pixel,
+            masks
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<bytes per pixel> * width`
+    /// * `fourcc` - optional, format description
+    /// * `data` - the data to consume
+    ///
+    /// # Format description
+    ///
+    /// `<bits per pixel>:<red mask>:<green mask>:<blue mask>[:<alpha mask>]`, bits per pixel is
+    /// one of `8`, `16`, `24` or `32`, masks are hexadecimal, each a single run of bits that
+    /// does not overlap the others. The default is `32:00ff0000:0000ff00:000000ff:ff000000`.
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `BitfieldPixelBuffer`,
+    /// `PixelBufferError::InvalidFormat` if the format description is invalid or
+    /// `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or(DEFAULT_FORMAT);
+        let (bytes_per_pixel, masks) = match BitfieldPixelBuffer::parse_format(f) {
+            Some(format) => format,
+            None => return Err(PixelBufferError::InvalidFormat)
+        };
+        let line_width = stride.unwrap_or(width * bytes_per_pixel);
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            BitfieldPixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                bytes_per_pixel,
+                masks
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<bytes per pixel> * width`
+    /// * `fourcc` - optional, format description
+    /// * `color` - fill color to use
+    ///
+    /// # Format description
+    ///
+    /// `<bits per pixel>:<red mask>:<green mask>:<blue mask>[:<alpha mask>]`, bits per pixel is
+    /// one of `8`, `16`, `24` or `32`, masks are hexadecimal, each a single run of bits that
+    /// does not overlap the others. The default is `32:00ff0000:0000ff00:000000ff:ff000000`,
+    /// invalid descriptions fall back to the default which is then returned by `get_fourcc`.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `BitfieldPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = BitfieldPixelBuffer::new(width, height, stride, fourcc);
+        let pixel_width = buffer.bytes_per_pixel;
+        let representation = &buffer.pack(color).to_le_bytes()[0..pixel_width];
+
+        let mut line = representation.repeat(width);
+        if buffer.stride > width * pixel_width {
+            line.extend([0].repeat(buffer.stride - width * pixel_width));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * self.bytes_per_pixel + y * self.stride;
+        let bytes = self.pack(color).to_le_bytes();
+
+        for i in 0..self.bytes_per_pixel {
+            self.data[start + i] = bytes[i];
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * self.bytes_per_pixel + y * self.stride;
+        let mut bytes: [u8; 4] = [0, 0, 0, 0];
+        for i in 0..self.bytes_per_pixel {
+            bytes[i] = self.data[start + i];
+        }
+
+        Ok(self.unpack(u32::from_le_bytes(bytes)))
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,218 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::bitfield::BitfieldPixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalRGBAColor{ r: 255, g: 64, b: 0, a: 128 };
+        let buffer = BitfieldPixelBuffer::new_with_background(2, 2, color, None, None);
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod argb8888 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = BitfieldPixelBuffer::new(2, 2, None, None);
+        assert_eq!(buffer.data.len(), 16);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 8);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 8);
+        assert_eq!(buffer.get_fourcc(), "32:00ff0000:0000ff00:000000ff:ff000000");
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 15];
+        match BitfieldPixelBuffer::new_with_data(2, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = BitfieldPixelBuffer::new_with_background(
+            1, 2,
+            DigitalRGBAColor{ r: 1, g: 2, b: 3, a: 4 },
+            Some(6), None
+        );
+
+        assert_eq!(buffer.data, vec![3, 2, 1, 4, 0, 0, 3, 2, 1, 4, 0, 0]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = BitfieldPixelBuffer::new(2, 2, None, None);
+
+        match buffer.set_pixel(1, 1, DigitalRGBAColor{ r: 1, g: 2, b: 3, a: 4 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(0, 2, DigitalRGBAColor{ r: 1, g: 2, b: 3, a: 4 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[12..16], [3, 2, 1, 4]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![3, 2, 1, 4];
+        let buffer = BitfieldPixelBuffer::new_with_data(1, 1, data, None, None).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalRGBAColor{ r: 1, g: 2, b: 3, a: 4 })
+        }
+    }
+
+    #[test]
+    fn invalid_format() {
+        let buffer = BitfieldPixelBuffer::new(1, 1, None, Some("12:f00:0f0:00f"));
+        assert_eq!(buffer.stride, 4);
+        assert_eq!(buffer.masks, [0x00ff0000, 0x0000ff00, 0x000000ff, 0xff000000]);
+
+        let buffer = BitfieldPixelBuffer::new(1, 1, None, Some("16:1f0000:07e0:001f"));
+        assert_eq!(buffer.stride, 4);
+        assert_eq!(buffer.get_fourcc(), "32:00ff0000:0000ff00:000000ff:ff000000");
+    }
+
+    #[test]
+    fn invalid_masks() {
+        // split red mask
+        let buffer = BitfieldPixelBuffer::new(1, 1, None, Some("16:f00f:07e0:0010"));
+        assert_eq!(buffer.get_fourcc(), "32:00ff0000:0000ff00:000000ff:ff000000");
+
+        // green overlaps red
+        let buffer = BitfieldPixelBuffer::new(1, 1, None, Some("16:f800:0fe0:001f"));
+        assert_eq!(buffer.get_fourcc(), "32:00ff0000:0000ff00:000000ff:ff000000");
+
+        let buffer = BitfieldPixelBuffer::new(1, 1, None, Some("32:ffff0000:0000ff00:000000ff:ff000000"));
+        assert_eq!(buffer.masks, [0x00ff0000, 0x0000ff00, 0x000000ff, 0xff000000]);
+    }
+
+    #[test]
+    fn prefilled_buffer_invalid_format() {
+        let data = vec![0; 4];
+        match BitfieldPixelBuffer::new_with_data(2, 1, data, None, Some("12:f00:0f0:00f")) {
+            Err(error) => assert_eq!(error, PixelBufferError::InvalidFormat),
+            Ok(_buffer) => assert!(false)
+        }
+
+        let data = vec![0; 4];
+        match BitfieldPixelBuffer::new_with_data(2, 1, data, None, Some("16:f800:0fe0:001f")) {
+            Err(error) => assert_eq!(error, PixelBufferError::InvalidFormat),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod rgb565 {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = BitfieldPixelBuffer::new(1, 1, None, Some("16:f800:07e0:001f"));
+        buffer.set_pixel(0, 0, DigitalRGBAColor{ r: 255, g: 0, b: 255, a: 0 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0x1f, 0xf8]);
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 0, b: 255, a: 255 });
+    }
+}
+
+#[cfg(test)]
+mod bgr233 {
+    use super::*;
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0b10_101_001];
+        let buffer = BitfieldPixelBuffer::new_with_data(1, 1, data, None, Some("8:07:38:c0")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 36, g: 182, b: 170, a: 255 });
+    }
+}
+
+#[cfg(test)]
+mod rgb24 {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = BitfieldPixelBuffer::new(2, 1, None, Some("24:0xff0000:0x00ff00:0x0000ff"));
+        buffer.set_pixel(1, 0, DigitalRGBAColor{ r: 1, g: 2, b: 3, a: 4 }).unwrap();
+
+        assert_eq!(buffer.stride, 6);
+        assert_eq!(buffer.data, vec![0, 0, 0, 3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod a2r10g10b10 {
+    use super::*;
+
+    #[test]
+    fn get_pixel() {
+        let value: u32 = 3 << 30 | 1023 << 20 | 512 << 10 | 2;
+        let data = value.to_le_bytes().to_vec();
+        let buffer = BitfieldPixelBuffer::new_with_data(1, 1, data, None, Some("32:3ff00000:000ffc00:000003ff:c0000000")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 128, b: 0, a: 255 });
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb_roundtrip() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            2, 2,
+            DigitalRGBAColor{ r: 0x11, g: 0x22, b: 0x33, a: 0x44 },
+            None, Some("RGBA")
+        );
+
+        let bitfield: BitfieldPixelBuffer = rgb.into();
+        let back: RGBPixelBuffer = bitfield.into();
+        for (x, y, color) in back {
+            assert_eq!(color, DigitalRGBAColor{ r: 0x11, g: 0x22, b: 0x33, a: 0x44 }, "x: {}, y: {}", x, y);
+        }
+    }
+
+    #[test]
+    fn alpha_from_masks() {
+        let opaque = BitfieldPixelBuffer::new(1, 1, None, Some("32:00ff0000:0000ff00:000000ff"));
+        let alpha = BitfieldPixelBuffer::new(1, 1, None, Some("32:00ff0000:0000ff00:000000ff:ff000000"));
+        assert!(!opaque.has_alpha());
+        assert!(alpha.has_alpha());
+
+        let rgb: RGBPixelBuffer = opaque.into();
+        assert_eq!(rgb.get_fourcc(), "RGB");
+
+        let rgba: RGBPixelBuffer = alpha.into();
+        assert_eq!(rgba.get_fourcc(), "RGBA");
+    }
+}
@@ -0,0 +1,54 @@
+//! Create iterator for CMYKiPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::CMYKiPixelBuffer;
+use crate::color::DigitalCMYKColor;
+
+
+/// Pixel iterator for `CMYKiPixelBuffer`
+///
+/// Items it will generate are of type `Pixel<DigitalCMYKColor>`
+#[derive(Debug, PartialEq)]
+pub struct CMYKiPixelIterator<'a> {
+    base: CMYKiPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for CMYKiPixelIterator<'a> {
+    type Item = Pixel<DigitalCMYKColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for CMYKiPixelBuffer<'a> {
+    type Item = Pixel<DigitalCMYKColor>;
+    type IntoIter = CMYKiPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CMYKiPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! This module describes an interleaved CMYK pixel buffer with 8 bit per ink
+
+use crate::*;
+pub use crate::color::{ DigitalCMYKColor, CMYKSeparation };
+use crate::rgb::RGBPixelBuffer;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CMYKComponent {
+    Cyan    = 0,
+    Magenta = 1,
+    Yellow  = 2,
+    Black   = 3
+}
+
+/// CMYK Pixel buffer
+#[derive(Debug, PartialEq)]
+pub struct CMYKiPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: Vec<CMYKComponent>,
+    data: Vec<u8>
+}
+
+impl<'a> CMYKiPixelBuffer<'a> {
+    pub(crate) fn decode_component_order(fourcc:&'a str) -> Vec<CMYKComponent> {
+        match fourcc {
+            "KCMY" => vec![CMYKComponent::Black, CMYKComponent::Cyan, CMYKComponent::Magenta, CMYKComponent::Yellow],
+            "CMYK" | _ => vec![CMYKComponent::Cyan, CMYKComponent::Magenta, CMYKComponent::Yellow, CMYKComponent::Black],
+        }
+    }
+
+    /// Separate a RGB buffer into inks
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - the buffer to convert
+    /// * `separation` - black generation and undercolor removal to apply
+    /// * `fourcc` - optional, data representation format of the new buffer
+    ///
+    /// # Returns
+    ///
+    /// A new `CMYKiPixelBuffer` with the same dimensions
+    pub fn from_rgb(buffer: RGBPixelBuffer<'_>, separation: CMYKSeparation, fourcc: Option<&'a str>) -> Self {
+        let mut result = CMYKiPixelBuffer::new(buffer.get_width(), buffer.get_height(), None, fourcc);
+        for (x, y, color) in buffer {
+            result.set_pixel(x, y, DigitalCMYKColor::from_rgb(color, separation)).unwrap();
+        }
+
+        result
+    }
+}
+
+impl<'a> PixelBuffer<'a> for CMYKiPixelBuffer<'a> {
+    type ColorType = DigitalCMYKColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `4 * width`
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `CMYK` (default)
+    /// * `KCMY`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `CMYKiPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("CMYK");
+        let line_width = stride.unwrap_or(width * 4);
+
+        CMYKiPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            component_order: CMYKiPixelBuffer::decode_component_order(f)
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `4 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `CMYK` (default)
+    /// * `KCMY`
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `CMYKiPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("CMYK");
+        let line_width = stride.unwrap_or(width * 4);
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            CMYKiPixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                component_order: CMYKiPixelBuffer::decode_component_order(f)
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `4 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `CMYK` (default)
+    /// * `KCMY`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `CMYKiPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = CMYKiPixelBuffer::new(width, height, stride, fourcc);
+        let rep: [u8; 4] = color.into();
+
+        let representation: Vec<u8> = buffer.component_order.iter().map(|component| rep[*component as usize]).collect();
+
+        let mut line = representation.repeat(width);
+        if buffer.stride > width * 4 {
+            line.extend([0].repeat(buffer.stride - width * 4));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 4 + y * self.stride;
+        let repr: [u8; 4] = color.into();
+
+        for i in 0..self.component_order.len() {
+            self.data[start + i] = repr[self.component_order[i] as usize];
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 4 + y * self.stride;
+        let mut color: [u8; 4] = [0, 0, 0, 0];
+        for i in 0..self.component_order.len() {
+            color[self.component_order[i] as usize] = self.data[start + i];
+        }
+
+        Ok(DigitalCMYKColor::from(color))
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,145 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::cmyki::CMYKiPixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::color::{ DigitalCMYKColor, CMYKSeparation };
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 };
+        let buffer = CMYKiPixelBuffer::new_with_background(2, 2, color, None, None);
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod cmyk {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = CMYKiPixelBuffer::new(2, 2, None, None);
+        assert_eq!(buffer.data.len(), 16);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 8);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 8);
+        assert_eq!(buffer.get_fourcc(), "CMYK");
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 15];
+        match CMYKiPixelBuffer::new_with_data(2, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = CMYKiPixelBuffer::new_with_background(
+            1, 2,
+            DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 },
+            Some(5), None
+        );
+
+        assert_eq!(buffer.data, vec![1, 2, 3, 4, 0, 1, 2, 3, 4, 0]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = CMYKiPixelBuffer::new(2, 2, None, None);
+
+        match buffer.set_pixel(1, 1, DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[12..16], [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let buffer = CMYKiPixelBuffer::new_with_data(2, 1, data, None, None).unwrap();
+
+        match buffer.get_pixel(1, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalCMYKColor{ c: 5, m: 6, y: 7, k: 8 })
+        }
+
+        match buffer.get_pixel(0, 1) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod kcmy {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = CMYKiPixelBuffer::new(1, 1, None, Some("KCMY"));
+        buffer.set_pixel(0, 0, DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 }).unwrap();
+
+        assert_eq!(buffer.data, vec![4, 1, 2, 3]);
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 });
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb_roundtrip() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            2, 2,
+            DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 },
+            None, None
+        );
+
+        let cmyk: CMYKiPixelBuffer = rgb.into();
+        assert_eq!(cmyk.get_pixel(1, 1).unwrap(), DigitalCMYKColor{ c: 0, m: 128, y: 255, k: 127 });
+
+        let back: RGBPixelBuffer = cmyk.into();
+        for (_x, _y, color) in back {
+            assert_eq!(color, DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 });
+        }
+    }
+
+    #[test]
+    fn rgb_separation() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            1, 1,
+            DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 },
+            None, None
+        );
+
+        let separation = CMYKSeparation{ black_generation: 0.0, undercolor_removal: 1.0 };
+        let cmyk = CMYKiPixelBuffer::from_rgb(rgb, separation, Some("KCMY"));
+        assert_eq!(cmyk.get_fourcc(), "KCMY");
+        assert_eq!(cmyk.data, vec![0, 127, 191, 255]);
+    }
+}
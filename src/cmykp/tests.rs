@@ -0,0 +1,137 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::cmykp::CMYKpPixelBuffer;
+pub use crate::cmyki::CMYKiPixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::color::{ DigitalCMYKColor, CMYKSeparation };
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 };
+        let buffer = CMYKpPixelBuffer::new_with_background(2, 2, color, None, None);
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod cmyk {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = CMYKpPixelBuffer::new(2, 2, None, None);
+        assert_eq!(buffer.data.len(), 16);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2);
+        assert_eq!(buffer.get_fourcc(), "CMYK");
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 15];
+        match CMYKpPixelBuffer::new_with_data(2, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = CMYKpPixelBuffer::new_with_background(
+            1, 2,
+            DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 },
+            Some(2), None
+        );
+
+        assert_eq!(buffer.data, vec![1, 0, 1, 0, 2, 0, 2, 0, 3, 0, 3, 0, 4, 0, 4, 0]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = CMYKpPixelBuffer::new(2, 1, None, None);
+
+        match buffer.set_pixel(1, 0, DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(0, 1, DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data, vec![0, 1, 0, 2, 0, 3, 0, 4]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![1, 5, 2, 6, 3, 7, 4, 8];
+        let buffer = CMYKpPixelBuffer::new_with_data(2, 1, data, None, None).unwrap();
+
+        match buffer.get_pixel(1, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalCMYKColor{ c: 5, m: 6, y: 7, k: 8 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod kcmy {
+    use super::*;
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![4, 1, 2, 3];
+        let buffer = CMYKpPixelBuffer::new_with_data(1, 1, data, None, Some("KCMY")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 });
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb_separation() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            1, 1,
+            DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 },
+            None, None
+        );
+
+        let separation = CMYKSeparation{ black_generation: 0.5, undercolor_removal: 1.0 };
+        let cmyk = CMYKpPixelBuffer::from_rgb(rgb, separation, None);
+        assert_eq!(cmyk.data, vec![85, 170, 255, 63]);
+
+        let back: RGBPixelBuffer = cmyk.into();
+        assert_eq!(back.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 });
+    }
+
+    #[test]
+    fn interleaved() {
+        let cmyki = CMYKiPixelBuffer::new_with_background(
+            2, 1,
+            DigitalCMYKColor{ c: 1, m: 2, y: 3, k: 4 },
+            None, None
+        );
+
+        let cmykp: CMYKpPixelBuffer = cmyki.into();
+        assert_eq!(cmykp.data, vec![1, 1, 2, 2, 3, 3, 4, 4]);
+    }
+}
@@ -0,0 +1,592 @@
+//! CIE XYZ and L*a*b* color types, white points and RGB primaries
+
+use super::*;
+pub use grapho_color::DigitalGrayscaleColor;
+
+type Matrix = [[f64; 3]; 3];
+
+/// Reference white of XYZ and L*a*b* colors
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum WhitePoint {
+    /// CIE standard illuminant D50, the ICC profile connection space white
+    D50,
+    /// CIE standard illuminant D65, the sRGB white
+    D65
+}
+
+impl WhitePoint {
+    /// CIE 1931 xy chromaticity of the white point
+    pub fn chromaticity(&self) -> [f32; 2] {
+        match self {
+            WhitePoint::D50 => [0.3457, 0.3585],
+            WhitePoint::D65 => [0.3127, 0.3290]
+        }
+    }
+
+    /// XYZ of the white point, normalized to `Y = 1.0`
+    pub fn xyz(&self) -> DigitalXYZColor {
+        let white = xy_to_xyz(self.chromaticity());
+
+        DigitalXYZColor { x: white[0] as f32, y: white[1] as f32, z: white[2] as f32 }
+    }
+}
+
+/// Chromaticities of the primaries and the white of a RGB color space
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RGBPrimaries {
+    /// CIE 1931 xy chromaticity of red
+    pub red: [f32; 2],
+    /// CIE 1931 xy chromaticity of green
+    pub green: [f32; 2],
+    /// CIE 1931 xy chromaticity of blue
+    pub blue: [f32; 2],
+    /// CIE 1931 xy chromaticity of white
+    pub white: [f32; 2]
+}
+
+impl RGBPrimaries {
+    /// sRGB and BT.709 primaries with D65 white
+    pub const SRGB: RGBPrimaries = RGBPrimaries {
+        red: [0.64, 0.33], green: [0.30, 0.60], blue: [0.15, 0.06], white: [0.3127, 0.3290]
+    };
+
+    /// Display P3 primaries with D65 white
+    pub const DISPLAY_P3: RGBPrimaries = RGBPrimaries {
+        red: [0.680, 0.320], green: [0.265, 0.690], blue: [0.150, 0.060], white: [0.3127, 0.3290]
+    };
+
+    /// Adobe RGB (1998) primaries with D65 white
+    pub const ADOBE_RGB: RGBPrimaries = RGBPrimaries {
+        red: [0.64, 0.33], green: [0.21, 0.71], blue: [0.15, 0.06], white: [0.3127, 0.3290]
+    };
+
+    /// BT.2020 primaries with D65 white
+    pub const BT2020: RGBPrimaries = RGBPrimaries {
+        red: [0.708, 0.292], green: [0.170, 0.797], blue: [0.131, 0.046], white: [0.3127, 0.3290]
+    };
+
+    /// Matrix converting linear RGB to XYZ relative to `white`, with Bradford chromatic
+    /// adaptation if the white of the primaries differs
+    fn to_xyz_matrix(self, white: WhitePoint) -> Matrix {
+        let red = xy_to_xyz(self.red);
+        let green = xy_to_xyz(self.green);
+        let blue = xy_to_xyz(self.blue);
+        let source_white = xy_to_xyz(self.white);
+
+        let primaries = [
+            [red[0], green[0], blue[0]],
+            [red[1], green[1], blue[1]],
+            [red[2], green[2], blue[2]]
+        ];
+        let scale = apply(&invert(&primaries), source_white);
+
+        let mut matrix = primaries;
+        for row in matrix.iter_mut() {
+            for (value, factor) in row.iter_mut().zip(scale.iter()) {
+                *value *= factor;
+            }
+        }
+
+        multiply(&bradford(source_white, xy_to_xyz(white.chromaticity())), &matrix)
+    }
+}
+
+impl Default for RGBPrimaries {
+    fn default() -> Self {
+        RGBPrimaries::SRGB
+    }
+}
+
+/// XYZ of a xy chromaticity with `Y = 1.0`
+fn xy_to_xyz(xy: [f32; 2]) -> [f64; 3] {
+    let x = xy[0] as f64;
+    let y = xy[1] as f64;
+
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+fn apply(m: &Matrix, v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2]
+    ]
+}
+
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+
+    result
+}
+
+fn invert(m: &Matrix) -> Matrix {
+    let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    let determinant = m[0][0] * cofactor(1, 2, 1, 2) - m[0][1] * cofactor(1, 2, 0, 2) + m[0][2] * cofactor(1, 2, 0, 1);
+
+    [
+        [cofactor(1, 2, 1, 2) / determinant, -cofactor(0, 2, 1, 2) / determinant, cofactor(0, 1, 1, 2) / determinant],
+        [-cofactor(1, 2, 0, 2) / determinant, cofactor(0, 2, 0, 2) / determinant, -cofactor(0, 1, 0, 2) / determinant],
+        [cofactor(1, 2, 0, 1) / determinant, -cofactor(0, 2, 0, 1) / determinant, cofactor(0, 1, 0, 1) / determinant]
+    ]
+}
+
+/// Bradford chromatic adaptation from one white to another
+fn bradford(from: [f64; 3], to: [f64; 3]) -> Matrix {
+    const CONE: Matrix = [
+        [0.8951, 0.2664, -0.1614],
+        [-0.7502, 1.7135, 0.0367],
+        [0.0389, -0.0685, 1.0296]
+    ];
+
+    let source = apply(&CONE, from);
+    let destination = apply(&CONE, to);
+    let scale = [
+        [destination[0] / source[0], 0.0, 0.0],
+        [0.0, destination[1] / source[1], 0.0],
+        [0.0, 0.0, destination[2] / source[2]]
+    ];
+
+    multiply(&invert(&CONE), &multiply(&scale, &CONE))
+}
+
+/// CIE XYZ color with `f32` components, the reference white has `Y = 1.0`
+///
+/// The `From` conversions assume sRGB primaries and a D65 reference white, use
+/// `from_linear_rgb` and `to_linear_rgb` to select others.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalXYZColor {
+    /// X
+    pub x: f32,
+    /// Y, luminance
+    pub y: f32,
+    /// Z
+    pub z: f32
+}
+
+/// CIE L*a*b* color with `f32` components, `L` is in `0.0..=100.0`
+///
+/// The `From` conversions assume a D65 reference white, use `from_xyz` and `to_xyz`
+/// to select another.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalLabColor {
+    /// lightness L*
+    pub l: f32,
+    /// green-red axis a*
+    pub a: f32,
+    /// blue-yellow axis b*
+    pub b: f32
+}
+
+impl DigitalXYZColor {
+    /// Convert a linear light RGB color, alpha is ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - the color to convert
+    /// * `primaries` - primaries of the RGB color space of `c`
+    /// * `white` - reference white of the result, the RGB white is adapted to it
+    pub fn from_linear_rgb(c: DigitalRGBAFloatColor, primaries: RGBPrimaries, white: WhitePoint) -> Self {
+        let xyz = apply(&primaries.to_xyz_matrix(white), [c.r as f64, c.g as f64, c.b as f64]);
+
+        DigitalXYZColor { x: xyz[0] as f32, y: xyz[1] as f32, z: xyz[2] as f32 }
+    }
+
+    /// Convert to a linear light RGB color with opaque alpha, out of gamut colors
+    /// are not clipped
+    ///
+    /// # Arguments
+    ///
+    /// * `primaries` - primaries of the RGB color space of the result
+    /// * `white` - reference white of this color
+    pub fn to_linear_rgb(&self, primaries: RGBPrimaries, white: WhitePoint) -> DigitalRGBAFloatColor {
+        let rgb = apply(&invert(&primaries.to_xyz_matrix(white)), [self.x as f64, self.y as f64, self.z as f64]);
+
+        DigitalRGBAFloatColor { r: rgb[0] as f32, g: rgb[1] as f32, b: rgb[2] as f32, a: 1.0 }
+    }
+
+    /// Adapt the color from one reference white to another using the Bradford transform
+    pub fn adapt(&self, from: WhitePoint, to: WhitePoint) -> Self {
+        if from == to {
+            return *self;
+        }
+
+        let matrix = bradford(xy_to_xyz(from.chromaticity()), xy_to_xyz(to.chromaticity()));
+        let xyz = apply(&matrix, [self.x as f64, self.y as f64, self.z as f64]);
+
+        DigitalXYZColor { x: xyz[0] as f32, y: xyz[1] as f32, z: xyz[2] as f32 }
+    }
+}
+
+const EPSILON: f32 = 216.0 / 24389.0;
+const KAPPA: f32 = 24389.0 / 27.0;
+
+impl DigitalLabColor {
+    /// Convert a XYZ color relative to `white`
+    pub fn from_xyz(c: DigitalXYZColor, white: WhitePoint) -> Self {
+        let reference = white.xyz();
+        let f = |t: f32| if t > EPSILON { t.cbrt() } else { (KAPPA * t + 16.0) / 116.0 };
+
+        let fx = f(c.x / reference.x);
+        let fy = f(c.y / reference.y);
+        let fz = f(c.z / reference.z);
+
+        DigitalLabColor {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz)
+        }
+    }
+
+    /// Convert to a XYZ color relative to `white`
+    pub fn to_xyz(&self, white: WhitePoint) -> DigitalXYZColor {
+        let reference = white.xyz();
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+        let f_inv = |t: f32| if t * t * t > EPSILON { t * t * t } else { (116.0 * t - 16.0) / KAPPA };
+
+        DigitalXYZColor {
+            x: reference.x * f_inv(fx),
+            y: reference.y * if self.l > KAPPA * EPSILON { fy * fy * fy } else { self.l / KAPPA },
+            z: reference.z * f_inv(fz)
+        }
+    }
+
+    /// Adapt the color from one reference white to another using the Bradford transform
+    pub fn adapt(&self, from: WhitePoint, to: WhitePoint) -> Self {
+        if from == to {
+            return *self;
+        }
+
+        DigitalLabColor::from_xyz(self.to_xyz(from).adapt(from, to), to)
+    }
+}
+
+impl From<[f32; 3]> for DigitalXYZColor {
+    fn from(c: [f32; 3]) -> Self {
+        DigitalXYZColor { x: c[0], y: c[1], z: c[2] }
+    }
+}
+
+impl From<DigitalXYZColor> for [f32; 3] {
+    fn from(c: DigitalXYZColor) -> Self {
+        [c.x, c.y, c.z]
+    }
+}
+
+impl From<[f32; 3]> for DigitalLabColor {
+    fn from(c: [f32; 3]) -> Self {
+        DigitalLabColor { l: c[0], a: c[1], b: c[2] }
+    }
+}
+
+impl From<DigitalLabColor> for [f32; 3] {
+    fn from(c: DigitalLabColor) -> Self {
+        [c.l, c.a, c.b]
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalXYZColor {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalXYZColor::from_linear_rgb(c, RGBPrimaries::SRGB, WhitePoint::D65)
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalRGBAFloatColor {
+    fn from(c: DigitalXYZColor) -> Self {
+        c.to_linear_rgb(RGBPrimaries::SRGB, WhitePoint::D65)
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalLabColor {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalLabColor::from_xyz(c, WhitePoint::D65)
+    }
+}
+
+impl From<DigitalLabColor> for DigitalXYZColor {
+    fn from(c: DigitalLabColor) -> Self {
+        c.to_xyz(WhitePoint::D65)
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalLabColor {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalXYZColor::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalRGBAFloatColor {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalXYZColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalXYZColor {
+    fn from(c: DigitalRGBAColor) -> Self {
+        DigitalRGBAFloatColor::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalRGBAColor {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBAFloatColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalXYZColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalRGBAFloatColor::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalRGBA64Color {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBAFloatColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalXYZColor {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalYCbCrColor {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalGrayscaleColor> for DigitalXYZColor {
+    fn from(c: DigitalGrayscaleColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalGrayscaleColor {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalXYZColor {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalYCoCgColor {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalXYZColor {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalYCoCgRColor {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalXYZColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalXYZColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalGray16Color {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalXYZColor {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalCMYKColor {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalXYZColor {
+    fn from(c: DigitalHSVColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalHSVColor {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalXYZColor {
+    fn from(c: DigitalHSLColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalHSLColor {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalLabColor {
+    fn from(c: DigitalRGBAColor) -> Self {
+        DigitalRGBAFloatColor::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalRGBAColor {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBAFloatColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalLabColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalRGBAFloatColor::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalRGBA64Color {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBAFloatColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalLabColor {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalYCbCrColor {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalGrayscaleColor> for DigitalLabColor {
+    fn from(c: DigitalGrayscaleColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalGrayscaleColor {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalLabColor {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalYCoCgColor {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalLabColor {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalYCoCgRColor {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalLabColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalLabColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalGray16Color {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalLabColor {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalCMYKColor {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalLabColor {
+    fn from(c: DigitalHSVColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalHSVColor {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalLabColor {
+    fn from(c: DigitalHSLColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalHSLColor {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
@@ -0,0 +1,202 @@
+//! CMYK color type with profile-less conversions
+
+use super::*;
+pub use grapho_color::DigitalGrayscaleColor;
+
+/// CMYK color with 8 bit ink coverage per component, `255` is full coverage
+///
+/// The `From` conversions use the naive device formulas without a color profile:
+/// full black generation and undercolor removal when converting from RGB,
+/// `r = (1 - c) * (1 - k)` when converting back. Use `from_rgb` to select
+/// another separation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalCMYKColor {
+    /// cyan
+    pub c: u8,
+    /// magenta
+    pub m: u8,
+    /// yellow
+    pub y: u8,
+    /// black
+    pub k: u8
+}
+
+/// Separation parameters for converting RGB to CMYK
+///
+/// The gray component of a color is the ink coverage all three colored inks have in
+/// common (`min(c, m, y)`). Black generation defines how much of it is printed with
+/// black ink, undercolor removal how much of that black is removed from the colored
+/// inks. The default of `1.0` for both is the naive conversion.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CMYKSeparation {
+    /// fraction of the gray component printed with black ink, `0.0..=1.0`
+    pub black_generation: f32,
+    /// fraction of the generated black removed from the colored inks, `0.0..=1.0`
+    pub undercolor_removal: f32
+}
+
+impl Default for CMYKSeparation {
+    fn default() -> Self {
+        CMYKSeparation { black_generation: 1.0, undercolor_removal: 1.0 }
+    }
+}
+
+impl DigitalCMYKColor {
+    /// Separate a RGB color into inks
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - the color to convert, alpha is ignored
+    /// * `separation` - black generation and undercolor removal to apply
+    pub fn from_rgb(c: DigitalRGBAColor, separation: CMYKSeparation) -> Self {
+        let cyan = 1.0 - c.r as f32 / 255.0;
+        let magenta = 1.0 - c.g as f32 / 255.0;
+        let yellow = 1.0 - c.b as f32 / 255.0;
+
+        let black = separation.black_generation.clamp(0.0, 1.0) * cyan.min(magenta).min(yellow);
+        let removal = separation.undercolor_removal.clamp(0.0, 1.0) * black;
+
+        let ink = |value: f32| {
+            if removal >= 1.0 {
+                0
+            } else {
+                (((value - removal) / (1.0 - removal)).clamp(0.0, 1.0) * 255.0).round() as u8
+            }
+        };
+
+        DigitalCMYKColor {
+            c: ink(cyan),
+            m: ink(magenta),
+            y: ink(yellow),
+            k: (black * 255.0).round() as u8
+        }
+    }
+}
+
+impl From<[u8; 4]> for DigitalCMYKColor {
+    fn from(c: [u8; 4]) -> Self {
+        DigitalCMYKColor { c: c[0], m: c[1], y: c[2], k: c[3] }
+    }
+}
+
+impl From<DigitalCMYKColor> for [u8; 4] {
+    fn from(c: DigitalCMYKColor) -> Self {
+        [c.c, c.m, c.y, c.k]
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalCMYKColor {
+    fn from(c: DigitalRGBAColor) -> Self {
+        DigitalCMYKColor::from_rgb(c, CMYKSeparation::default())
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalRGBAColor {
+    fn from(c: DigitalCMYKColor) -> Self {
+        let white = 255 - c.k as u32;
+        let channel = |ink: u8| (((255 - ink as u32) * white + 127) / 255) as u8;
+
+        DigitalRGBAColor {
+            r: channel(c.c),
+            g: channel(c.m),
+            b: channel(c.y),
+            a: 255
+        }
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalCMYKColor {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalYCbCrColor {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalGrayscaleColor> for DigitalCMYKColor {
+    fn from(c: DigitalGrayscaleColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalGrayscaleColor {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalCMYKColor {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalYCoCgColor {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalCMYKColor {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalYCoCgRColor {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalCMYKColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalRGBA64Color {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalCMYKColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalCMYKColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalGray16Color {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalCMYKColor {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalRGBAFloatColor {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
@@ -0,0 +1,109 @@
+//! 16 bit grayscale color type
+
+use super::*;
+pub use grapho_color::DigitalGrayscaleColor;
+
+/// Grayscale color with a 16 bit value
+///
+/// Converting from 8 bit replicates the byte (`v * 257`) so full scale maps to full scale,
+/// converting back rounds to the nearest value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalGray16Color {
+    /// value
+    pub v: u16
+}
+
+impl From<u16> for DigitalGray16Color {
+    fn from(v: u16) -> Self {
+        DigitalGray16Color { v }
+    }
+}
+
+impl From<DigitalGrayscaleColor> for DigitalGray16Color {
+    fn from(c: DigitalGrayscaleColor) -> Self {
+        DigitalGray16Color { v: widen_u8(c.v) }
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalGrayscaleColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalGrayscaleColor { v: narrow_u16(c.v) }
+    }
+}
+
+/// Luma of the 16 bit color, full range BT.601 weights
+impl From<DigitalRGBA64Color> for DigitalGray16Color {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        let v = 0.299 * c.r as f32 + 0.587 * c.g as f32 + 0.114 * c.b as f32;
+
+        DigitalGray16Color { v: v.round().max(0.0).min(65535.0) as u16 }
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalRGBA64Color {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalRGBA64Color { r: c.v, g: c.v, b: c.v, a: 65535 }
+    }
+}
+
+/// `DigitalYCbCr48Color` puts 8 bit values into the upper byte, so luma is rescaled
+/// to keep full scale at full scale
+impl From<DigitalYCbCr48Color> for DigitalGray16Color {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalGray16Color { v: ((c.y as u32 * 65535 + 32640) / 65280).min(65535) as u16 }
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalYCbCr48Color {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalYCbCr48Color { y: ((c.v as u32 * 65280 + 32767) / 65535) as u16, cb: 32768, cr: 32768 }
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalGray16Color {
+    fn from(c: DigitalRGBAColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalRGBAColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalGray16Color {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalGrayscaleColor::from(c).into()
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalYCbCrColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalGrayscaleColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalGray16Color {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalYCoCgColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalGray16Color {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalYCoCgRColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
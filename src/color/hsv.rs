@@ -0,0 +1,431 @@
+//! HSV and HSL color types for editing, hue in degrees and the other components as fractions
+
+use super::*;
+pub use grapho_color::DigitalGrayscaleColor;
+
+/// HSV color with `f32` components
+///
+/// Hue is in degrees (`0.0..360.0`), saturation and value in `0.0..=1.0`. The
+/// conversions operate on the gamma encoded RGB components, alpha is ignored and
+/// set opaque when converting back.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalHSVColor {
+    /// hue
+    pub h: f32,
+    /// saturation
+    pub s: f32,
+    /// value
+    pub v: f32
+}
+
+/// HSL color with `f32` components
+///
+/// Hue is in degrees (`0.0..360.0`), saturation and lightness in `0.0..=1.0`. The
+/// conversions operate on the gamma encoded RGB components, alpha is ignored and
+/// set opaque when converting back.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalHSLColor {
+    /// hue
+    pub h: f32,
+    /// saturation
+    pub s: f32,
+    /// lightness
+    pub l: f32
+}
+
+/// Hue in degrees, maximum and minimum of normalized RGB components
+fn hue_max_min(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta <= 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+
+    (hue, max, min)
+}
+
+/// Normalized RGB components of a hue with given chroma and offset
+fn hue_to_rgb(h: f32, chroma: f32, offset: f32) -> [f32; 3] {
+    let sector = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x)
+    };
+
+    [r + offset, g + offset, b + offset]
+}
+
+impl DigitalHSVColor {
+    fn from_normalized(r: f32, g: f32, b: f32) -> Self {
+        let (h, max, min) = hue_max_min(r, g, b);
+
+        DigitalHSVColor {
+            h,
+            s: if max <= 0.0 { 0.0 } else { (max - min) / max },
+            v: max
+        }
+    }
+
+    fn to_normalized(self) -> [f32; 3] {
+        let s = self.s.clamp(0.0, 1.0);
+        let v = self.v.clamp(0.0, 1.0);
+        let chroma = v * s;
+
+        hue_to_rgb(self.h, chroma, v - chroma)
+    }
+}
+
+impl DigitalHSLColor {
+    fn from_normalized(r: f32, g: f32, b: f32) -> Self {
+        let (h, max, min) = hue_max_min(r, g, b);
+        let l = (max + min) / 2.0;
+        let divisor = 1.0 - (2.0 * l - 1.0).abs();
+
+        DigitalHSLColor {
+            h,
+            s: if divisor <= 0.0 { 0.0 } else { ((max - min) / divisor).min(1.0) },
+            l
+        }
+    }
+
+    fn to_normalized(self) -> [f32; 3] {
+        let s = self.s.clamp(0.0, 1.0);
+        let l = self.l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+
+        hue_to_rgb(self.h, chroma, l - chroma / 2.0)
+    }
+}
+
+impl From<[f32; 3]> for DigitalHSVColor {
+    fn from(c: [f32; 3]) -> Self {
+        DigitalHSVColor { h: c[0], s: c[1], v: c[2] }
+    }
+}
+
+impl From<DigitalHSVColor> for [f32; 3] {
+    fn from(c: DigitalHSVColor) -> Self {
+        [c.h, c.s, c.v]
+    }
+}
+
+impl From<[f32; 3]> for DigitalHSLColor {
+    fn from(c: [f32; 3]) -> Self {
+        DigitalHSLColor { h: c[0], s: c[1], l: c[2] }
+    }
+}
+
+impl From<DigitalHSLColor> for [f32; 3] {
+    fn from(c: DigitalHSLColor) -> Self {
+        [c.h, c.s, c.l]
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalHSLColor {
+    fn from(c: DigitalHSVColor) -> Self {
+        let v = c.v.clamp(0.0, 1.0);
+        let l = v * (1.0 - c.s.clamp(0.0, 1.0) / 2.0);
+        let divisor = l.min(1.0 - l);
+
+        DigitalHSLColor {
+            h: c.h,
+            s: if divisor <= 0.0 { 0.0 } else { (v - l) / divisor },
+            l
+        }
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalHSVColor {
+    fn from(c: DigitalHSLColor) -> Self {
+        let l = c.l.clamp(0.0, 1.0);
+        let v = l + c.s.clamp(0.0, 1.0) * l.min(1.0 - l);
+
+        DigitalHSVColor {
+            h: c.h,
+            s: if v <= 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) },
+            v
+        }
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalHSVColor {
+    fn from(c: DigitalRGBAColor) -> Self {
+        DigitalHSVColor::from_normalized(c.r as f32 / 255.0, c.g as f32 / 255.0, c.b as f32 / 255.0)
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalRGBAColor {
+    fn from(c: DigitalHSVColor) -> Self {
+        let rgb = c.to_normalized();
+
+        DigitalRGBAColor {
+            r: (rgb[0] * 255.0).round() as u8,
+            g: (rgb[1] * 255.0).round() as u8,
+            b: (rgb[2] * 255.0).round() as u8,
+            a: 255
+        }
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalHSVColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalHSVColor::from_normalized(c.r as f32 / 65535.0, c.g as f32 / 65535.0, c.b as f32 / 65535.0)
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalRGBA64Color {
+    fn from(c: DigitalHSVColor) -> Self {
+        let rgb = c.to_normalized();
+
+        DigitalRGBA64Color {
+            r: (rgb[0] * 65535.0).round() as u16,
+            g: (rgb[1] * 65535.0).round() as u16,
+            b: (rgb[2] * 65535.0).round() as u16,
+            a: 65535
+        }
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalHSVColor {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalYCbCrColor {
+    fn from(c: DigitalHSVColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalGrayscaleColor> for DigitalHSVColor {
+    fn from(c: DigitalGrayscaleColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalGrayscaleColor {
+    fn from(c: DigitalHSVColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalHSVColor {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalYCoCgColor {
+    fn from(c: DigitalHSVColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalHSVColor {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalYCoCgRColor {
+    fn from(c: DigitalHSVColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalHSVColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalHSVColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalHSVColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalGray16Color {
+    fn from(c: DigitalHSVColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalHSVColor {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalRGBAFloatColor {
+    fn from(c: DigitalHSVColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalHSVColor {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalCMYKColor {
+    fn from(c: DigitalHSVColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalHSLColor {
+    fn from(c: DigitalRGBAColor) -> Self {
+        DigitalHSLColor::from_normalized(c.r as f32 / 255.0, c.g as f32 / 255.0, c.b as f32 / 255.0)
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalRGBAColor {
+    fn from(c: DigitalHSLColor) -> Self {
+        let rgb = c.to_normalized();
+
+        DigitalRGBAColor {
+            r: (rgb[0] * 255.0).round() as u8,
+            g: (rgb[1] * 255.0).round() as u8,
+            b: (rgb[2] * 255.0).round() as u8,
+            a: 255
+        }
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalHSLColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalHSLColor::from_normalized(c.r as f32 / 65535.0, c.g as f32 / 65535.0, c.b as f32 / 65535.0)
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalRGBA64Color {
+    fn from(c: DigitalHSLColor) -> Self {
+        let rgb = c.to_normalized();
+
+        DigitalRGBA64Color {
+            r: (rgb[0] * 65535.0).round() as u16,
+            g: (rgb[1] * 65535.0).round() as u16,
+            b: (rgb[2] * 65535.0).round() as u16,
+            a: 65535
+        }
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalHSLColor {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalYCbCrColor {
+    fn from(c: DigitalHSLColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalGrayscaleColor> for DigitalHSLColor {
+    fn from(c: DigitalGrayscaleColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalGrayscaleColor {
+    fn from(c: DigitalHSLColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalHSLColor {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalYCoCgColor {
+    fn from(c: DigitalHSLColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalHSLColor {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalYCoCgRColor {
+    fn from(c: DigitalHSLColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalHSLColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalHSLColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalHSLColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalGray16Color {
+    fn from(c: DigitalHSLColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalHSLColor {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalRGBAFloatColor {
+    fn from(c: DigitalHSLColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalHSLColor {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalCMYKColor {
+    fn from(c: DigitalHSLColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
@@ -0,0 +1,99 @@
+//! This module contains color types that are not (yet) part of `grapho-color`,
+//! including their conversions into the `grapho-color` types
+
+pub use grapho_color::{ DigitalRGBAColor, DigitalYCbCrColor };
+
+/// Clamp an intermediate integer result into the `u8` range
+pub(crate) fn clamp_u8(value: i32) -> u8 {
+    if value < 0 {
+        0
+    } else if value > 255 {
+        255
+    } else {
+        value as u8
+    }
+}
+
+/// Expand a component with `bits` bits to 8 bit, components with up to 8 bits
+/// replicate their high bits into the low bits, wider components are rounded
+pub(crate) fn expand_bits(value: u32, bits: u32) -> u8 {
+    if bits > 8 {
+        let max = (1u64 << bits) - 1;
+        return ((value as u64 * 255 + max / 2) / max) as u8;
+    }
+
+    let mut result: u32 = 0;
+    let mut filled: u32 = 0;
+
+    while filled < 8 {
+        result = (result << bits) | value;
+        filled += bits;
+    }
+
+    (result >> (filled - 8)) as u8
+}
+
+/// Reduce an 8 bit component to `bits` bits with rounding
+pub(crate) fn reduce_bits(value: u8, bits: u32) -> u32 {
+    let max = (1u64 << bits) - 1;
+
+    ((value as u64 * max + 127) / 255) as u32
+}
+
+/// Expand a component with up to 16 bits to 16 bit by bit replication
+pub(crate) fn expand_bits16(value: u32, bits: u32) -> u16 {
+    let mut result: u32 = 0;
+    let mut filled: u32 = 0;
+
+    while filled < 16 {
+        result = (result << bits) | value;
+        filled += bits;
+    }
+
+    (result >> (filled - 16)) as u16
+}
+
+/// Reduce a 16 bit component to `bits` bits with rounding
+pub(crate) fn reduce_bits16(value: u16, bits: u32) -> u32 {
+    let max = (1u32 << bits) - 1;
+
+    (value as u32 * max + 32767) / 65535
+}
+
+mod ycocg;
+pub use ycocg::DigitalYCoCgColor;
+
+mod ycocgr;
+pub use ycocgr::DigitalYCoCgRColor;
+
+mod rgba64;
+pub use rgba64::DigitalRGBA64Color;
+pub(crate) use rgba64::{ widen_u8, narrow_u16 };
+
+mod ycbcr48;
+pub use ycbcr48::DigitalYCbCr48Color;
+pub(crate) use ycbcr48::quantize_msb;
+
+mod gray16;
+pub use gray16::DigitalGray16Color;
+
+mod transfer;
+pub use transfer::TransferFunction;
+
+mod rgbafloat;
+pub use rgbafloat::DigitalRGBAFloatColor;
+pub(crate) use rgbafloat::{ f16_to_f32, f32_to_f16 };
+
+mod cmyk;
+pub use cmyk::{ DigitalCMYKColor, CMYKSeparation };
+
+mod hsv;
+pub use hsv::{ DigitalHSVColor, DigitalHSLColor };
+
+mod cie;
+pub use cie::{ DigitalXYZColor, DigitalLabColor, WhitePoint, RGBPrimaries };
+
+mod ycbcra;
+pub use ycbcra::DigitalYCbCrAColor;
+
+mod tests;
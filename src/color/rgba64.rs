@@ -0,0 +1,112 @@
+//! 16 bit per channel RGBA color type
+
+use super::*;
+pub use grapho_color::DigitalGrayscaleColor;
+
+/// RGBA color with 16 bit per component
+///
+/// Converting from 8 bit components replicates the byte (`v * 257`), so `255`
+/// maps to `65535`. Converting back to 8 bit rounds to the nearest value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalRGBA64Color {
+    /// red
+    pub r: u16,
+    /// green
+    pub g: u16,
+    /// blue
+    pub b: u16,
+    /// alpha
+    pub a: u16
+}
+
+/// Widen an 8 bit component to 16 bit
+pub(crate) fn widen_u8(value: u8) -> u16 {
+    value as u16 * 257
+}
+
+/// Narrow a 16 bit component to 8 bit with rounding
+pub(crate) fn narrow_u16(value: u16) -> u8 {
+    ((value as u32 * 255 + 32767) / 65535) as u8
+}
+
+impl From<[u16; 4]> for DigitalRGBA64Color {
+    fn from(c: [u16; 4]) -> Self {
+        DigitalRGBA64Color { r: c[0], g: c[1], b: c[2], a: c[3] }
+    }
+}
+
+impl From<DigitalRGBA64Color> for [u16; 4] {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        [c.r, c.g, c.b, c.a]
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalRGBA64Color {
+    fn from(c: DigitalRGBAColor) -> Self {
+        DigitalRGBA64Color {
+            r: widen_u8(c.r),
+            g: widen_u8(c.g),
+            b: widen_u8(c.b),
+            a: widen_u8(c.a)
+        }
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalRGBAColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalRGBAColor {
+            r: narrow_u16(c.r),
+            g: narrow_u16(c.g),
+            b: narrow_u16(c.b),
+            a: narrow_u16(c.a)
+        }
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalRGBA64Color {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalYCbCrColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalGrayscaleColor> for DigitalRGBA64Color {
+    fn from(c: DigitalGrayscaleColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalGrayscaleColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalRGBA64Color {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalYCoCgColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalRGBA64Color {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalYCoCgRColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
@@ -0,0 +1,234 @@
+//! Floating point RGBA color type in linear light
+
+use super::*;
+pub use grapho_color::DigitalGrayscaleColor;
+
+/// RGBA color with `f32` components in linear light
+///
+/// The nominal range of all components is `0.0..=1.0`, color components may exceed
+/// it for HDR content. Alpha is never gamma encoded. The `From` conversions from and
+/// to the integer color types assume sRGB encoded integer components, use
+/// `from_digital` and `to_digital` to select another transfer function.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalRGBAFloatColor {
+    /// red
+    pub r: f32,
+    /// green
+    pub g: f32,
+    /// blue
+    pub b: f32,
+    /// alpha
+    pub a: f32
+}
+
+/// Convert a half precision float (IEEE 754 binary16) to `f32`
+pub(crate) fn f16_to_f32(value: u16) -> f32 {
+    let sign = ((value & 0x8000) as u32) << 16;
+    let exponent = ((value >> 10) & 0x1f) as u32;
+    let mantissa = (value & 0x3ff) as u32;
+
+    let bits = if exponent == 0 {
+        // zero or subnormal
+        let magnitude = mantissa as f32 * (-24f32).exp2();
+        return if sign != 0 { -magnitude } else { magnitude };
+    } else if exponent == 0x1f {
+        // infinity or NaN
+        sign | 0x7f80_0000 | (mantissa << 13)
+    } else {
+        sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)
+    };
+
+    f32::from_bits(bits)
+}
+
+/// Convert a `f32` to half precision float (IEEE 754 binary16), rounding to nearest even
+pub(crate) fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exponent == 0xff {
+        // infinity or NaN
+        return sign | 0x7c00 | if mantissa != 0 { 0x200 } else { 0 };
+    }
+
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    if exponent <= 0 {
+        // subnormal or zero
+        if exponent < -10 {
+            return sign;
+        }
+        let mantissa = mantissa | 0x80_0000;
+        let shift = (14 - exponent) as u32;
+        let rounded = (mantissa + (1 << (shift - 1)) - 1 + ((mantissa >> shift) & 1)) >> shift;
+        return sign | rounded as u16;
+    }
+
+    // a carry of the rounding increments the exponent, overflowing to infinity
+    let rounded = mantissa + 0xfff + ((mantissa >> 13) & 1);
+    let result = ((exponent as u32) << 10) + (rounded >> 13);
+
+    sign | result.min(0x7c00) as u16
+}
+
+/// Quantize a component in the range `0.0..=1.0` to an integer with `max` as maximum
+fn quantize(value: f32, max: f32) -> f32 {
+    (value.clamp(0.0, 1.0) * max).round()
+}
+
+impl DigitalRGBAFloatColor {
+    /// Convert an 8 bit color with components encoded by `transfer` to linear light
+    pub fn from_digital(c: DigitalRGBAColor, transfer: TransferFunction) -> Self {
+        DigitalRGBAFloatColor {
+            r: transfer.decode(c.r as f32 / 255.0),
+            g: transfer.decode(c.g as f32 / 255.0),
+            b: transfer.decode(c.b as f32 / 255.0),
+            a: c.a as f32 / 255.0
+        }
+    }
+
+    /// Convert to an 8 bit color with components encoded by `transfer`, out of range
+    /// values are clamped
+    pub fn to_digital(&self, transfer: TransferFunction) -> DigitalRGBAColor {
+        DigitalRGBAColor {
+            r: quantize(transfer.encode(self.r), 255.0) as u8,
+            g: quantize(transfer.encode(self.g), 255.0) as u8,
+            b: quantize(transfer.encode(self.b), 255.0) as u8,
+            a: quantize(self.a, 255.0) as u8
+        }
+    }
+
+    /// Convert a 16 bit color with components encoded by `transfer` to linear light
+    pub fn from_digital64(c: DigitalRGBA64Color, transfer: TransferFunction) -> Self {
+        DigitalRGBAFloatColor {
+            r: transfer.decode(c.r as f32 / 65535.0),
+            g: transfer.decode(c.g as f32 / 65535.0),
+            b: transfer.decode(c.b as f32 / 65535.0),
+            a: c.a as f32 / 65535.0
+        }
+    }
+
+    /// Convert to a 16 bit color with components encoded by `transfer`, out of range
+    /// values are clamped
+    pub fn to_digital64(&self, transfer: TransferFunction) -> DigitalRGBA64Color {
+        DigitalRGBA64Color {
+            r: quantize(transfer.encode(self.r), 65535.0) as u16,
+            g: quantize(transfer.encode(self.g), 65535.0) as u16,
+            b: quantize(transfer.encode(self.b), 65535.0) as u16,
+            a: quantize(self.a, 65535.0) as u16
+        }
+    }
+}
+
+impl From<[f32; 4]> for DigitalRGBAFloatColor {
+    fn from(c: [f32; 4]) -> Self {
+        DigitalRGBAFloatColor { r: c[0], g: c[1], b: c[2], a: c[3] }
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for [f32; 4] {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        [c.r, c.g, c.b, c.a]
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalRGBAFloatColor {
+    fn from(c: DigitalRGBAColor) -> Self {
+        DigitalRGBAFloatColor::from_digital(c, TransferFunction::SRGB)
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalRGBAColor {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        c.to_digital(TransferFunction::SRGB)
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalRGBAFloatColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalRGBAFloatColor::from_digital64(c, TransferFunction::SRGB)
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalRGBA64Color {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        c.to_digital64(TransferFunction::SRGB)
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalRGBAFloatColor {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalYCbCrColor {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalGrayscaleColor> for DigitalRGBAFloatColor {
+    fn from(c: DigitalGrayscaleColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalGrayscaleColor {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalRGBAFloatColor {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalYCoCgColor {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalRGBAFloatColor {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalYCoCgRColor {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalRGBAFloatColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalRGBAFloatColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalGray16Color {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalRGBA64Color::from(c).into()
+    }
+}
@@ -0,0 +1,545 @@
+//
+// Tests
+//
+
+pub use crate::color::*;
+
+#[cfg(test)]
+mod ycocg {
+    use super::*;
+
+    #[test]
+    fn from_rgba() {
+        let color: DigitalYCoCgColor = DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 }.into();
+        assert_eq!(color, DigitalYCoCgColor{ y: 255, co: 128, cg: 128 });
+
+        let color: DigitalYCoCgColor = DigitalRGBAColor{ r: 0, g: 255, b: 0, a: 255 }.into();
+        assert_eq!(color, DigitalYCoCgColor{ y: 128, co: 128, cg: 255 });
+
+        let color: DigitalYCoCgColor = DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 }.into();
+        assert_eq!(color, DigitalYCoCgColor{ y: 64, co: 255, cg: 64 });
+    }
+
+    #[test]
+    fn to_rgba() {
+        let color: DigitalRGBAColor = DigitalYCoCgColor{ y: 255, co: 128, cg: 128 }.into();
+        assert_eq!(color, DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 });
+
+        let color: DigitalRGBAColor = DigitalYCoCgColor{ y: 64, co: 255, cg: 64 }.into();
+        assert_eq!(color, DigitalRGBAColor{ r: 255, g: 0, b: 1, a: 255 });
+    }
+
+    #[test]
+    fn roundtrip() {
+        for r in (0..256).step_by(15) {
+            for g in (0..256).step_by(15) {
+                for b in (0..256).step_by(15) {
+                    let rgba = DigitalRGBAColor{ r: r as u8, g: g as u8, b: b as u8, a: 255 };
+                    let back: DigitalRGBAColor = DigitalYCoCgColor::from(rgba).into();
+                    assert!((back.r as i32 - r).abs() <= 2, "{:?} -> {:?}", rgba, back);
+                    assert!((back.g as i32 - g).abs() <= 2, "{:?} -> {:?}", rgba, back);
+                    assert!((back.b as i32 - b).abs() <= 2, "{:?} -> {:?}", rgba, back);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod ycocgr {
+    use super::*;
+
+    #[test]
+    fn from_rgba() {
+        let color: DigitalYCoCgRColor = DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 }.into();
+        assert_eq!(color, DigitalYCoCgRColor{ y: 255, co: 0, cg: 0 });
+
+        let color: DigitalYCoCgRColor = DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 }.into();
+        assert_eq!(color, DigitalYCoCgRColor{ y: 63, co: 255, cg: -127 });
+
+        let color: DigitalYCoCgRColor = DigitalRGBAColor{ r: 0, g: 0, b: 255, a: 255 }.into();
+        assert_eq!(color, DigitalYCoCgRColor{ y: 63, co: -255, cg: -127 });
+    }
+
+    #[test]
+    fn lossless_roundtrip() {
+        for r in 0..256 {
+            for g in 0..256 {
+                for b in 0..256 {
+                    let rgba = DigitalRGBAColor{ r: r as u8, g: g as u8, b: b as u8, a: 255 };
+                    let back: DigitalRGBAColor = DigitalYCoCgRColor::from(rgba).into();
+                    assert_eq!(rgba, back);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod rgba64 {
+    use super::*;
+
+    #[test]
+    fn from_rgba() {
+        let color: DigitalRGBA64Color = DigitalRGBAColor{ r: 255, g: 128, b: 1, a: 0 }.into();
+        assert_eq!(color, DigitalRGBA64Color{ r: 65535, g: 32896, b: 257, a: 0 });
+    }
+
+    #[test]
+    fn to_rgba() {
+        let color: DigitalRGBAColor = DigitalRGBA64Color{ r: 65535, g: 32767, b: 128, a: 129 }.into();
+        assert_eq!(color, DigitalRGBAColor{ r: 255, g: 127, b: 0, a: 1 });
+    }
+
+    #[test]
+    fn roundtrip() {
+        for v in 0..256 {
+            let rgba = DigitalRGBAColor{ r: v as u8, g: v as u8, b: v as u8, a: v as u8 };
+            let back: DigitalRGBAColor = DigitalRGBA64Color::from(rgba).into();
+            assert_eq!(back, rgba);
+        }
+    }
+}
+
+#[cfg(test)]
+mod ycbcr48 {
+    use super::*;
+
+    #[test]
+    fn from_ycbcr() {
+        let color: DigitalYCbCr48Color = DigitalYCbCrColor{ y: 255, cb: 128, cr: 0 }.into();
+        assert_eq!(color, DigitalYCbCr48Color{ y: 0xff00, cb: 0x8000, cr: 0 });
+    }
+
+    #[test]
+    fn to_ycbcr() {
+        let color: DigitalYCbCrColor = DigitalYCbCr48Color{ y: 0xffff, cb: 0x807f, cr: 0x8080 }.into();
+        assert_eq!(color, DigitalYCbCrColor{ y: 255, cb: 128, cr: 129 });
+    }
+
+    #[test]
+    fn rgba64_roundtrip() {
+        let rgba = DigitalRGBA64Color{ r: 0x1234, g: 0xfedc, b: 0x8000, a: 65535 };
+        let back: DigitalRGBA64Color = DigitalYCbCr48Color::from(rgba).into();
+        assert!((back.r as i32 - rgba.r as i32).abs() <= 2, "{:?} -> {:?}", rgba, back);
+        assert!((back.g as i32 - rgba.g as i32).abs() <= 2, "{:?} -> {:?}", rgba, back);
+        assert!((back.b as i32 - rgba.b as i32).abs() <= 2, "{:?} -> {:?}", rgba, back);
+    }
+
+    #[test]
+    fn white_black_roundtrip() {
+        for v in [0u8, 255] {
+            let ycbcr: DigitalYCbCr48Color = DigitalYCbCrColor{ y: v, cb: 128, cr: 128 }.into();
+            let rgba64 = DigitalRGBA64Color::from(ycbcr);
+            let full = if v == 0 { 0 } else { 65535 };
+            assert_eq!(rgba64, DigitalRGBA64Color{ r: full, g: full, b: full, a: 65535 });
+
+            let rgba: DigitalRGBAColor = rgba64.into();
+            assert_eq!(rgba, DigitalRGBAColor{ r: v, g: v, b: v, a: 255 });
+
+            let back: DigitalYCbCrColor = DigitalYCbCr48Color::from(rgba64).into();
+            assert_eq!(back, DigitalYCbCrColor{ y: v, cb: 128, cr: 128 });
+        }
+    }
+
+    #[test]
+    fn gray16_agrees() {
+        let ycbcr = DigitalYCbCr48Color{ y: 0xff00, cb: 0x8000, cr: 0x8000 };
+        let gray = DigitalGray16Color::from(ycbcr);
+        let rgba64 = DigitalRGBA64Color::from(ycbcr);
+        assert_eq!(gray.v, rgba64.r);
+    }
+}
+
+#[cfg(test)]
+mod gray16 {
+    use super::*;
+    use grapho_color::DigitalGrayscaleColor;
+
+    #[test]
+    fn grayscale() {
+        let color: DigitalGray16Color = DigitalGrayscaleColor{ v: 128 }.into();
+        assert_eq!(color, DigitalGray16Color{ v: 0x8080 });
+
+        let color: DigitalGrayscaleColor = DigitalGray16Color{ v: 0x807f }.into();
+        assert_eq!(color, DigitalGrayscaleColor{ v: 128 });
+    }
+
+    #[test]
+    fn ycbcr48() {
+        let color: DigitalYCbCr48Color = DigitalGray16Color{ v: 0xffff }.into();
+        assert_eq!(color, DigitalYCbCr48Color{ y: 0xff00, cb: 0x8000, cr: 0x8000 });
+
+        let color: DigitalGray16Color = DigitalYCbCr48Color{ y: 0xff00, cb: 0x8000, cr: 0x8000 }.into();
+        assert_eq!(color, DigitalGray16Color{ v: 0xffff });
+    }
+}
+
+#[cfg(test)]
+mod rgbafloat {
+    use super::*;
+
+    #[test]
+    fn transfer_function() {
+        assert_eq!(TransferFunction::Linear.decode(0.5), 0.5);
+        assert!((TransferFunction::SRGB.decode(0.5) - 0.214_041).abs() < 1e-5);
+        assert!((TransferFunction::SRGB.encode(0.214_041) - 0.5).abs() < 1e-5);
+        assert_eq!(TransferFunction::SRGB.decode(0.0), 0.0);
+        assert!((TransferFunction::SRGB.decode(1.0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn from_rgba() {
+        let color: DigitalRGBAFloatColor = DigitalRGBAColor{ r: 255, g: 0, b: 128, a: 51 }.into();
+        assert_eq!(color.r, 1.0);
+        assert_eq!(color.g, 0.0);
+        assert!((color.b - 0.215_861).abs() < 1e-5);
+        assert!((color.a - 0.2).abs() < 1e-6);
+
+        let color = DigitalRGBAFloatColor::from_digital(
+            DigitalRGBAColor{ r: 255, g: 0, b: 128, a: 255 },
+            TransferFunction::Linear
+        );
+        assert!((color.b - 128.0 / 255.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn to_rgba() {
+        let color: DigitalRGBAColor = DigitalRGBAFloatColor{ r: 2.0, g: -1.0, b: 0.215_861, a: 0.5 }.into();
+        assert_eq!(color, DigitalRGBAColor{ r: 255, g: 0, b: 128, a: 128 });
+
+        let color = DigitalRGBAFloatColor{ r: 0.0, g: 0.5, b: 1.0, a: 1.0 }.to_digital(TransferFunction::Linear);
+        assert_eq!(color, DigitalRGBAColor{ r: 0, g: 128, b: 255, a: 255 });
+    }
+
+    #[test]
+    fn roundtrip() {
+        for v in 0..256 {
+            let rgba = DigitalRGBAColor{ r: v as u8, g: v as u8, b: v as u8, a: v as u8 };
+            let back: DigitalRGBAColor = DigitalRGBAFloatColor::from(rgba).into();
+            assert_eq!(back, rgba);
+
+            let rgba64 = DigitalRGBA64Color::from(rgba);
+            let back: DigitalRGBA64Color = DigitalRGBAFloatColor::from(rgba64).into();
+            assert_eq!(back, rgba64);
+        }
+    }
+
+    #[test]
+    fn half_float() {
+        assert_eq!(f32_to_f16(0.0), 0x0000);
+        assert_eq!(f32_to_f16(-0.0), 0x8000);
+        assert_eq!(f32_to_f16(1.0), 0x3c00);
+        assert_eq!(f32_to_f16(-2.0), 0xc000);
+        assert_eq!(f32_to_f16(0.5), 0x3800);
+        assert_eq!(f32_to_f16(65504.0), 0x7bff);
+        assert_eq!(f32_to_f16(1.0e6), 0x7c00);
+        assert_eq!(f32_to_f16(5.960_464_5e-8), 0x0001);
+        assert_eq!(f32_to_f16(1.0 + 1.0 / 4096.0), 0x3c00);
+        assert_eq!(f32_to_f16(1.0 + 3.0 / 2048.0), 0x3c02);
+        assert_eq!(f32_to_f16(f32::NAN) & 0x7e00, 0x7e00);
+
+        assert_eq!(f16_to_f32(0x3c00), 1.0);
+        assert_eq!(f16_to_f32(0xc000), -2.0);
+        assert_eq!(f16_to_f32(0x7bff), 65504.0);
+        assert_eq!(f16_to_f32(0x0001), 5.960_464_5e-8);
+        assert_eq!(f16_to_f32(0x7c00), f32::INFINITY);
+        assert!(f16_to_f32(0x7e00).is_nan());
+
+        for bits in 0..0x7c00u16 {
+            assert_eq!(f32_to_f16(f16_to_f32(bits)), bits);
+        }
+    }
+}
+
+#[cfg(test)]
+mod cmyk {
+    use super::*;
+
+    #[test]
+    fn from_rgba() {
+        let color: DigitalCMYKColor = DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 }.into();
+        assert_eq!(color, DigitalCMYKColor{ c: 0, m: 0, y: 0, k: 0 });
+
+        let color: DigitalCMYKColor = DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 255 }.into();
+        assert_eq!(color, DigitalCMYKColor{ c: 0, m: 0, y: 0, k: 255 });
+
+        let color: DigitalCMYKColor = DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 }.into();
+        assert_eq!(color, DigitalCMYKColor{ c: 0, m: 255, y: 255, k: 0 });
+
+        let color: DigitalCMYKColor = DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 }.into();
+        assert_eq!(color, DigitalCMYKColor{ c: 0, m: 128, y: 255, k: 127 });
+    }
+
+    #[test]
+    fn to_rgba() {
+        let color: DigitalRGBAColor = DigitalCMYKColor{ c: 0, m: 128, y: 255, k: 127 }.into();
+        assert_eq!(color, DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 });
+
+        let color: DigitalRGBAColor = DigitalCMYKColor{ c: 255, m: 255, y: 255, k: 255 }.into();
+        assert_eq!(color, DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 255 });
+    }
+
+    #[test]
+    fn separation() {
+        let rgba = DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 };
+
+        // no black generation, all inks colored
+        let color = DigitalCMYKColor::from_rgb(rgba, CMYKSeparation{ black_generation: 0.0, undercolor_removal: 1.0 });
+        assert_eq!(color, DigitalCMYKColor{ c: 127, m: 191, y: 255, k: 0 });
+
+        // half of the gray component printed black, removed from the colored inks
+        let color = DigitalCMYKColor::from_rgb(rgba, CMYKSeparation{ black_generation: 0.5, undercolor_removal: 1.0 });
+        assert_eq!(color, DigitalCMYKColor{ c: 85, m: 170, y: 255, k: 63 });
+
+        // black added on top of the colored inks
+        let color = DigitalCMYKColor::from_rgb(rgba, CMYKSeparation{ black_generation: 1.0, undercolor_removal: 0.0 });
+        assert_eq!(color, DigitalCMYKColor{ c: 127, m: 191, y: 255, k: 127 });
+    }
+
+    #[test]
+    fn roundtrip() {
+        for r in (0..256).step_by(15) {
+            for g in (0..256).step_by(15) {
+                for b in (0..256).step_by(15) {
+                    let rgba = DigitalRGBAColor{ r: r as u8, g: g as u8, b: b as u8, a: 255 };
+                    for black_generation in [0.0, 0.5, 1.0].iter() {
+                        let separation = CMYKSeparation{ black_generation: *black_generation, undercolor_removal: 1.0 };
+                        let back: DigitalRGBAColor = DigitalCMYKColor::from_rgb(rgba, separation).into();
+                        assert!((back.r as i32 - r).abs() <= 1, "{:?} -> {:?}", rgba, back);
+                        assert!((back.g as i32 - g).abs() <= 1, "{:?} -> {:?}", rgba, back);
+                        assert!((back.b as i32 - b).abs() <= 1, "{:?} -> {:?}", rgba, back);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod hsv {
+    use super::*;
+
+    fn assert_hsv(color: DigitalHSVColor, expected: [f32; 3]) {
+        assert!((color.h - expected[0]).abs() < 1e-3, "{:?} != {:?}", color, expected);
+        assert!((color.s - expected[1]).abs() < 1e-5, "{:?} != {:?}", color, expected);
+        assert!((color.v - expected[2]).abs() < 1e-5, "{:?} != {:?}", color, expected);
+    }
+
+    #[test]
+    fn from_rgba() {
+        assert_hsv(DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 }.into(), [0.0, 1.0, 1.0]);
+        assert_hsv(DigitalRGBAColor{ r: 0, g: 255, b: 0, a: 255 }.into(), [120.0, 1.0, 1.0]);
+        assert_hsv(DigitalRGBAColor{ r: 0, g: 0, b: 255, a: 255 }.into(), [240.0, 1.0, 1.0]);
+        assert_hsv(DigitalRGBAColor{ r: 255, g: 0, b: 255, a: 255 }.into(), [300.0, 1.0, 1.0]);
+        assert_hsv(DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 }.into(), [30.0, 1.0, 128.0 / 255.0]);
+        assert_hsv(DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 255 }.into(), [0.0, 0.0, 0.0]);
+        assert_hsv(DigitalRGBAColor{ r: 51, g: 51, b: 51, a: 0 }.into(), [0.0, 0.0, 0.2]);
+    }
+
+    #[test]
+    fn to_rgba() {
+        let color: DigitalRGBAColor = DigitalHSVColor{ h: 30.0, s: 1.0, v: 128.0 / 255.0 }.into();
+        assert_eq!(color, DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 });
+
+        // hue wraps around, saturation and value are clamped
+        let color: DigitalRGBAColor = DigitalHSVColor{ h: -60.0, s: 2.0, v: 1.5 }.into();
+        assert_eq!(color, DigitalRGBAColor{ r: 255, g: 0, b: 255, a: 255 });
+
+        let color: DigitalRGBAColor = DigitalHSVColor{ h: 720.0, s: 0.0, v: 0.2 }.into();
+        assert_eq!(color, DigitalRGBAColor{ r: 51, g: 51, b: 51, a: 255 });
+    }
+
+    #[test]
+    fn roundtrip() {
+        for r in (0..256).step_by(15) {
+            for g in (0..256).step_by(15) {
+                for b in (0..256).step_by(15) {
+                    let rgba = DigitalRGBAColor{ r: r as u8, g: g as u8, b: b as u8, a: 255 };
+                    let back: DigitalRGBAColor = DigitalHSVColor::from(rgba).into();
+                    assert_eq!(back, rgba);
+
+                    let back: DigitalRGBAColor = DigitalHSLColor::from(DigitalHSVColor::from(rgba)).into();
+                    assert_eq!(back, rgba);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod hsl {
+    use super::*;
+
+    fn assert_hsl(color: DigitalHSLColor, expected: [f32; 3]) {
+        assert!((color.h - expected[0]).abs() < 1e-3, "{:?} != {:?}", color, expected);
+        assert!((color.s - expected[1]).abs() < 1e-5, "{:?} != {:?}", color, expected);
+        assert!((color.l - expected[2]).abs() < 1e-5, "{:?} != {:?}", color, expected);
+    }
+
+    #[test]
+    fn from_rgba() {
+        assert_hsl(DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 }.into(), [0.0, 1.0, 0.5]);
+        assert_hsl(DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 }.into(), [30.0, 1.0, 64.0 / 255.0]);
+        assert_hsl(DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 }.into(), [0.0, 0.0, 1.0]);
+        assert_hsl(DigitalRGBAColor{ r: 191, g: 64, b: 64, a: 255 }.into(), [0.0, 127.0 / 255.0, 0.5]);
+    }
+
+    #[test]
+    fn to_rgba() {
+        let color: DigitalRGBAColor = DigitalHSLColor{ h: 30.0, s: 1.0, l: 64.0 / 255.0 }.into();
+        assert_eq!(color, DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 });
+
+        let color: DigitalRGBAColor = DigitalHSLColor{ h: 240.0, s: 0.5, l: 0.5 }.into();
+        assert_eq!(color, DigitalRGBAColor{ r: 64, g: 64, b: 191, a: 255 });
+    }
+
+    #[test]
+    fn hsv() {
+        let color: DigitalHSLColor = DigitalHSVColor{ h: 60.0, s: 1.0, v: 1.0 }.into();
+        assert_hsl(color, [60.0, 1.0, 0.5]);
+
+        let color: DigitalHSVColor = DigitalHSLColor{ h: 60.0, s: 0.5, l: 0.25 }.into();
+        assert!((color.s - 2.0 / 3.0).abs() < 1e-6);
+        assert!((color.v - 0.375).abs() < 1e-6);
+
+        let color: DigitalHSVColor = DigitalHSLColor{ h: 60.0, s: 1.0, l: 0.0 }.into();
+        assert_eq!(color, DigitalHSVColor{ h: 60.0, s: 0.0, v: 0.0 });
+    }
+
+    #[test]
+    fn roundtrip() {
+        for r in (0..256).step_by(15) {
+            for g in (0..256).step_by(15) {
+                for b in (0..256).step_by(15) {
+                    let rgba = DigitalRGBAColor{ r: r as u8, g: g as u8, b: b as u8, a: 255 };
+                    let back: DigitalRGBAColor = DigitalHSLColor::from(rgba).into();
+                    assert_eq!(back, rgba);
+
+                    let rgba64 = DigitalRGBA64Color::from(rgba);
+                    let back: DigitalRGBA64Color = DigitalHSLColor::from(rgba64).into();
+                    assert_eq!(back, rgba64);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod cie {
+    use super::*;
+
+    fn assert_close(actual: [f32; 3], expected: [f32; 3], tolerance: f32) {
+        for i in 0..3 {
+            assert!((actual[i] - expected[i]).abs() < tolerance, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn white_point() {
+        assert_close(WhitePoint::D65.xyz().into(), [0.950_456, 1.0, 1.089_058], 1e-5);
+        assert_close(WhitePoint::D50.xyz().into(), [0.964_296, 1.0, 0.825_105], 1e-5);
+    }
+
+    #[test]
+    fn from_rgba() {
+        let color: DigitalXYZColor = DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 }.into();
+        assert_close(color.into(), [0.412_39, 0.212_64, 0.019_33], 1e-4);
+
+        let color: DigitalXYZColor = DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 }.into();
+        assert_close(color.into(), WhitePoint::D65.xyz().into(), 1e-5);
+
+        let color: DigitalLabColor = DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 }.into();
+        assert_close(color.into(), [53.24, 80.09, 67.20], 0.05);
+
+        let color: DigitalLabColor = DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 }.into();
+        assert_close(color.into(), [100.0, 0.0, 0.0], 1e-3);
+
+        let color: DigitalLabColor = DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 255 }.into();
+        assert_close(color.into(), [0.0, 0.0, 0.0], 1e-3);
+    }
+
+    #[test]
+    fn primaries() {
+        let red = DigitalRGBAFloatColor{ r: 1.0, g: 0.0, b: 0.0, a: 1.0 };
+
+        // sRGB red relative to D50, Bradford adapted
+        let color = DigitalXYZColor::from_linear_rgb(red, RGBPrimaries::SRGB, WhitePoint::D50);
+        assert_close(color.into(), [0.4360, 0.2225, 0.0139], 1e-3);
+        assert_close(DigitalLabColor::from_xyz(color, WhitePoint::D50).into(), [54.29, 80.81, 69.89], 0.1);
+
+        // the sRGB white maps to the D50 white
+        let white = DigitalRGBAFloatColor{ r: 1.0, g: 1.0, b: 1.0, a: 1.0 };
+        let color = DigitalXYZColor::from_linear_rgb(white, RGBPrimaries::SRGB, WhitePoint::D50);
+        assert_close(color.into(), WhitePoint::D50.xyz().into(), 1e-5);
+
+        // Display P3 has a wider gamut, its red is out of the sRGB gamut
+        let color = DigitalXYZColor::from_linear_rgb(red, RGBPrimaries::DISPLAY_P3, WhitePoint::D65);
+        let rgb = color.to_linear_rgb(RGBPrimaries::SRGB, WhitePoint::D65);
+        assert!(rgb.r > 1.0 && rgb.g < 0.0 && rgb.b < 0.0, "{:?}", rgb);
+
+        let back = color.to_linear_rgb(RGBPrimaries::DISPLAY_P3, WhitePoint::D65);
+        assert_close([back.r, back.g, back.b], [1.0, 0.0, 0.0], 1e-5);
+    }
+
+    #[test]
+    fn adapt() {
+        let color = WhitePoint::D65.xyz().adapt(WhitePoint::D65, WhitePoint::D50);
+        assert_close(color.into(), WhitePoint::D50.xyz().into(), 1e-5);
+
+        let lab = DigitalLabColor{ l: 54.29, a: 80.81, b: 69.89 }.adapt(WhitePoint::D50, WhitePoint::D65);
+        assert_close(lab.into(), [53.24, 80.09, 67.20], 0.1);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for r in (0..256).step_by(15) {
+            for g in (0..256).step_by(15) {
+                for b in (0..256).step_by(15) {
+                    let rgba = DigitalRGBAColor{ r: r as u8, g: g as u8, b: b as u8, a: 255 };
+                    let back: DigitalRGBAColor = DigitalLabColor::from(rgba).into();
+                    assert_eq!(back, rgba);
+
+                    let lab = DigitalLabColor::from(rgba).adapt(WhitePoint::D65, WhitePoint::D50);
+                    let back: DigitalRGBAColor = lab.adapt(WhitePoint::D50, WhitePoint::D65).into();
+                    assert_eq!(back, rgba);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod ycbcra {
+    use super::*;
+
+    #[test]
+    fn rgba() {
+        let rgba = DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 51 };
+        let ycbcr = DigitalYCbCrColor::from(rgba);
+
+        let color = DigitalYCbCrAColor::from(rgba);
+        assert_eq!(color, DigitalYCbCrAColor{ y: ycbcr.y, cb: ycbcr.cb, cr: ycbcr.cr, a: 51 });
+
+        let back = DigitalRGBAColor::from(color);
+        assert_eq!(back, DigitalRGBAColor{ a: 51, ..DigitalRGBAColor::from(ycbcr) });
+    }
+
+    #[test]
+    fn ycbcr() {
+        let color: DigitalYCbCrAColor = DigitalYCbCrColor{ y: 1, cb: 2, cr: 3 }.into();
+        assert_eq!(color, DigitalYCbCrAColor{ y: 1, cb: 2, cr: 3, a: 255 });
+
+        let color: DigitalYCbCrColor = DigitalYCbCrAColor{ y: 1, cb: 2, cr: 3, a: 0 }.into();
+        assert_eq!(color, DigitalYCbCrColor{ y: 1, cb: 2, cr: 3 });
+    }
+
+    #[test]
+    fn alpha() {
+        for a in 0..256 {
+            let rgba64 = DigitalRGBA64Color::from(DigitalRGBAColor{ r: 0, g: 0, b: 0, a: a as u8 });
+            let back: DigitalRGBA64Color = DigitalYCbCrAColor::from(rgba64).into();
+            assert_eq!(back.a, rgba64.a);
+        }
+    }
+}
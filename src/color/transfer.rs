@@ -0,0 +1,38 @@
+//! Transfer functions between linear light and encoded component values
+
+/// Transfer function (opto-electronic transfer characteristic) of encoded components
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TransferFunction {
+    /// Components are stored in linear light
+    Linear,
+    /// IEC 61966-2-1 sRGB curve, used by 8 bit RGB images unless stated otherwise
+    SRGB
+}
+
+impl TransferFunction {
+    /// Convert an encoded value in the range `0.0..=1.0` to linear light
+    pub fn decode(&self, value: f32) -> f32 {
+        match self {
+            TransferFunction::Linear => value,
+            TransferFunction::SRGB =>
+                if value <= 0.04045 {
+                    value / 12.92
+                } else {
+                    ((value + 0.055) / 1.055).powf(2.4)
+                }
+        }
+    }
+
+    /// Convert a linear light value in the range `0.0..=1.0` to the encoded value
+    pub fn encode(&self, value: f32) -> f32 {
+        match self {
+            TransferFunction::Linear => value,
+            TransferFunction::SRGB =>
+                if value <= 0.0031308 {
+                    value * 12.92
+                } else {
+                    1.055 * value.powf(1.0 / 2.4) - 0.055
+                }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! 16 bit per channel YCbCr color type
+
+use super::*;
+pub use grapho_color::DigitalGrayscaleColor;
+
+/// YCbCr color with 16 bit per component
+///
+/// Components are MSB aligned, lower bit depths (10, 12 bit) use the upper bits.
+/// Converting from 8 bit shifts the value into the upper byte so the neutral
+/// chroma value `128` maps to `32768`, converting back rounds to the nearest value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalYCbCr48Color {
+    /// luma
+    pub y: u16,
+    /// blue chroma
+    pub cb: u16,
+    /// red chroma
+    pub cr: u16
+}
+
+/// Shift an 8 bit component into the upper byte of a 16 bit component
+fn shift_u8(value: u8) -> u16 {
+    (value as u16) << 8
+}
+
+/// Round a 16 bit component to its upper byte
+fn round_u16(value: u16) -> u8 {
+    ((value as u32 + 128) >> 8).min(255) as u8
+}
+
+/// Round a 16 bit component to `bits` significant bits, keeping it MSB aligned
+pub(crate) fn quantize_msb(value: u16, bits: u32) -> u16 {
+    let shift = 16 - bits;
+    if shift == 0 {
+        return value;
+    }
+
+    let max = (1u32 << bits) - 1;
+    let quantized = ((value as u32 + (1 << (shift - 1))) >> shift).min(max);
+
+    (quantized << shift) as u16
+}
+
+/// Ratio between the full scale of `DigitalRGBA64Color` (`65535`) and the full scale
+/// of this type (`65280`, 8 bit `255` shifted into the upper byte)
+const FULL_SCALE: f32 = 65535.0 / 65280.0;
+
+/// Clamp an intermediate float result into the `u16` range
+fn clamp_u16(value: f32) -> u16 {
+    value.round().max(0.0).min(65535.0) as u16
+}
+
+impl From<[u16; 3]> for DigitalYCbCr48Color {
+    fn from(c: [u16; 3]) -> Self {
+        DigitalYCbCr48Color { y: c[0], cb: c[1], cr: c[2] }
+    }
+}
+
+impl From<DigitalYCbCr48Color> for [u16; 3] {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        [c.y, c.cb, c.cr]
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalYCbCr48Color {
+            y: shift_u8(c.y),
+            cb: shift_u8(c.cb),
+            cr: shift_u8(c.cr)
+        }
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalYCbCrColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalYCbCrColor {
+            y: round_u16(c.y),
+            cb: round_u16(c.cb),
+            cr: round_u16(c.cr)
+        }
+    }
+}
+
+/// Full range BT.601 conversion without going through 8 bit, the result is rescaled
+/// so RGB full scale maps to the same value as 8 bit `255`
+impl From<DigitalRGBA64Color> for DigitalYCbCr48Color {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        let (r, g, b) = (c.r as f32 / FULL_SCALE, c.g as f32 / FULL_SCALE, c.b as f32 / FULL_SCALE);
+
+        DigitalYCbCr48Color {
+            y: clamp_u16(0.299 * r + 0.587 * g + 0.114 * b),
+            cb: clamp_u16(32768.0 - 0.168736 * r - 0.331264 * g + 0.5 * b),
+            cr: clamp_u16(32768.0 + 0.5 * r - 0.418688 * g - 0.081312 * b)
+        }
+    }
+}
+
+/// Full range BT.601 conversion without going through 8 bit, the components are
+/// rescaled like the `DigitalGray16Color` conversion so 8 bit `255` maps to `65535`
+impl From<DigitalYCbCr48Color> for DigitalRGBA64Color {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        let y = c.y as f32 * FULL_SCALE;
+        let cb = (c.cb as f32 - 32768.0) * FULL_SCALE;
+        let cr = (c.cr as f32 - 32768.0) * FULL_SCALE;
+
+        DigitalRGBA64Color {
+            r: clamp_u16(y + 1.402 * cr),
+            g: clamp_u16(y - 0.344136 * cb - 0.714136 * cr),
+            b: clamp_u16(y + 1.772 * cb),
+            a: 65535
+        }
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalRGBAColor) -> Self {
+        DigitalYCbCrColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalRGBAColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalYCbCrColor::from(c).into()
+    }
+}
+
+impl From<DigitalGrayscaleColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalGrayscaleColor) -> Self {
+        DigitalYCbCrColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalGrayscaleColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalYCbCrColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalYCbCrColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalYCoCgColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalYCbCrColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalYCbCrColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalYCoCgRColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalYCbCrColor::from(c).into()
+    }
+}
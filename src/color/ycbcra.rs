@@ -0,0 +1,204 @@
+//! YCbCr color type with alpha channel
+
+use super::*;
+pub use grapho_color::DigitalGrayscaleColor;
+
+/// YCbCr color with alpha, 8 bit per component
+///
+/// Luma and chroma convert like `DigitalYCbCrColor`, alpha is carried over unchanged
+/// from and to `DigitalRGBAColor`. Converting from `DigitalYCbCrColor` is opaque.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalYCbCrAColor {
+    /// luma
+    pub y: u8,
+    /// blue chroma
+    pub cb: u8,
+    /// red chroma
+    pub cr: u8,
+    /// alpha
+    pub a: u8
+}
+
+impl From<[u8; 4]> for DigitalYCbCrAColor {
+    fn from(c: [u8; 4]) -> Self {
+        DigitalYCbCrAColor { y: c[0], cb: c[1], cr: c[2], a: c[3] }
+    }
+}
+
+impl From<DigitalYCbCrAColor> for [u8; 4] {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        [c.y, c.cb, c.cr, c.a]
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalYCbCrAColor {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalYCbCrAColor { y: c.y, cb: c.cb, cr: c.cr, a: 255 }
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalYCbCrColor {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalYCbCrColor { y: c.y, cb: c.cb, cr: c.cr }
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalYCbCrAColor {
+    fn from(c: DigitalRGBAColor) -> Self {
+        let ycbcr = DigitalYCbCrColor::from(c);
+
+        DigitalYCbCrAColor { y: ycbcr.y, cb: ycbcr.cb, cr: ycbcr.cr, a: c.a }
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalRGBAColor {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        let rgba = DigitalRGBAColor::from(DigitalYCbCrColor::from(c));
+
+        DigitalRGBAColor { a: c.a, ..rgba }
+    }
+}
+
+impl From<DigitalGrayscaleColor> for DigitalYCbCrAColor {
+    fn from(c: DigitalGrayscaleColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalGrayscaleColor {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalYCbCrAColor {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalYCoCgColor {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalYCbCrAColor {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalYCoCgRColor {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBA64Color> for DigitalYCbCrAColor {
+    fn from(c: DigitalRGBA64Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalRGBA64Color {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCr48Color> for DigitalYCbCrAColor {
+    fn from(c: DigitalYCbCr48Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalYCbCr48Color {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalGray16Color> for DigitalYCbCrAColor {
+    fn from(c: DigitalGray16Color) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalGray16Color {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalRGBAFloatColor> for DigitalYCbCrAColor {
+    fn from(c: DigitalRGBAFloatColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalRGBAFloatColor {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalCMYKColor> for DigitalYCbCrAColor {
+    fn from(c: DigitalCMYKColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalCMYKColor {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalYCbCrAColor {
+    fn from(c: DigitalHSVColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalHSVColor {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalYCbCrAColor {
+    fn from(c: DigitalHSLColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalHSLColor {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalXYZColor> for DigitalYCbCrAColor {
+    fn from(c: DigitalXYZColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalXYZColor {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalLabColor> for DigitalYCbCrAColor {
+    fn from(c: DigitalLabColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalLabColor {
+    fn from(c: DigitalYCbCrAColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
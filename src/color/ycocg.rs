@@ -0,0 +1,68 @@
+//! YCoCg color type
+
+use super::*;
+
+/// YCoCg color, `co` and `cg` are stored with an offset of 128
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalYCoCgColor {
+    /// luma
+    pub y: u8,
+    /// orange chroma
+    pub co: u8,
+    /// green chroma
+    pub cg: u8
+}
+
+impl From<[u8; 3]> for DigitalYCoCgColor {
+    fn from(c: [u8; 3]) -> Self {
+        DigitalYCoCgColor { y: c[0], co: c[1], cg: c[2] }
+    }
+}
+
+impl From<DigitalYCoCgColor> for [u8; 3] {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        [c.y, c.co, c.cg]
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalYCoCgColor {
+    fn from(c: DigitalRGBAColor) -> Self {
+        let r = c.r as i32;
+        let g = c.g as i32;
+        let b = c.b as i32;
+
+        DigitalYCoCgColor {
+            y: clamp_u8((r + 2 * g + b + 2) >> 2),
+            co: clamp_u8(((r - b + 1) >> 1) + 128),
+            cg: clamp_u8(((2 * g - r - b + 2) >> 2) + 128)
+        }
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalRGBAColor {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        let y = c.y as i32;
+        let co = c.co as i32 - 128;
+        let cg = c.cg as i32 - 128;
+        let tmp = y - cg;
+
+        DigitalRGBAColor {
+            r: clamp_u8(tmp + co),
+            g: clamp_u8(y + cg),
+            b: clamp_u8(tmp - co),
+            a: 255
+        }
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalYCoCgColor {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalYCbCrColor {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
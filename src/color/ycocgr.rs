@@ -0,0 +1,68 @@
+//! Reversible YCoCg-R color type
+
+use super::*;
+
+/// YCoCg-R color, the lossless lifting variant of YCoCg
+///
+/// `co` and `cg` need one bit more than the RGB components, so they are
+/// stored as signed values in the range `-255..=255` without an offset.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitalYCoCgRColor {
+    /// luma
+    pub y: u8,
+    /// orange chroma
+    pub co: i16,
+    /// green chroma
+    pub cg: i16
+}
+
+impl From<DigitalRGBAColor> for DigitalYCoCgRColor {
+    fn from(c: DigitalRGBAColor) -> Self {
+        let co = c.r as i16 - c.b as i16;
+        let t = c.b as i16 + (co >> 1);
+        let cg = c.g as i16 - t;
+        let y = t + (cg >> 1);
+
+        DigitalYCoCgRColor { y: y as u8, co, cg }
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalRGBAColor {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        let t = c.y as i32 - (c.cg as i32 >> 1);
+        let g = c.cg as i32 + t;
+        let b = t - (c.co as i32 >> 1);
+        let r = b + c.co as i32;
+
+        DigitalRGBAColor {
+            r: clamp_u8(r),
+            g: clamp_u8(g),
+            b: clamp_u8(b),
+            a: 255
+        }
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalYCoCgRColor {
+    fn from(c: DigitalYCbCrColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalYCbCrColor {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgColor> for DigitalYCoCgRColor {
+    fn from(c: DigitalYCoCgColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalYCoCgColor {
+    fn from(c: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(c).into()
+    }
+}
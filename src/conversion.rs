@@ -1,4 +1,5 @@
 //! This module contains bitplane and color conversions
+
 use crate::PixelBuffer;
 
 macro_rules! conversion {
@@ -18,34 +19,1326 @@ macro_rules! conversion {
     )
 }
 
+macro_rules! conversion_alpha {
+    ($t:ty, $u:ty, $opaque:expr, $alpha:expr) => (
+
+        impl From<$t> for $u {
+
+            fn from(f: $t) -> Self {
+                let fourcc = if f.has_alpha() { $alpha } else { $opaque };
+                let mut buffer:$u = <$u>::new(f.get_width(), f.get_height(), None, Some(fourcc));
+                for (x, y, color) in f {
+                    buffer.set_pixel(x, y, color.into()).unwrap();
+                }
+
+                buffer
+            }
+        }
+    )
+}
+
+macro_rules! conversion_fourcc {
+    ($t:ty, $u:ty, $fourcc:expr) => (
+
+        impl From<$t> for $u {
+
+            fn from(f: $t) -> Self {
+                let mut buffer:$u = <$u>::new(f.get_width(), f.get_height(), None, Some($fourcc));
+                for (x, y, color) in f {
+                    buffer.set_pixel(x, y, color.into()).unwrap();
+                }
+
+                buffer
+            }
+        }
+    )
+}
+
+macro_rules! conversion_palette {
+    ($t:ty) => (
+
+        impl From<$t> for PalettePixelBuffer<'_> {
+
+            fn from(f: $t) -> Self {
+                let (width, height) = (f.get_width(), f.get_height());
+                let pixels = f.into_iter().map(|(x, y, color)| (x, y, color.into())).collect();
+
+                PalettePixelBuffer::from_pixels(width, height, pixels)
+            }
+        }
+    )
+}
+
+macro_rules! conversion_mosaic {
+    ($t:ty, $fourcc:expr) => (
+
+        impl From<$t> for BayerPixelBuffer<'_> {
+
+            fn from(f: $t) -> Self {
+                let mut buffer = BayerPixelBuffer::new(f.get_width(), f.get_height(), None, Some($fourcc));
+                for (x, y, color) in f {
+                    let rgb: [u16; 4] = DigitalRGBA64Color::from(color).into();
+                    let sample = rgb[buffer.get_channel(x, y) as usize];
+                    buffer.set_pixel(x, y, DigitalGray16Color { v: sample }).unwrap();
+                }
+
+                buffer
+            }
+        }
+    )
+}
+
+macro_rules! conversion_adapt {
+    ($t:ty, $u:ty) => (
+
+        impl From<$t> for $u {
+
+            fn from(f: $t) -> Self {
+                let white = f.get_white_point();
+                let mut buffer:$u = <$u>::new(f.get_width(), f.get_height(), None, None);
+                for (x, y, color) in f {
+                    buffer.set_pixel(x, y, color.adapt(white, WhitePoint::D65).into()).unwrap();
+                }
+
+                buffer
+            }
+        }
+    );
+    ($t:ty) => (
+
+        impl From<$t> for PalettePixelBuffer<'_> {
+
+            fn from(f: $t) -> Self {
+                let (width, height, white) = (f.get_width(), f.get_height(), f.get_white_point());
+                let pixels = f.into_iter().map(|(x, y, color)| (x, y, color.adapt(white, WhitePoint::D65).into())).collect();
+
+                PalettePixelBuffer::from_pixels(width, height, pixels)
+            }
+        }
+    )
+}
+
 use crate::rgb::RGBPixelBuffer;
 use crate::grayscale::GrayscalePixelBuffer;
 use crate::yuv422i::YUV422iPixelBuffer;
 use crate::yuv444i::YUV444iPixelBuffer;
 use crate::yuv422p::YUV422pPixelBuffer;
+use crate::yuv422pi::YUV422piPixelBuffer;
+use crate::ycocg444i::YCoCg444iPixelBuffer;
+use crate::ycocg444p::YCoCg444pPixelBuffer;
+use crate::ycocg422p::YCoCg422pPixelBuffer;
+use crate::ycocg422i::YCoCg422iPixelBuffer;
+use crate::ycocgr::YCoCgRPixelBuffer;
+use crate::yuv420p::YUV420pPixelBuffer;
+use crate::yuv444p::YUV444pPixelBuffer;
+use crate::rgb48::RGB48PixelBuffer;
+use crate::p010::P010PixelBuffer;
+use crate::v210::V210PixelBuffer;
+use crate::yuv422i::Y210PixelBuffer;
+use crate::rgb16::RGB16PixelBuffer;
+use crate::bitfield::BitfieldPixelBuffer;
+use crate::rgb30::RGB30PixelBuffer;
+use crate::gray16::Gray16PixelBuffer;
+use crate::packedgray::PackedGrayPixelBuffer;
+use crate::palette::PalettePixelBuffer;
+use crate::rgbfloat::RGBFloatPixelBuffer;
+use crate::bayer::{ BayerPixelBuffer, DemosaicAlgorithm };
+use crate::mipiraw::MipiRawPixelBuffer;
+use crate::cmyki::CMYKiPixelBuffer;
+use crate::cmykp::CMYKpPixelBuffer;
+use crate::hsv::HSVPixelBuffer;
+use crate::hsl::HSLPixelBuffer;
+use crate::xyz::XYZPixelBuffer;
+use crate::lab::LabPixelBuffer;
+use crate::color::{ DigitalLabColor, WhitePoint };
+use crate::yuvap::YUVApPixelBuffer;
+use crate::yuv411i::YUV411iPixelBuffer;
+use crate::yuv411p::YUV411pPixelBuffer;
+use crate::color::{ DigitalRGBA64Color, DigitalGray16Color, DigitalRGBAColor, DigitalYCbCrAColor };
 
 
 conversion!(RGBPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
 conversion!(RGBPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
 conversion!(RGBPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
 conversion!(RGBPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(RGBPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
 
 conversion!(YUV422iPixelBuffer<'_>, RGBPixelBuffer<'_>);
 conversion!(YUV422iPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
 conversion!(YUV422iPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
 conversion!(YUV422iPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
 
 conversion!(GrayscalePixelBuffer<'_>, RGBPixelBuffer<'_>);
 conversion!(GrayscalePixelBuffer<'_>, YUV422iPixelBuffer<'_>);
 conversion!(GrayscalePixelBuffer<'_>, YUV422pPixelBuffer<'_>);
 conversion!(GrayscalePixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, YUV422piPixelBuffer<'_>);
 
-conversion!(YUV444iPixelBuffer<'_>, RGBPixelBuffer<'_>);
 conversion!(YUV444iPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
 conversion!(YUV444iPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
 conversion!(YUV444iPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
 
 conversion!(YUV422pPixelBuffer<'_>, RGBPixelBuffer<'_>);
 conversion!(YUV422pPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
 conversion!(YUV422pPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
 conversion!(YUV422pPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+
+conversion!(YUV422piPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(RGBPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(RGBPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(RGBPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+
+conversion!(YUV422iPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+
+conversion!(YUV444iPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+
+conversion!(YUV422pPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+
+conversion!(YUV422piPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+
+conversion!(YCoCg444iPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+
+conversion!(YCoCg444pPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+
+conversion!(YCoCg422pPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+
+conversion!(YCoCg422iPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion!(YCoCgRPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+
+conversion!(YUV420pPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+
+conversion!(YUV444pPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion_alpha!(RGBPixelBuffer<'_>, RGB48PixelBuffer<'_>, "RGB48LE", "RGBA64LE");
+conversion_alpha!(RGB48PixelBuffer<'_>, RGBPixelBuffer<'_>, "RGB", "RGBA");
+conversion!(GrayscalePixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+
+conversion!(RGB48PixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, P010PixelBuffer<'_>);
+
+conversion!(P010PixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion!(YUV422iPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(RGBPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, V210PixelBuffer<'_>);
+
+conversion_fourcc!(V210PixelBuffer<'_>, YUV422iPixelBuffer<'_>, "UYVY");
+conversion!(V210PixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, Y210PixelBuffer<'_>);
+
+conversion!(Y210PixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion_alpha!(RGBPixelBuffer<'_>, RGB16PixelBuffer<'_>, "RGB565", "ARGB4444");
+conversion_alpha!(RGB48PixelBuffer<'_>, RGB16PixelBuffer<'_>, "RGB565", "ARGB4444");
+conversion!(GrayscalePixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+
+conversion_alpha!(RGB16PixelBuffer<'_>, RGBPixelBuffer<'_>, "RGB", "RGBA");
+conversion_alpha!(RGB16PixelBuffer<'_>, RGB48PixelBuffer<'_>, "RGB48LE", "RGBA64LE");
+conversion!(RGB16PixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion_alpha!(RGBPixelBuffer<'_>, BitfieldPixelBuffer<'_>, "32:00ff0000:0000ff00:000000ff", "32:00ff0000:0000ff00:000000ff:ff000000");
+conversion_alpha!(RGB48PixelBuffer<'_>, BitfieldPixelBuffer<'_>, "32:00ff0000:0000ff00:000000ff", "32:00ff0000:0000ff00:000000ff:ff000000");
+conversion!(RGB16PixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+
+conversion_alpha!(BitfieldPixelBuffer<'_>, RGBPixelBuffer<'_>, "RGB", "RGBA");
+conversion_alpha!(BitfieldPixelBuffer<'_>, RGB48PixelBuffer<'_>, "RGB48LE", "RGBA64LE");
+conversion_alpha!(BitfieldPixelBuffer<'_>, RGB16PixelBuffer<'_>, "RGB565", "ARGB4444");
+conversion!(BitfieldPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion_alpha!(RGBPixelBuffer<'_>, RGB30PixelBuffer<'_>, "XR30", "AR30");
+conversion_alpha!(RGB48PixelBuffer<'_>, RGB30PixelBuffer<'_>, "XR30", "AR30");
+conversion!(RGB16PixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion_alpha!(BitfieldPixelBuffer<'_>, RGB30PixelBuffer<'_>, "XR30", "AR30");
+conversion!(GrayscalePixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+
+conversion_alpha!(RGB30PixelBuffer<'_>, RGBPixelBuffer<'_>, "RGB", "RGBA");
+conversion_alpha!(RGB30PixelBuffer<'_>, RGB48PixelBuffer<'_>, "RGB48LE", "RGBA64LE");
+conversion!(RGB30PixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+
+conversion!(Gray16PixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+
+conversion!(PackedGrayPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, Y210PixelBuffer<'_>);
+
+conversion_palette!(RGBPixelBuffer<'_>);
+conversion_palette!(RGB48PixelBuffer<'_>);
+conversion_palette!(RGB16PixelBuffer<'_>);
+conversion_palette!(RGB30PixelBuffer<'_>);
+conversion_palette!(BitfieldPixelBuffer<'_>);
+conversion_palette!(GrayscalePixelBuffer<'_>);
+conversion_palette!(Gray16PixelBuffer<'_>);
+conversion_palette!(PackedGrayPixelBuffer<'_>);
+conversion_palette!(YUV422iPixelBuffer<'_>);
+conversion_palette!(YUV444iPixelBuffer<'_>);
+conversion_palette!(YUV444pPixelBuffer<'_>);
+conversion_palette!(YUV420pPixelBuffer<'_>);
+conversion_palette!(YUV422pPixelBuffer<'_>);
+conversion_palette!(YUV422piPixelBuffer<'_>);
+conversion_palette!(P010PixelBuffer<'_>);
+conversion_palette!(V210PixelBuffer<'_>);
+conversion_palette!(Y210PixelBuffer<'_>);
+conversion_palette!(YCoCg444iPixelBuffer<'_>);
+conversion_palette!(YCoCg444pPixelBuffer<'_>);
+conversion_palette!(YCoCg422pPixelBuffer<'_>);
+conversion_palette!(YCoCg422iPixelBuffer<'_>);
+conversion_palette!(YCoCgRPixelBuffer<'_>);
+
+impl From<PalettePixelBuffer<'_>> for RGBPixelBuffer<'_> {
+
+    fn from(f: PalettePixelBuffer<'_>) -> Self {
+        let fourcc = if f.get_palette().iter().any(|color| color.a != 255) { "RGBA" } else { "RGB" };
+        let mut buffer = RGBPixelBuffer::new(f.get_width(), f.get_height(), None, Some(fourcc));
+        for (x, y, color) in f {
+            buffer.set_pixel(x, y, color).unwrap();
+        }
+
+        buffer
+    }
+}
+
+conversion!(PalettePixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+
+conversion_alpha!(RGBPixelBuffer<'_>, RGBFloatPixelBuffer<'_>, "RGB32F", "RGBA32F");
+conversion_alpha!(RGB48PixelBuffer<'_>, RGBFloatPixelBuffer<'_>, "RGB32F", "RGBA32F");
+conversion_alpha!(RGB16PixelBuffer<'_>, RGBFloatPixelBuffer<'_>, "RGB32F", "RGBA32F");
+conversion_alpha!(RGB30PixelBuffer<'_>, RGBFloatPixelBuffer<'_>, "RGB32F", "RGBA32F");
+conversion_alpha!(BitfieldPixelBuffer<'_>, RGBFloatPixelBuffer<'_>, "RGB32F", "RGBA32F");
+conversion!(GrayscalePixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion_fourcc!(PalettePixelBuffer<'_>, RGBFloatPixelBuffer<'_>, "RGBA32F");
+
+conversion_alpha!(RGBFloatPixelBuffer<'_>, RGBPixelBuffer<'_>, "RGB", "RGBA");
+conversion_alpha!(RGBFloatPixelBuffer<'_>, RGB48PixelBuffer<'_>, "RGB48LE", "RGBA64LE");
+conversion_alpha!(RGBFloatPixelBuffer<'_>, RGB16PixelBuffer<'_>, "RGB565", "ARGB4444");
+conversion_alpha!(RGBFloatPixelBuffer<'_>, RGB30PixelBuffer<'_>, "XR30", "AR30");
+conversion_alpha!(RGBFloatPixelBuffer<'_>, BitfieldPixelBuffer<'_>, "32:00ff0000:0000ff00:000000ff", "32:00ff0000:0000ff00:000000ff:ff000000");
+conversion!(RGBFloatPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion_palette!(RGBFloatPixelBuffer<'_>);
+
+conversion_mosaic!(RGBPixelBuffer<'_>, "RGGB");
+conversion_mosaic!(RGB48PixelBuffer<'_>, "RGGB16");
+
+impl From<BayerPixelBuffer<'_>> for RGBPixelBuffer<'_> {
+
+    fn from(f: BayerPixelBuffer<'_>) -> Self {
+        f.demosaic(DemosaicAlgorithm::Bilinear)
+    }
+}
+
+impl From<BayerPixelBuffer<'_>> for RGB48PixelBuffer<'_> {
+
+    fn from(f: BayerPixelBuffer<'_>) -> Self {
+        f.demosaic48(DemosaicAlgorithm::Bilinear)
+    }
+}
+
+conversion_fourcc!(MipiRawPixelBuffer<'_>, BayerPixelBuffer<'_>, "RGGB16");
+conversion_fourcc!(MipiRawPixelBuffer<'_>, Gray16PixelBuffer<'_>, "GRAY16LE");
+
+conversion!(RGBPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+
+conversion!(CMYKiPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion_palette!(CMYKiPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+
+conversion!(CMYKpPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion_palette!(CMYKpPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, HSVPixelBuffer<'_>);
+
+conversion!(HSVPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion_palette!(HSVPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, HSLPixelBuffer<'_>);
+
+conversion!(HSLPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion_palette!(HSLPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, XYZPixelBuffer<'_>);
+
+conversion_adapt!(XYZPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, LabPixelBuffer<'_>);
+
+conversion_adapt!(LabPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>);
+
+impl From<XYZPixelBuffer<'_>> for LabPixelBuffer<'_> {
+
+    fn from(f: XYZPixelBuffer<'_>) -> Self {
+        let white = f.get_white_point();
+        let fourcc = if white == WhitePoint::D50 { "LABD50" } else { "LABD65" };
+        let mut buffer = LabPixelBuffer::new(f.get_width(), f.get_height(), None, Some(fourcc));
+        for (x, y, color) in f {
+            buffer.set_pixel(x, y, DigitalLabColor::from_xyz(color, white)).unwrap();
+        }
+
+        buffer
+    }
+}
+
+impl From<LabPixelBuffer<'_>> for XYZPixelBuffer<'_> {
+
+    fn from(f: LabPixelBuffer<'_>) -> Self {
+        let white = f.get_white_point();
+        let fourcc = if white == WhitePoint::D50 { "XYZD50" } else { "XYZD65" };
+        let mut buffer = XYZPixelBuffer::new(f.get_width(), f.get_height(), None, Some(fourcc));
+        for (x, y, color) in f {
+            buffer.set_pixel(x, y, color.to_xyz(white)).unwrap();
+        }
+
+        buffer
+    }
+}
+
+conversion!(RGBPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+
+conversion_fourcc!(YUVApPixelBuffer<'_>, RGBPixelBuffer<'_>, "RGBA");
+conversion_fourcc!(YUVApPixelBuffer<'_>, RGB48PixelBuffer<'_>, "RGBA64LE");
+conversion_fourcc!(YUVApPixelBuffer<'_>, RGB16PixelBuffer<'_>, "ARGB4444");
+conversion!(YUVApPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion_fourcc!(YUVApPixelBuffer<'_>, BitfieldPixelBuffer<'_>, "32:00ff0000:0000ff00:000000ff:ff000000");
+conversion!(YUVApPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion_fourcc!(YUVApPixelBuffer<'_>, RGBFloatPixelBuffer<'_>, "RGBA32F");
+conversion!(YUVApPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion_palette!(YUVApPixelBuffer<'_>);
+
+impl From<YUV444iPixelBuffer<'_>> for RGBPixelBuffer<'_> {
+
+    fn from(f: YUV444iPixelBuffer<'_>) -> Self {
+        let fourcc = if f.has_alpha() { "RGBA" } else { "RGB" };
+        let mut buffer = RGBPixelBuffer::new(f.get_width(), f.get_height(), None, Some(fourcc));
+        for y in 0..f.get_height() {
+            for x in 0..f.get_width() {
+                let color = DigitalRGBAColor::from(f.get_pixel(x, y).unwrap());
+                buffer.set_pixel(x, y, DigitalRGBAColor { a: f.get_alpha(x, y).unwrap(), ..color }).unwrap();
+            }
+        }
+
+        buffer
+    }
+}
+
+impl From<YUV444iPixelBuffer<'_>> for YUVApPixelBuffer<'_> {
+
+    fn from(f: YUV444iPixelBuffer<'_>) -> Self {
+        let mut buffer = YUVApPixelBuffer::new(f.get_width(), f.get_height(), None, None);
+        for y in 0..f.get_height() {
+            for x in 0..f.get_width() {
+                let color = f.get_pixel(x, y).unwrap();
+                buffer.set_pixel(x, y, DigitalYCbCrAColor {
+                    y: color.y, cb: color.cb, cr: color.cr, a: f.get_alpha(x, y).unwrap()
+                }).unwrap();
+            }
+        }
+
+        buffer
+    }
+}
+
+conversion!(RGBPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+
+conversion!(YUV411iPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion_palette!(YUV411iPixelBuffer<'_>);
+
+conversion!(RGBPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(RGB48PixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(RGB16PixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(RGB30PixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(BitfieldPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(GrayscalePixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(Gray16PixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(PackedGrayPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YUV422iPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YUV444iPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YUV444pPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YUV420pPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YUV422pPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YUV422piPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YUVApPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(P010PixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(V210PixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(Y210PixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YCoCg444iPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YCoCg444pPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YCoCg422pPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YCoCg422iPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YCoCgRPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(RGBFloatPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(CMYKiPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(CMYKpPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(HSVPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(HSLPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(YUV411iPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion!(PalettePixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion_adapt!(XYZPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+conversion_adapt!(LabPixelBuffer<'_>, YUV411pPixelBuffer<'_>);
+
+conversion!(YUV411pPixelBuffer<'_>, RGBPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, RGB48PixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, RGB16PixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, RGB30PixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, BitfieldPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, GrayscalePixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, Gray16PixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, PackedGrayPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YUV422iPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YUV444iPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YUV444pPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YUV420pPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YUV422pPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YUV422piPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YUVApPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, P010PixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, V210PixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, Y210PixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YCoCg444iPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YCoCg444pPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YCoCg422pPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YCoCg422iPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YCoCgRPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, RGBFloatPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, CMYKiPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, CMYKpPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, HSVPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, HSLPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, YUV411iPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, XYZPixelBuffer<'_>);
+conversion!(YUV411pPixelBuffer<'_>, LabPixelBuffer<'_>);
+conversion_palette!(YUV411pPixelBuffer<'_>);
@@ -0,0 +1,53 @@
+//! Create iterator for Gray16PixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::Gray16PixelBuffer;
+use crate::color::DigitalGray16Color;
+
+
+/// Pixel iterator for `Gray16PixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalGray16Color>`
+#[derive(Debug, PartialEq)]
+pub struct Gray16PixelIterator<'a> {
+    base: Gray16PixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for Gray16PixelIterator<'a> {
+    type Item = Pixel<DigitalGray16Color>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for Gray16PixelBuffer<'a> {
+    type Item = Pixel<DigitalGray16Color>;
+    type IntoIter = Gray16PixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Gray16PixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
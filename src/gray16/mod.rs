@@ -0,0 +1,231 @@
+//! This module describes a high bit depth grayscale buffer with 16 bit containers
+//! (`GRAY10`, `GRAY12`, `GRAY16`) in little or big endian byte order
+//!
+//! The significant bits are stored in the least significant bits of each container, or in the
+//! most significant bits for the MSB aligned variants. Reading expands the value to 16 bit by bit
+//! replication, writing rounds to the nearest representable value.
+
+use crate::*;
+pub use crate::color::DigitalGray16Color;
+use crate::color::{ expand_bits16, reduce_bits16 };
+
+/// High bit depth grayscale Pixel buffer
+#[derive(Debug, PartialEq)]
+pub struct Gray16PixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    bits: u32,
+    msb_aligned: bool,
+    big_endian: bool,
+    data: Vec<u8>
+}
+
+impl<'a> Gray16PixelBuffer<'a> {
+    /// Decode significant bits, MSB alignment and endianness from `GRAY<bits>[M][LE|BE]`
+    fn decode_format(fourcc:&'a str) -> (u32, bool, bool) {
+        let big_endian = fourcc.ends_with("BE");
+        let format = fourcc.trim_end_matches("LE").trim_end_matches("BE");
+        let msb_aligned = format.ends_with('M');
+
+        let bits = match format.trim_end_matches('M') {
+            "GRAY10" => 10,
+            "GRAY12" => 12,
+            "GRAY16" | _ => 16,
+        };
+
+        (bits, msb_aligned && bits < 16, big_endian)
+    }
+
+    fn read(&self, offset: usize) -> u16 {
+        let bytes = [self.data[offset], self.data[offset + 1]];
+        let raw = if self.big_endian { u16::from_be_bytes(bytes) } else { u16::from_le_bytes(bytes) };
+        let value = if self.msb_aligned {
+            raw >> (16 - self.bits)
+        } else {
+            raw & (((1u32 << self.bits) - 1) as u16)
+        };
+
+        expand_bits16(value as u32, self.bits)
+    }
+
+    fn encode(&self, value: u16) -> [u8; 2] {
+        let mut raw = reduce_bits16(value, self.bits) as u16;
+        if self.msb_aligned {
+            raw <<= 16 - self.bits;
+        }
+
+        if self.big_endian { raw.to_be_bytes() } else { raw.to_le_bytes() }
+    }
+}
+
+impl<'a> PixelBuffer<'a> for Gray16PixelBuffer<'a> {
+    type ColorType = DigitalGray16Color;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `2 * width`
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `GRAY16LE` (default), `GRAY16BE`, 16 significant bits
+    /// * `GRAY12LE`, `GRAY12BE`, 12 significant bits in the least significant bits
+    /// * `GRAY10LE`, `GRAY10BE`, 10 significant bits in the least significant bits
+    /// * `GRAY12MLE`, `GRAY12MBE`, `GRAY10MLE`, `GRAY10MBE`, MSB aligned variants
+    ///
+    /// Codes without an endianness suffix are little endian.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `Gray16PixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("GRAY16LE");
+        let (bits, msb_aligned, big_endian) = Gray16PixelBuffer::decode_format(f);
+        let line_width = stride.unwrap_or(width * 2);
+
+        Gray16PixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            bits,
+            msb_aligned,
+            big_endian
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `2 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `GRAY16LE` (default), `GRAY16BE`, 16 significant bits
+    /// * `GRAY12LE`, `GRAY12BE`, 12 significant bits in the least significant bits
+    /// * `GRAY10LE`, `GRAY10BE`, 10 significant bits in the least significant bits
+    /// * `GRAY12MLE`, `GRAY12MBE`, `GRAY10MLE`, `GRAY10MBE`, MSB aligned variants
+    ///
+    /// Codes without an endianness suffix are little endian.
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `Gray16PixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("GRAY16LE");
+        let (bits, msb_aligned, big_endian) = Gray16PixelBuffer::decode_format(f);
+        let line_width = stride.unwrap_or(width * 2);
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            Gray16PixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                bits,
+                msb_aligned,
+                big_endian
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `2 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `GRAY16LE` (default), `GRAY16BE`, 16 significant bits
+    /// * `GRAY12LE`, `GRAY12BE`, 12 significant bits in the least significant bits
+    /// * `GRAY10LE`, `GRAY10BE`, 10 significant bits in the least significant bits
+    /// * `GRAY12MLE`, `GRAY12MBE`, `GRAY10MLE`, `GRAY10MBE`, MSB aligned variants
+    ///
+    /// Codes without an endianness suffix are little endian.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `Gray16PixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = Gray16PixelBuffer::new(width, height, stride, fourcc);
+        let representation = buffer.encode(color.v);
+
+        let mut line = representation.repeat(width);
+        if buffer.stride > width * 2 {
+            line.extend([0].repeat(buffer.stride - width * 2));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 2 + y * self.stride;
+        let bytes = self.encode(color.v);
+
+        self.data[start] = bytes[0];
+        self.data[start + 1] = bytes[1];
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        Ok(DigitalGray16Color::from(self.read(x * 2 + y * self.stride)))
+    }
+}
+
+pub mod iter;
+mod tests;
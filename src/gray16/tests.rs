@@ -0,0 +1,157 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::gray16::Gray16PixelBuffer;
+pub use crate::grayscale::GrayscalePixelBuffer;
+pub use crate::color::DigitalGray16Color;
+pub use grapho_color::DigitalGrayscaleColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalGray16Color{ v: 0x1234 };
+        let buffer = Gray16PixelBuffer::new_with_background(2, 2, color, None, Some("GRAY16LE"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod gray16 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = Gray16PixelBuffer::new(2, 2, None, None);
+        assert_eq!(buffer.data.len(), 8);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 4);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 4);
+        assert_eq!(buffer.get_fourcc(), "GRAY16LE");
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 7];
+        match Gray16PixelBuffer::new_with_data(2, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = Gray16PixelBuffer::new_with_background(
+            2, 2,
+            DigitalGray16Color{ v: 0x1234 },
+            Some(6), Some("GRAY16BE")
+        );
+
+        assert_eq!(buffer.data, vec![0x12, 0x34, 0x12, 0x34, 0, 0, 0x12, 0x34, 0x12, 0x34, 0, 0]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = Gray16PixelBuffer::new(2, 2, None, Some("GRAY16LE"));
+
+        match buffer.set_pixel(1, 1, DigitalGray16Color{ v: 0x1234 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(0, 2, DigitalGray16Color{ v: 0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[6..8], [0x34, 0x12]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0x34, 0x12, 0xff, 0xff];
+        let buffer = Gray16PixelBuffer::new_with_data(2, 1, data, None, Some("GRAY16LE")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGray16Color{ v: 0x1234 });
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalGray16Color{ v: 0xffff });
+    }
+}
+
+#[cfg(test)]
+mod gray12 {
+    use super::*;
+
+    #[test]
+    fn lsb_aligned() {
+        let mut buffer = Gray16PixelBuffer::new(2, 1, None, Some("GRAY12LE"));
+        buffer.set_pixel(0, 0, DigitalGray16Color{ v: 0xffff }).unwrap();
+        buffer.set_pixel(1, 0, DigitalGray16Color{ v: 0x8008 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0xff, 0x0f, 0x00, 0x08]);
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGray16Color{ v: 0xffff });
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalGray16Color{ v: 0x8008 });
+    }
+
+    #[test]
+    fn msb_aligned() {
+        let mut buffer = Gray16PixelBuffer::new(1, 1, None, Some("GRAY12MBE"));
+        buffer.set_pixel(0, 0, DigitalGray16Color{ v: 0x1231 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0x12, 0x30]);
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGray16Color{ v: 0x1231 });
+    }
+
+    #[test]
+    fn ignores_padding_bits() {
+        let data = vec![0xff, 0xff];
+        let buffer = Gray16PixelBuffer::new_with_data(1, 1, data, None, Some("GRAY12LE")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGray16Color{ v: 0xffff });
+    }
+}
+
+#[cfg(test)]
+mod gray10 {
+    use super::*;
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0x02, 0x00, 0x00, 0x80];
+        let buffer = Gray16PixelBuffer::new_with_data(1, 1, data.clone(), None, Some("GRAY10LE")).unwrap();
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGray16Color{ v: 0x0080 });
+
+        let buffer = Gray16PixelBuffer::new_with_data(2, 1, data, None, Some("GRAY10MLE")).unwrap();
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalGray16Color{ v: 0x8020 });
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn grayscale_roundtrip() {
+        let mut gray = GrayscalePixelBuffer::new(256, 1, None, None);
+        for x in 0..256 {
+            gray.set_pixel(x, 0, DigitalGrayscaleColor{ v: x as u8 }).unwrap();
+        }
+
+        let gray16: Gray16PixelBuffer = gray.into();
+        assert_eq!(gray16.get_pixel(255, 0).unwrap(), DigitalGray16Color{ v: 0xffff });
+
+        let back: GrayscalePixelBuffer = gray16.into();
+        for (x, _y, color) in back {
+            assert_eq!(color, DigitalGrayscaleColor{ v: x as u8 });
+        }
+    }
+}
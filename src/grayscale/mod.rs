@@ -186,7 +186,7 @@ impl<'a> PixelBuffer<'a> for GrayscalePixelBuffer<'a> {
     }
 
     fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
-        if (x >= self.width) || (y >= self.width) {
+        if (x >= self.width) || (y >= self.height) {
             return Err(PixelBufferError::RequestOutOfBounds);
         }
 
@@ -202,7 +202,7 @@ impl<'a> PixelBuffer<'a> for GrayscalePixelBuffer<'a> {
     }
 
     fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
-        if (x >= self.width) || (y >= self.width) {
+        if (x >= self.width) || (y >= self.height) {
             return Err(PixelBufferError::RequestOutOfBounds);
         }
 
@@ -104,6 +104,29 @@ mod grayscale {
             Ok(_color) => assert!(false)
         }
     }
+
+    #[test]
+    fn non_square_bounds() {
+        let mut wide = GrayscalePixelBuffer::new(4, 2, None, Some("Y"));
+        match wide.set_pixel(0, 3, DigitalGrayscaleColor{ v: 64 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+        match wide.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+
+        let mut tall = GrayscalePixelBuffer::new(2, 4, None, Some("Y"));
+        match tall.set_pixel(1, 3, DigitalGrayscaleColor{ v: 64 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+        match tall.get_pixel(1, 3) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalGrayscaleColor{ v: 64 })
+        }
+    }
 }
 
 
@@ -120,4 +143,16 @@ mod iter {
             assert_eq!(pixel.2, color);
         }
     }
+
+    #[test]
+    fn iter_coordinates() {
+        let color = DigitalGrayscaleColor{ v: 64 };
+        let mut buffer = GrayscalePixelBuffer::new(2, 2, None, Some("Y"));
+        buffer.set_pixel(1, 0, color).unwrap();
+
+        let pixels: Vec<_> = buffer.into_iter().collect();
+        let coordinates: Vec<(usize, usize)> = pixels.iter().map(|pixel| (pixel.0, pixel.1)).collect();
+        assert_eq!(coordinates, vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+        assert_eq!(pixels[1].2, color);
+    }
 }
@@ -0,0 +1,53 @@
+//! Create iterator for HSLPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::HSLPixelBuffer;
+use crate::color::DigitalHSLColor;
+
+
+/// Pixel iterator for `HSLPixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalHSLColor>`
+#[derive(Debug, PartialEq)]
+pub struct HSLPixelIterator<'a> {
+    base: HSLPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for HSLPixelIterator<'a> {
+    type Item = Pixel<DigitalHSLColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for HSLPixelBuffer<'a> {
+    type Item = Pixel<DigitalHSLColor>;
+    type IntoIter = HSLPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        HSLPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
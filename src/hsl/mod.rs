@@ -0,0 +1,192 @@
+//! This module describes an interleaved HSL pixel buffer with 8 bit (`HSL`, `HSL180`) or
+//! 32 bit float (`HSL32F`) components
+//!
+//! The component storage is the same as for `HSVPixelBuffer`, with lightness in place of value.
+
+use crate::*;
+pub use crate::color::DigitalHSLColor;
+pub use crate::hsv::HueScaling;
+
+/// HSL Pixel buffer without alpha channel
+#[derive(Debug, PartialEq)]
+pub struct HSLPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    scaling: HueScaling,
+    data: Vec<u8>
+}
+
+impl<'a> HSLPixelBuffer<'a> {
+    fn decode_scaling(fourcc:&'a str) -> HueScaling {
+        match fourcc {
+            "HSL180" => HueScaling::Half,
+            "HSL" => HueScaling::Full,
+            "HSL32F" | _ => HueScaling::Float,
+        }
+    }
+
+    /// Storage of the components in this buffer
+    pub fn get_scaling(&self) -> HueScaling {
+        self.scaling
+    }
+}
+
+impl<'a> PixelBuffer<'a> for HSLPixelBuffer<'a> {
+    type ColorType = DigitalHSLColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `3 * width` for 8 bit, `12 * width` for float components
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `HSL32F` (default), 32 bit float, hue in degrees
+    /// * `HSL`, 8 bit, hue circle scaled to `0..256`
+    /// * `HSL180`, 8 bit, hue in 2 degree steps `0..180`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `HSLPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("HSL32F");
+        let scaling = HSLPixelBuffer::decode_scaling(f);
+        let line_width = stride.unwrap_or(width * scaling.pixel_size());
+
+        HSLPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            scaling
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `3 * width` for 8 bit, `12 * width` for float components
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `HSL32F` (default), 32 bit float, hue in degrees
+    /// * `HSL`, 8 bit, hue circle scaled to `0..256`
+    /// * `HSL180`, 8 bit, hue in 2 degree steps `0..180`
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `HSLPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("HSL32F");
+        let scaling = HSLPixelBuffer::decode_scaling(f);
+        let line_width = stride.unwrap_or(width * scaling.pixel_size());
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            HSLPixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                scaling
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `3 * width` for 8 bit, `12 * width` for float components
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `HSL32F` (default), 32 bit float, hue in degrees
+    /// * `HSL`, 8 bit, hue circle scaled to `0..256`
+    /// * `HSL180`, 8 bit, hue in 2 degree steps `0..180`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `HSLPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = HSLPixelBuffer::new(width, height, stride, fourcc);
+        let pixel_width = buffer.scaling.pixel_size();
+
+        let mut line = buffer.scaling.encode(color.into()).repeat(width);
+        if buffer.stride > width * pixel_width {
+            line.extend([0].repeat(buffer.stride - width * pixel_width));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let size = self.scaling.pixel_size();
+        let start = x * size + y * self.stride;
+        self.data[start..start + size].copy_from_slice(&self.scaling.encode(color.into()));
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let size = self.scaling.pixel_size();
+        let start = x * size + y * self.stride;
+
+        Ok(DigitalHSLColor::from(self.scaling.decode(&self.data[start..start + size])))
+    }
+}
+
+pub mod iter;
+mod tests;
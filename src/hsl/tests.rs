@@ -0,0 +1,163 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::hsl::{ HSLPixelBuffer, HueScaling };
+pub use crate::hsv::HSVPixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::color::{ DigitalHSLColor, DigitalHSVColor };
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalHSLColor{ h: 90.0, s: 0.2, l: 1.0 };
+        let buffer = HSLPixelBuffer::new_with_background(2, 2, color, None, Some("HSL"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod hsl {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = HSLPixelBuffer::new(2, 2, None, Some("HSL"));
+        assert_eq!(buffer.data.len(), 12);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 6);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 6);
+        assert_eq!(buffer.get_fourcc(), "HSL");
+        assert_eq!(buffer.get_scaling(), HueScaling::Full);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 11];
+        match HSLPixelBuffer::new_with_data(2, 2, data, None, Some("HSL")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = HSLPixelBuffer::new_with_background(
+            1, 2,
+            DigitalHSLColor{ h: 180.0, s: 1.0, l: 0.2 },
+            Some(4), Some("HSL")
+        );
+
+        assert_eq!(buffer.data, vec![128, 255, 51, 0, 128, 255, 51, 0]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = HSLPixelBuffer::new(2, 2, None, Some("HSL"));
+
+        match buffer.set_pixel(1, 1, DigitalHSLColor{ h: 30.0, s: 1.0, l: 64.0 / 255.0 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalHSLColor{ h: 30.0, s: 1.0, l: 0.5 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[9..12], [21, 255, 64]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0, 0, 0, 64, 255, 51];
+        let buffer = HSLPixelBuffer::new_with_data(2, 1, data, None, Some("HSL")).unwrap();
+
+        match buffer.get_pixel(1, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalHSLColor{ h: 90.0, s: 1.0, l: 0.2 })
+        }
+
+        match buffer.get_pixel(0, 1) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod hsl180 {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = HSLPixelBuffer::new(1, 1, None, Some("HSL180"));
+        buffer.set_pixel(0, 0, DigitalHSLColor{ h: 240.0, s: 0.5, l: 0.5 }).unwrap();
+
+        assert_eq!(buffer.get_scaling(), HueScaling::Half);
+        assert_eq!(buffer.data, vec![120, 128, 128]);
+    }
+}
+
+#[cfg(test)]
+mod hsl32f {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = HSLPixelBuffer::new(1, 1, None, Some("HSL32F"));
+        buffer.set_pixel(0, 0, DigitalHSLColor{ h: 240.0, s: 0.5, l: 0.25 }).unwrap();
+
+        assert_eq!(buffer.get_stride(), 12);
+        assert_eq!(buffer.get_scaling(), HueScaling::Float);
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalHSLColor{ h: 240.0, s: 0.5, l: 0.25 });
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb_roundtrip() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            2, 1,
+            DigitalRGBAColor{ r: 64, g: 64, b: 191, a: 255 },
+            None, Some("RGB")
+        );
+
+        let hsl: HSLPixelBuffer = rgb.into();
+        let color = hsl.get_pixel(1, 0).unwrap();
+        assert!((color.h - 240.0).abs() < 1e-3);
+        assert!((color.s - 127.0 / 255.0).abs() < 1e-5);
+        assert!((color.l - 0.5).abs() < 1e-5);
+
+        let back: RGBPixelBuffer = hsl.into();
+        for (_x, _y, color) in back {
+            assert_eq!(color, DigitalRGBAColor{ r: 64, g: 64, b: 191, a: 255 });
+        }
+    }
+
+    #[test]
+    fn hsv() {
+        let hsv = HSVPixelBuffer::new_with_background(
+            1, 1,
+            DigitalHSVColor{ h: 60.0, s: 1.0, v: 1.0 },
+            None, Some("HSV32F")
+        );
+
+        let hsl: HSLPixelBuffer = hsv.into();
+        assert_eq!(hsl.get_pixel(0, 0).unwrap(), DigitalHSLColor{ h: 60.0, s: 1.0, l: 0.5 });
+    }
+}
@@ -0,0 +1,53 @@
+//! Create iterator for HSVPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::HSVPixelBuffer;
+use crate::color::DigitalHSVColor;
+
+
+/// Pixel iterator for `HSVPixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalHSVColor>`
+#[derive(Debug, PartialEq)]
+pub struct HSVPixelIterator<'a> {
+    base: HSVPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for HSVPixelIterator<'a> {
+    type Item = Pixel<DigitalHSVColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for HSVPixelBuffer<'a> {
+    type Item = Pixel<DigitalHSVColor>;
+    type IntoIter = HSVPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        HSVPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
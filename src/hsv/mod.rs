@@ -0,0 +1,245 @@
+//! This module describes an interleaved HSV pixel buffer with 8 bit (`HSV`, `HSV180`) or
+//! 32 bit float (`HSV32F`) components
+//!
+//! The 8 bit formats scale saturation and value to `0..=255` and the hue circle either to
+//! `0..256` (`HSV`) or to 2 degree steps `0..180` (`HSV180`, as used by OpenCV). The default
+//! float format stores hue in degrees as little endian IEEE 754 floats, round trips to and
+//! from the RGB buffers are lossless with it.
+
+use crate::*;
+pub use crate::color::DigitalHSVColor;
+
+/// Storage of the components of a HSV or HSL pixel
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum HueScaling {
+    /// 8 bit components, the hue circle scaled to `0..256`
+    Full,
+    /// 8 bit components, hue in 2 degree steps `0..180`
+    Half,
+    /// 32 bit float components, hue in degrees
+    Float
+}
+
+impl HueScaling {
+    /// Size of one pixel in bytes
+    pub(crate) fn pixel_size(&self) -> usize {
+        match self {
+            HueScaling::Float => 12,
+            _ => 3
+        }
+    }
+
+    pub(crate) fn encode(&self, color: [f32; 3]) -> Vec<u8> {
+        let fraction = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let hue = color[0].rem_euclid(360.0);
+
+        match self {
+            HueScaling::Full =>
+                vec![((hue * 256.0 / 360.0).round() as u32 % 256) as u8, fraction(color[1]), fraction(color[2])],
+            HueScaling::Half =>
+                vec![((hue / 2.0).round() as u32 % 180) as u8, fraction(color[1]), fraction(color[2])],
+            HueScaling::Float =>
+                color.iter().flat_map(|value| value.to_le_bytes()).collect()
+        }
+    }
+
+    pub(crate) fn decode(&self, bytes: &[u8]) -> [f32; 3] {
+        match self {
+            HueScaling::Full =>
+                [bytes[0] as f32 * 360.0 / 256.0, bytes[1] as f32 / 255.0, bytes[2] as f32 / 255.0],
+            HueScaling::Half =>
+                [bytes[0] as f32 * 2.0, bytes[1] as f32 / 255.0, bytes[2] as f32 / 255.0],
+            HueScaling::Float => {
+                let mut color = [0.0; 3];
+                for (i, value) in color.iter_mut().enumerate() {
+                    *value = f32::from_le_bytes([bytes[i * 4], bytes[i * 4 + 1], bytes[i * 4 + 2], bytes[i * 4 + 3]]);
+                }
+                color
+            }
+        }
+    }
+}
+
+/// HSV Pixel buffer without alpha channel
+#[derive(Debug, PartialEq)]
+pub struct HSVPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    scaling: HueScaling,
+    data: Vec<u8>
+}
+
+impl<'a> HSVPixelBuffer<'a> {
+    fn decode_scaling(fourcc:&'a str) -> HueScaling {
+        match fourcc {
+            "HSV180" => HueScaling::Half,
+            "HSV" => HueScaling::Full,
+            "HSV32F" | _ => HueScaling::Float,
+        }
+    }
+
+    /// Storage of the components in this buffer
+    pub fn get_scaling(&self) -> HueScaling {
+        self.scaling
+    }
+}
+
+impl<'a> PixelBuffer<'a> for HSVPixelBuffer<'a> {
+    type ColorType = DigitalHSVColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `3 * width` for 8 bit, `12 * width` for float components
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `HSV32F` (default), 32 bit float, hue in degrees
+    /// * `HSV`, 8 bit, hue circle scaled to `0..256`
+    /// * `HSV180`, 8 bit, hue in 2 degree steps `0..180`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `HSVPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("HSV32F");
+        let scaling = HSVPixelBuffer::decode_scaling(f);
+        let line_width = stride.unwrap_or(width * scaling.pixel_size());
+
+        HSVPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            scaling
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `3 * width` for 8 bit, `12 * width` for float components
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `HSV32F` (default), 32 bit float, hue in degrees
+    /// * `HSV`, 8 bit, hue circle scaled to `0..256`
+    /// * `HSV180`, 8 bit, hue in 2 degree steps `0..180`
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `HSVPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("HSV32F");
+        let scaling = HSVPixelBuffer::decode_scaling(f);
+        let line_width = stride.unwrap_or(width * scaling.pixel_size());
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            HSVPixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                scaling
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `3 * width` for 8 bit, `12 * width` for float components
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `HSV32F` (default), 32 bit float, hue in degrees
+    /// * `HSV`, 8 bit, hue circle scaled to `0..256`
+    /// * `HSV180`, 8 bit, hue in 2 degree steps `0..180`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `HSVPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = HSVPixelBuffer::new(width, height, stride, fourcc);
+        let pixel_width = buffer.scaling.pixel_size();
+
+        let mut line = buffer.scaling.encode(color.into()).repeat(width);
+        if buffer.stride > width * pixel_width {
+            line.extend([0].repeat(buffer.stride - width * pixel_width));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let size = self.scaling.pixel_size();
+        let start = x * size + y * self.stride;
+        self.data[start..start + size].copy_from_slice(&self.scaling.encode(color.into()));
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let size = self.scaling.pixel_size();
+        let start = x * size + y * self.stride;
+
+        Ok(DigitalHSVColor::from(self.scaling.decode(&self.data[start..start + size])))
+    }
+}
+
+pub mod iter;
+mod tests;
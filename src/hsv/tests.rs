@@ -0,0 +1,206 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::hsv::{ HSVPixelBuffer, HueScaling };
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::color::DigitalHSVColor;
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalHSVColor{ h: 90.0, s: 0.2, v: 1.0 };
+        let buffer = HSVPixelBuffer::new_with_background(2, 2, color, None, Some("HSV"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod hsv {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = HSVPixelBuffer::new(2, 2, None, Some("HSV"));
+        assert_eq!(buffer.data.len(), 12);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 6);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 6);
+        assert_eq!(buffer.get_fourcc(), "HSV");
+        assert_eq!(buffer.get_scaling(), HueScaling::Full);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 11];
+        match HSVPixelBuffer::new_with_data(2, 2, data, None, Some("HSV")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = HSVPixelBuffer::new_with_background(
+            1, 2,
+            DigitalHSVColor{ h: 180.0, s: 1.0, v: 0.2 },
+            Some(4), Some("HSV")
+        );
+
+        assert_eq!(buffer.data, vec![128, 255, 51, 0, 128, 255, 51, 0]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = HSVPixelBuffer::new(2, 2, None, Some("HSV"));
+
+        match buffer.set_pixel(1, 1, DigitalHSVColor{ h: 30.0, s: 1.0, v: 128.0 / 255.0 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalHSVColor{ h: 30.0, s: 1.0, v: 0.5 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        // hue wraps around
+        buffer.set_pixel(0, 0, DigitalHSVColor{ h: 359.5, s: 0.0, v: 0.0 }).unwrap();
+
+        assert_eq!(buffer.data[9..12], [21, 255, 128]);
+        assert_eq!(buffer.data[0..3], [0, 0, 0]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0, 0, 0, 64, 255, 51];
+        let buffer = HSVPixelBuffer::new_with_data(2, 1, data, None, Some("HSV")).unwrap();
+
+        match buffer.get_pixel(1, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalHSVColor{ h: 90.0, s: 1.0, v: 0.2 })
+        }
+
+        match buffer.get_pixel(0, 1) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod hsv180 {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = HSVPixelBuffer::new(2, 1, None, Some("HSV180"));
+        buffer.set_pixel(0, 0, DigitalHSVColor{ h: 30.0, s: 1.0, v: 128.0 / 255.0 }).unwrap();
+        buffer.set_pixel(1, 0, DigitalHSVColor{ h: 359.5, s: 0.2, v: 1.0 }).unwrap();
+
+        assert_eq!(buffer.get_scaling(), HueScaling::Half);
+        assert_eq!(buffer.data, vec![15, 255, 128, 0, 51, 255]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![179, 255, 51];
+        let buffer = HSVPixelBuffer::new_with_data(1, 1, data, None, Some("HSV180")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalHSVColor{ h: 358.0, s: 1.0, v: 0.2 });
+    }
+}
+
+#[cfg(test)]
+mod hsv32f {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = HSVPixelBuffer::new(2, 2, None, None);
+        assert_eq!(buffer.get_fourcc(), "HSV32F");
+        assert_eq!(buffer.data.len(), 48);
+        assert_eq!(buffer.get_stride(), 24);
+        assert_eq!(buffer.get_scaling(), HueScaling::Float);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = HSVPixelBuffer::new(1, 1, None, Some("HSV32F"));
+        buffer.set_pixel(0, 0, DigitalHSVColor{ h: 30.5, s: 0.25, v: 1.0 }).unwrap();
+
+        let mut expected = 30.5f32.to_le_bytes().to_vec();
+        expected.extend(0.25f32.to_le_bytes());
+        expected.extend(1.0f32.to_le_bytes());
+        assert_eq!(buffer.data, expected);
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalHSVColor{ h: 30.5, s: 0.25, v: 1.0 });
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb_roundtrip() {
+        let mut rgb = RGBPixelBuffer::new(3, 1, None, Some("RGB"));
+        rgb.set_pixel(0, 0, DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 }).unwrap();
+        rgb.set_pixel(1, 0, DigitalRGBAColor{ r: 0, g: 255, b: 0, a: 255 }).unwrap();
+        rgb.set_pixel(2, 0, DigitalRGBAColor{ r: 12, g: 34, b: 56, a: 255 }).unwrap();
+
+        let hsv: HSVPixelBuffer = rgb.into();
+        assert_eq!(hsv.get_fourcc(), "HSV32F");
+        assert_eq!(hsv.get_pixel(1, 0).unwrap(), DigitalHSVColor{ h: 120.0, s: 1.0, v: 1.0 });
+
+        let back: RGBPixelBuffer = hsv.into();
+        assert_eq!(back.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 });
+        assert_eq!(back.get_pixel(1, 0).unwrap(), DigitalRGBAColor{ r: 0, g: 255, b: 0, a: 255 });
+        assert_eq!(back.get_pixel(2, 0).unwrap(), DigitalRGBAColor{ r: 12, g: 34, b: 56, a: 255 });
+    }
+
+    #[test]
+    fn hue_shift() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            2, 2,
+            DigitalRGBAColor{ r: 12, g: 34, b: 56, a: 255 },
+            None, Some("RGB")
+        );
+
+        let hsv: HSVPixelBuffer = rgb.into();
+        let mut shifted = HSVPixelBuffer::new(2, 2, None, None);
+        for (x, y, color) in hsv {
+            shifted.set_pixel(x, y, DigitalHSVColor{ h: color.h + 120.0, ..color }).unwrap();
+        }
+
+        let back: RGBPixelBuffer = shifted.into();
+        for (_x, _y, color) in back {
+            assert_eq!(color, DigitalRGBAColor{ r: 56, g: 12, b: 34, a: 255 });
+        }
+    }
+
+    #[test]
+    fn rgb_8bit() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            1, 1,
+            DigitalRGBAColor{ r: 128, g: 64, b: 0, a: 255 },
+            None, Some("RGB")
+        );
+
+        let mut hsv = HSVPixelBuffer::new(1, 1, None, Some("HSV180"));
+        for (x, y, color) in rgb {
+            hsv.set_pixel(x, y, color.into()).unwrap();
+        }
+        assert_eq!(hsv.data, vec![15, 255, 128]);
+    }
+}
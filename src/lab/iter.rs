@@ -0,0 +1,53 @@
+//! Create iterator for LabPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::LabPixelBuffer;
+use crate::color::DigitalLabColor;
+
+
+/// Pixel iterator for `LabPixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalLabColor>`
+#[derive(Debug, PartialEq)]
+pub struct LabPixelIterator<'a> {
+    base: LabPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for LabPixelIterator<'a> {
+    type Item = Pixel<DigitalLabColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for LabPixelBuffer<'a> {
+    type Item = Pixel<DigitalLabColor>;
+    type IntoIter = LabPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LabPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::lab::{ LabPixelBuffer, WhitePoint, RGBPrimaries, TransferFunction };
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::color::DigitalLabColor;
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalLabColor{ l: 50.0, a: -20.5, b: 10.25 };
+        let buffer = LabPixelBuffer::new_with_background(2, 2, color, None, None);
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod labd65 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = LabPixelBuffer::new(2, 2, None, None);
+        assert_eq!(buffer.data.len(), 48);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 24);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 24);
+        assert_eq!(buffer.get_fourcc(), "LABD65");
+        assert_eq!(buffer.get_white_point(), WhitePoint::D65);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 47];
+        match LabPixelBuffer::new_with_data(2, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = LabPixelBuffer::new(2, 1, None, None);
+
+        match buffer.set_pixel(1, 0, DigitalLabColor{ l: 50.0, a: -2.0, b: 0.5 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(0, 1, DigitalLabColor{ l: 50.0, a: -2.0, b: 0.5 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[12..24], [0x00, 0x00, 0x48, 0x42, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x3f]);
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalLabColor{ l: 50.0, a: -2.0, b: 0.5 });
+    }
+}
+
+#[cfg(test)]
+mod labd50 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = LabPixelBuffer::new(1, 1, None, Some("LABD50"));
+        assert_eq!(buffer.get_stride(), 12);
+        assert_eq!(buffer.get_white_point(), WhitePoint::D50);
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            1, 1,
+            DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 },
+            None, Some("RGB")
+        );
+
+        let lab: LabPixelBuffer = rgb.into();
+        let color = lab.get_pixel(0, 0).unwrap();
+        assert!((color.l - 53.24).abs() < 0.05, "{:?}", color);
+        assert!((color.a - 80.09).abs() < 0.05, "{:?}", color);
+        assert!((color.b - 67.20).abs() < 0.05, "{:?}", color);
+
+        let back: RGBPixelBuffer = lab.into();
+        assert_eq!(back.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 });
+    }
+
+    #[test]
+    fn rgb_d50() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            1, 1,
+            DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 },
+            None, Some("RGB")
+        );
+
+        let lab = LabPixelBuffer::from_rgb(rgb, TransferFunction::SRGB, RGBPrimaries::SRGB, Some("LABD50"));
+        let color = lab.get_pixel(0, 0).unwrap();
+        assert!((color.l - 54.29).abs() < 0.1, "{:?}", color);
+        assert!((color.a - 80.81).abs() < 0.1, "{:?}", color);
+        assert!((color.b - 69.89).abs() < 0.1, "{:?}", color);
+
+        let back = lab.into_rgb(TransferFunction::SRGB, RGBPrimaries::SRGB, Some("RGB"));
+        assert_eq!(back.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 });
+    }
+}
@@ -10,12 +10,98 @@
 //! - `RGB`, `BGR` 24 bit without alpha
 //! - `RGBA`, `ARGB`, `BGRA`, `ABGR` 32 bit with alpha
 //!
+//! ### RGB 16 bit interleaved `RGB48PixelBuffer`
+//!
+//! - `RGB48LE`, `RGB48BE`, `BGR48LE`, `BGR48BE` 48 bit without alpha
+//! - `RGBA64LE`, `RGBA64BE`, `BGRA64LE`, `BGRA64BE` 64 bit with alpha
+//!
+//! ### RGB packed 16 bit `RGB16PixelBuffer`
+//!
+//! - `RGB565`, `RGB555` / `XRGB1555`, `RGB444` / `XRGB4444` 16 bit without alpha
+//! - `ARGB1555`, `ARGB4444` 16 bit with alpha
+//!
+//! All codes take an `LE` or `BE` suffix for the byte order, without suffix they are little endian.
+//!
+//! ### RGB packed 10 bit `RGB30PixelBuffer`
+//!
+//! - `AR30`, `AB30` 2 bit alpha and 10 bit red, green and blue (blue, green and red) in a little endian `u32`
+//! - `XR30`, `XB30` as `AR30`, `AB30` without alpha
+//!
+//! ### RGB bitmask described `BitfieldPixelBuffer`
+//!
+//! - `<bits per pixel>:<red mask>:<green mask>:<blue mask>[:<alpha mask>]` 8, 16, 24 or 32 bit little endian pixels
+//!   with hexadecimal, contiguous and non-overlapping component masks, e.g. `16:f800:07e0:001f` or `32:00ff0000:0000ff00:000000ff:ff000000` (default)
+//!
 //! ### Grayscale `GrayscalePixelBuffer`
 //!
 //! - `Y` Simple, single Y plane for monochrome images.
 //! - `Yxx` 3 bytes, ignore the last two (interpret a YUV444 image as grayscale)
 //! - `Yx` and `xY`, 2 bytes, ignore the x (interpret a YUV422 image as grayscale)
 //!
+//! ### Grayscale high bit depth `Gray16PixelBuffer`
+//!
+//! - `GRAY16LE`, `GRAY16BE` 16 bit little or big endian samples.
+//! - `GRAY12LE`, `GRAY12BE`, `GRAY10LE`, `GRAY10BE` 12 or 10 significant bits in the least significant bits of 16 bit samples.
+//! - `GRAY12MLE`, `GRAY12MBE`, `GRAY10MLE`, `GRAY10MBE` as above with the significant bits in the most significant bits.
+//!
+//! ### Grayscale packed low bit depth `PackedGrayPixelBuffer`
+//!
+//! - `GRAY1` 1 bit per pixel, 8 pixels per byte, first pixel in the most significant bit.
+//! - `GRAY2`, `GRAY4` 2 or 4 bits per pixel, 4 or 2 pixels per byte, first pixel in the most significant bits.
+//! - `GRAY1LSB`, `GRAY2LSB`, `GRAY4LSB` as above with the first pixel in the least significant bits,
+//!   `GRAY1MSB`, `GRAY2MSB`, `GRAY4MSB` are aliases of the default bit order.
+//!
+//! ### Indexed color `PalettePixelBuffer`
+//!
+//! - `PAL1`, `PAL2`, `PAL4`, `PAL8` (default) 1, 2, 4 or 8 bit indices into a palette of up to 256 RGBA colors,
+//!   indices smaller than a byte are packed with the first pixel in the most significant bits.
+//!
+//! ### RGB floating point linear light `RGBFloatPixelBuffer`
+//!
+//! - `RGBA32F` (default), `RGB32F` 32 bit little endian float components with or without alpha.
+//! - `RGBA16F`, `RGB16F` 16 bit (half precision) little endian float components with or without alpha.
+//!
+//! ### Bayer raw sensor data `BayerPixelBuffer`
+//!
+//! - `RGGB` (default), `BGGR`, `GRBG`, `GBRG` 8 bit samples, the code names the colors of the top left 2x2 cell.
+//! - `RGGB16`, `BGGR16`, `GRBG16`, `GBRG16` as above with 16 bit little endian samples.
+//!
+//! ### MIPI CSI-2 packed raw `MipiRawPixelBuffer`
+//!
+//! - `RAW10` (default) 4 pixels in 5 bytes, the 8 most significant bits of each pixel followed by a byte with the 2 least significant bits of all pixels.
+//! - `RAW12` 2 pixels in 3 bytes, the 8 most significant bits of each pixel followed by a byte with the 4 least significant bits of both pixels.
+//! - `RAW14` 4 pixels in 7 bytes, the 8 most significant bits of each pixel followed by 3 bytes with the 6 least significant bits of all pixels.
+//!
+//! ### CMYK interleaved `CMYKiPixelBuffer`
+//!
+//! - `CMYK` (default), `KCMY` 4 bytes per pixel, 8 bit ink coverage per component.
+//!
+//! ### CMYK planar `CMYKpPixelBuffer`
+//!
+//! - `CMYK` (default), `KCMY` 4 full resolution planes, 8 bit ink coverage per component.
+//!
+//! ### HSV interleaved `HSVPixelBuffer`
+//!
+//! - `HSV32F` (default) 3 little endian 32 bit floats per pixel, hue in degrees, saturation and value `0.0..=1.0`.
+//! - `HSV` 3 bytes per pixel, hue circle scaled to `0..256`, saturation and value `0..=255`.
+//! - `HSV180` 3 bytes per pixel, hue in 2 degree steps `0..180`.
+//!
+//! ### HSL interleaved `HSLPixelBuffer`
+//!
+//! - `HSL32F` (default) 3 little endian 32 bit floats per pixel, hue in degrees, saturation and lightness `0.0..=1.0`.
+//! - `HSL` 3 bytes per pixel, hue circle scaled to `0..256`, saturation and lightness `0..=255`.
+//! - `HSL180` 3 bytes per pixel, hue in 2 degree steps `0..180`.
+//!
+//! ### CIE XYZ interleaved `XYZPixelBuffer`
+//!
+//! - `XYZD65` (default) 3 little endian 32 bit floats per pixel, D65 reference white with `Y = 1.0`.
+//! - `XYZD50` 3 little endian 32 bit floats per pixel, D50 reference white with `Y = 1.0`.
+//!
+//! ### CIE L\*a\*b\* interleaved `LabPixelBuffer`
+//!
+//! - `LABD65` (default) 3 little endian 32 bit floats per pixel, D65 reference white.
+//! - `LABD50` 3 little endian 32 bit floats per pixel, D50 reference white.
+//!
 //! ### YUV `YUV422iPixelBuffer`
 //!
 //! - `UYVY` YUV 4:2:2 (Y sample at every pixel, U and V sampled at every second pixel horizontally on each line). A macropixel contains 2 pixels in 1 `u32`.
@@ -23,10 +109,97 @@
 //! - `YVYU` YUV 4:2:2 as for `UYVY` but with different component ordering within the `u32` macropixel.
 //! - `VYUY` YUV 4:2:2 as for `UYVY` but with different component ordering within the `u32` macropixel.
 //!
-//! ### YUV 4:2:2 planar `YUV422pPixelBuffer`
-//! 
-//! - `YV21` / `I420` 8 bit Y plane followed by 8 bit 2x2 subsampled U and V planes.
+//! ### YUV 4:4:4 interleaved `YUV444iPixelBuffer`
+//!
+//! - `YUV` / `YUV444` YUV 4:4:4, 3 bytes per pixel, full resolution U and V planes.
+//! - `VUY` like `YUV` but with different component ordering
+//! - `UVY` like `YUV` but with different component ordering
+//! - `YVU` like `YUV` but with different component ordering
+//! - `AYUV` / `VUYA` YUV 4:4:4 with alpha, 4 bytes per pixel in V, U, Y, A byte order.
+//! - `XYUV` / `VUYX` like `AYUV` but the fourth byte is padding instead of alpha.
+//!
+//! ### YUV 4:2:2 high bit depth interleaved `Y210PixelBuffer`
+//!
+//! - `Y210` YUV 4:2:2 with the `YUYV` macropixel layout in 16 bit little endian samples, 10 significant bits
+//!   in the most significant bits of each sample.
+//! - `Y216` As `Y210` with 16 significant bits.
+//!
+//! ### YUV 4:4:4 planar `YUV444pPixelBuffer`
+//!
+//! - `I444` 8 bit Y plane followed by 8 bit full resolution U and V planes.
+//! - `YV24` 8 bit Y plane followed by 8 bit full resolution V and U planes.
+//!
+//! ### YUV 4:2:0 planar `YUV420pPixelBuffer`
+//!
+//! - `I420` / `IYUV` 8 bit Y plane followed by 8 bit 2x2 subsampled U and V planes.
 //! - `YV12` 8 bit Y plane followed by 8 bit 2x2 subsampled V and U planes.
+//!
+//! ### YUV 4:2:2 planar `YUV422pPixelBuffer`
+//!
+//! - `I422` 8 bit Y plane followed by 8 bit horizontally subsampled U and V planes.
+//! - `YV16` 8 bit Y plane followed by 8 bit horizontally subsampled V and U planes.
+//! - `YV12` / `YV21` Legacy codes of this buffer, `YV12` selects the U/V order of `I422` and `YV21` the V/U order of `YV16`.
+//!   For 4:2:0 `YV12` data use `YUV420pPixelBuffer`.
+//!
+//! ### YUV planar with alpha `YUVApPixelBuffer`
+//!
+//! - `YUVA420P` (default) YUV 4:2:0 (half resolution U and V planes) followed by a full resolution alpha plane.
+//! - `YUVA444P` YUV 4:4:4 (full resolution U and V planes) followed by a full resolution alpha plane.
+//!
+//! ### YUV 4:1:1 packed `YUV411iPixelBuffer`
+//!
+//! - `Y41P` YUV 4:1:1, 8 pixels packed into 12 bytes (`U0 Y0 V0 Y1 U4 Y2 V4 Y3 Y4 Y5 Y6 Y7`), U and V sampled at every fourth pixel horizontally.
+//!
+//! ### YUV 4:1:1 and 4:1:0 planar `YUV411pPixelBuffer`
+//!
+//! - `411P` / `Y41B` 8 bit Y plane followed by 8 bit 4x1 subsampled U and V planes.
+//! - `YUV9` 8 bit Y plane followed by 8 bit 4x4 subsampled U and V planes.
+//! - `YVU9` 8 bit Y plane followed by 8 bit 4x4 subsampled V and U planes.
+//!
+//! ### YUV 4:2:2 planar/interleaved `YUV422piPixelBuffer`
+//!
+//! - `NV12` 8 bit Y plane followed by an interleaved U/V plane with 2x2 subsampling.
+//! - `NV21` As `NV12` with U and V reversed in the interleaved plane.
+//! - `NV16` / `NV61` As `NV12` / `NV21` with horizontal subsampling only (full height chroma plane).
+//! - `NV24` / `NV42` As `NV12` / `NV21` without subsampling, the chroma plane is twice as wide as the Y plane.
+//!
+//! ### YUV 4:2:0 high bit depth planar/interleaved `P010PixelBuffer`
+//!
+//! - `P010` 16 bit little endian Y plane followed by an interleaved U/V plane with 2x2 subsampling,
+//!   10 significant bits in the most significant bits of each sample.
+//! - `P012` As `P010` with 12 significant bits.
+//! - `P016` As `P010` with 16 significant bits.
+//!
+//! ### YUV 4:2:2 packed 10 bit `V210PixelBuffer`
+//!
+//! - `v210` 6 pixels packed into 16 bytes as four little endian `u32` words with three 10 bit samples each,
+//!   lines aligned to 128 bytes.
+//!
+//! ### YCoCg 4:4:4 interleaved `YCoCg444iPixelBuffer`
+//!
+//! - `YCoCg` 3 bytes per pixel, full resolution Co and Cg.
+//! - `YCgCo`, `CoCgY` like `YCoCg` but with different component ordering
+//!
+//! ### YCoCg 4:4:4 planar `YCoCg444pPixelBuffer`
+//!
+//! - `YCoCg` 8 bit Y plane followed by full resolution Co and Cg planes.
+//! - `YCgCo` like `YCoCg` with the Cg plane before the Co plane.
+//!
+//! ### YCoCg 4:2:2 planar `YCoCg422pPixelBuffer`
+//!
+//! - `YCoCg` 8 bit Y plane followed by horizontally subsampled Co and Cg planes.
+//! - `YCgCo` like `YCoCg` with the Cg plane before the Co plane.
+//!
+//! ### YCoCg 4:2:2 interleaved `YCoCg422iPixelBuffer`
+//!
+//! - `YCoYCg` Co and Cg sampled at every second pixel horizontally, a macropixel contains 2 pixels in 1 `u32`.
+//! - `CoYCgY` like `YCoYCg` but with different component ordering within the `u32` macropixel.
+//!
+//! ### YCoCg-R interleaved `YCoCgRPixelBuffer`
+//!
+//! - `YCoCgR` / `YCoCg-R` reversible YCoCg lifting transform, lossless conversion from and to RGB.
+//!   Y, Co and Cg are stored as signed 16 bit little endian values, 6 bytes per pixel.
+//! - `YCgCoR` like `YCoCgR` but with different component ordering
 
 #![feature(repeat_generic_slice)]
 #![feature(doc_spotlight)]
@@ -42,7 +215,13 @@ pub enum PixelBufferError {
     /// Buffer too small for requested operation
     BufferTooSmall,
     /// Request out of buffer bounds
-    RequestOutOfBounds
+    RequestOutOfBounds,
+    /// Color or index not found in the palette of an indexed buffer
+    ColorNotInPalette,
+    /// Palette has more entries than the index size can address
+    PaletteTooLarge,
+    /// Format description could not be decoded
+    InvalidFormat
 }
 
 /// Pixel type used by iterators, contains
@@ -150,9 +329,40 @@ pub trait PixelBuffer<'a>: Sized + IntoIterator
 }
 
 pub mod rgb;
+pub mod rgb48;
+pub mod rgb16;
+pub mod rgb30;
+pub mod rgbfloat;
+pub mod bitfield;
 pub mod grayscale;
+pub mod gray16;
+pub mod packedgray;
+pub mod bayer;
+pub mod mipiraw;
+pub mod cmyki;
+pub mod cmykp;
+pub mod hsv;
+pub mod hsl;
+pub mod xyz;
+pub mod lab;
+pub mod palette;
 pub mod yuv422i;
 pub mod yuv444i;
+pub mod yuv444p;
+pub mod yuv420p;
 pub mod yuv422p;
+pub mod yuv422pi;
+pub mod yuvap;
+pub mod yuv411i;
+pub mod yuv411p;
+pub mod p010;
+pub mod v210;
+pub mod ycocg444i;
+pub mod ycocg444p;
+pub mod ycocg422p;
+pub mod ycocg422i;
+pub mod ycocgr;
+
+pub mod color;
 
 pub mod conversion;
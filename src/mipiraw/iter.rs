@@ -0,0 +1,53 @@
+//! Create iterator for MipiRawPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::MipiRawPixelBuffer;
+use crate::color::DigitalGray16Color;
+
+
+/// Pixel iterator for `MipiRawPixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalGray16Color>`
+#[derive(Debug, PartialEq)]
+pub struct MipiRawPixelIterator<'a> {
+    base: MipiRawPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for MipiRawPixelIterator<'a> {
+    type Item = Pixel<DigitalGray16Color>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for MipiRawPixelBuffer<'a> {
+    type Item = Pixel<DigitalGray16Color>;
+    type IntoIter = MipiRawPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MipiRawPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
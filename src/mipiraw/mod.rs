@@ -0,0 +1,295 @@
+//! This module describes the packed raw formats of MIPI CSI-2 camera sensors with 10 (`RAW10`),
+//! 12 (`RAW12`) or 14 (`RAW14`) bit per sample
+//!
+//! Pixels are packed in groups: first the 8 most significant bits of every pixel of the group,
+//! one byte each, followed by the remaining least significant bits of all pixels packed into
+//! bytes starting at the least significant bit. Samples are read and written as 16 bit values,
+//! use `unpack` and `pack` to convert from and to 16 bit Bayer or grayscale buffers.
+
+use crate::*;
+pub use crate::color::DigitalGray16Color;
+use crate::color::{ expand_bits16, reduce_bits16 };
+
+/// MIPI CSI-2 packed raw Pixel buffer, a single sample per pixel
+#[derive(Debug, PartialEq)]
+pub struct MipiRawPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    bits: usize,
+    data: Vec<u8>
+}
+
+impl<'a> MipiRawPixelBuffer<'a> {
+    fn decode_bits(fourcc:&'a str) -> usize {
+        match fourcc {
+            "RAW12" => 12,
+            "RAW14" => 14,
+            "RAW10" | _ => 10,
+        }
+    }
+
+    /// Number of pixels and bytes of a packed group
+    fn group_size(bits: usize) -> (usize, usize) {
+        match bits {
+            12 => (2, 3),
+            14 => (4, 7),
+            _ => (4, 5),
+        }
+    }
+
+    /// Default stride, lines are padded to full groups
+    fn default_stride(width: usize, bits: usize) -> usize {
+        let (pixels, bytes) = MipiRawPixelBuffer::group_size(bits);
+
+        (width + pixels - 1) / pixels * bytes
+    }
+
+    /// Pack one group of samples with `bits` bits each
+    fn pack_group(values: &[u32], bits: usize) -> Vec<u8> {
+        let (pixels, bytes) = MipiRawPixelBuffer::group_size(bits);
+        let low_bits = bits - 8;
+        let mut low: u32 = 0;
+
+        let mut group: Vec<u8> = Vec::with_capacity(bytes);
+        for (i, value) in values.iter().enumerate().take(pixels) {
+            group.push((value >> low_bits) as u8);
+            low |= (value & ((1 << low_bits) - 1)) << (i * low_bits);
+        }
+        group.extend_from_slice(&low.to_le_bytes()[..bytes - pixels]);
+
+        group
+    }
+
+    /// Offset of the group, index within the group and the group size of the pixel at `x`, `y`
+    fn position(&self, x: usize, y: usize) -> (usize, usize, (usize, usize)) {
+        let (pixels, bytes) = MipiRawPixelBuffer::group_size(self.bits);
+
+        (y * self.stride + x / pixels * bytes, x % pixels, (pixels, bytes))
+    }
+
+    /// Least significant bits of all pixels of a group
+    fn read_low_bits(&self, start: usize, pixels: usize, bytes: usize) -> u32 {
+        let mut low: u32 = 0;
+        for i in 0..(bytes - pixels) {
+            low |= (self.data[start + pixels + i] as u32) << (i * 8);
+        }
+
+        low
+    }
+
+    /// Unpack into a buffer with 16 bit samples, e.g. a `BayerPixelBuffer` or `Gray16PixelBuffer`
+    ///
+    /// # Arguments
+    ///
+    /// * `fourcc` - optional, data representation format of the new buffer
+    ///
+    /// # Returns
+    ///
+    /// A new buffer with the same dimensions and default stride
+    pub fn unpack<'b, T>(&self, fourcc: Option<&'b str>) -> T where T: PixelBuffer<'b, ColorType = DigitalGray16Color> {
+        let mut buffer = T::new(self.width, self.height, None, fourcc);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                buffer.set_pixel(x, y, self.get_pixel(x, y).unwrap()).unwrap();
+            }
+        }
+
+        buffer
+    }
+
+    /// Pack a buffer with 16 bit samples, e.g. a `BayerPixelBuffer` or `Gray16PixelBuffer`
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - the buffer to pack
+    /// * `stride` - optional, the line-width of the new buffer if it differs from the default
+    /// * `fourcc` - optional, data representation format of the new buffer
+    ///
+    /// # Returns
+    ///
+    /// A new `MipiRawPixelBuffer` with the same dimensions
+    pub fn pack<'b, T>(buffer: &T, stride: Option<usize>, fourcc: Option<&'a str>) -> Self where T: PixelBuffer<'b, ColorType = DigitalGray16Color> {
+        let mut result = MipiRawPixelBuffer::new(buffer.get_width(), buffer.get_height(), stride, fourcc);
+        for y in 0..result.height {
+            for x in 0..result.width {
+                result.set_pixel(x, y, buffer.get_pixel(x, y).unwrap()).unwrap();
+            }
+        }
+
+        result
+    }
+}
+
+impl<'a> PixelBuffer<'a> for MipiRawPixelBuffer<'a> {
+    type ColorType = DigitalGray16Color;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `width` rounded up to full groups
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RAW10` (default), 4 pixels in 5 bytes
+    /// * `RAW12`, 2 pixels in 3 bytes
+    /// * `RAW14`, 4 pixels in 7 bytes
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `MipiRawPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("RAW10");
+        let bits = MipiRawPixelBuffer::decode_bits(f);
+        let line_width = stride.unwrap_or(MipiRawPixelBuffer::default_stride(width, bits));
+
+        MipiRawPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            bits
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `width` rounded up to full groups
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RAW10` (default), 4 pixels in 5 bytes
+    /// * `RAW12`, 2 pixels in 3 bytes
+    /// * `RAW14`, 4 pixels in 7 bytes
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `MipiRawPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("RAW10");
+        let bits = MipiRawPixelBuffer::decode_bits(f);
+        let line_width = stride.unwrap_or(MipiRawPixelBuffer::default_stride(width, bits));
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            MipiRawPixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                bits
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `width` rounded up to full groups
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - raw sample value to fill all pixels with
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RAW10` (default), 4 pixels in 5 bytes
+    /// * `RAW12`, 2 pixels in 3 bytes
+    /// * `RAW14`, 4 pixels in 7 bytes
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `MipiRawPixelBuffer` with it's contents set to the
+    /// defined sample value, including unused pixels of the last group. If stride is bigger
+    /// than needed width the padding is filled with zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = MipiRawPixelBuffer::new(width, height, stride, fourcc);
+        let (pixels, _bytes) = MipiRawPixelBuffer::group_size(buffer.bits);
+        let value = reduce_bits16(color.v, buffer.bits as u32);
+        let line_bytes = MipiRawPixelBuffer::default_stride(width, buffer.bits);
+
+        let group = MipiRawPixelBuffer::pack_group(&[value].repeat(pixels), buffer.bits);
+        let mut line = group.repeat((width + pixels - 1) / pixels);
+        if buffer.stride > line_bytes {
+            line.extend([0].repeat(buffer.stride - line_bytes));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let (start, index, (pixels, bytes)) = self.position(x, y);
+        let low_bits = self.bits - 8;
+        let mask = (1u32 << low_bits) - 1;
+        let value = reduce_bits16(color.v, self.bits as u32);
+
+        let mut low = self.read_low_bits(start, pixels, bytes);
+        low = (low & !(mask << (index * low_bits))) | ((value & mask) << (index * low_bits));
+
+        self.data[start + index] = (value >> low_bits) as u8;
+        for i in 0..(bytes - pixels) {
+            self.data[start + pixels + i] = (low >> (i * 8)) as u8;
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let (start, index, (pixels, bytes)) = self.position(x, y);
+        let low_bits = self.bits - 8;
+        let low = self.read_low_bits(start, pixels, bytes) >> (index * low_bits);
+        let value = ((self.data[start + index] as u32) << low_bits) | (low & ((1 << low_bits) - 1));
+
+        Ok(DigitalGray16Color { v: expand_bits16(value, self.bits as u32) })
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,195 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::mipiraw::MipiRawPixelBuffer;
+pub use crate::bayer::BayerPixelBuffer;
+pub use crate::gray16::Gray16PixelBuffer;
+pub use crate::color::DigitalGray16Color;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalGray16Color{ v: 0x1230 };
+        let buffer = MipiRawPixelBuffer::new_with_background(5, 2, color, None, Some("RAW12"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, DigitalGray16Color{ v: 0x1231 });
+        }
+    }
+}
+
+#[cfg(test)]
+mod raw10 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = MipiRawPixelBuffer::new(6, 2, None, None);
+        assert_eq!(buffer.data.len(), 20);
+        assert_eq!(buffer.width, 6);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 10);
+        assert_eq!(buffer.get_width(), 6);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 10);
+        assert_eq!(buffer.get_fourcc(), "RAW10");
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 19];
+        match MipiRawPixelBuffer::new_with_data(6, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = MipiRawPixelBuffer::new_with_background(
+            4, 2,
+            DigitalGray16Color{ v: 0xffff },
+            Some(6), None
+        );
+
+        assert_eq!(buffer.data, vec![0xff, 0xff, 0xff, 0xff, 0xff, 0, 0xff, 0xff, 0xff, 0xff, 0xff, 0]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = MipiRawPixelBuffer::new(4, 1, None, None);
+
+        // 10 bit value 0x2c9
+        match buffer.set_pixel(2, 0, DigitalGray16Color{ v: 0xb26c }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(4, 0, DigitalGray16Color{ v: 0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data, vec![0, 0, 0xb2, 0, 0x10]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0b11_10_01_00];
+        let buffer = MipiRawPixelBuffer::new_with_data(4, 1, data, None, None).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGray16Color{ v: 0x0100 });
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalGray16Color{ v: 0x0240 });
+        assert_eq!(buffer.get_pixel(2, 0).unwrap(), DigitalGray16Color{ v: 0x0380 });
+        assert_eq!(buffer.get_pixel(3, 0).unwrap(), DigitalGray16Color{ v: 0x04c1 });
+
+        match buffer.get_pixel(0, 1) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod raw12 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = MipiRawPixelBuffer::new(3, 1, None, Some("RAW12"));
+        assert_eq!(buffer.get_stride(), 6);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = MipiRawPixelBuffer::new(2, 1, None, Some("RAW12"));
+        buffer.set_pixel(0, 0, DigitalGray16Color{ v: 0xabca }).unwrap();
+        buffer.set_pixel(1, 0, DigitalGray16Color{ v: 0x1231 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0xab, 0x12, 0x3c]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0xab, 0x12, 0x3c];
+        let buffer = MipiRawPixelBuffer::new_with_data(2, 1, data, None, Some("RAW12")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGray16Color{ v: 0xabca });
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalGray16Color{ v: 0x1231 });
+    }
+}
+
+#[cfg(test)]
+mod raw14 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = MipiRawPixelBuffer::new(5, 1, None, Some("RAW14"));
+        assert_eq!(buffer.get_stride(), 14);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut buffer = MipiRawPixelBuffer::new(4, 1, None, Some("RAW14"));
+        let values = [0x3fffu32, 0x0001, 0x2aaa, 0x1555];
+        for (x, value) in values.iter().enumerate() {
+            buffer.set_pixel(x, 0, DigitalGray16Color{ v: (value << 2 | value >> 12) as u16 }).unwrap();
+        }
+
+        // high bytes, then 6 low bits of every pixel from the least significant bit
+        let low = 0x3fu32 | 0x01 << 6 | 0x2a << 12 | 0x15 << 18;
+        assert_eq!(buffer.data, vec![0xff, 0x00, 0xaa, 0x55, low as u8, (low >> 8) as u8, (low >> 16) as u8]);
+
+        for (x, value) in values.iter().enumerate() {
+            assert_eq!(buffer.get_pixel(x, 0).unwrap(), DigitalGray16Color{ v: (value << 2 | value >> 12) as u16 });
+        }
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn unpack_gray() {
+        let data = vec![
+            0x01, 0x02, 0x03, 0x04, 0b11_10_01_00, 0x05, 0x00, 0x00, 0x00, 0x03, 0, 0,
+        ];
+        let raw = MipiRawPixelBuffer::new_with_data(5, 1, data, Some(12), None).unwrap();
+
+        let gray: Gray16PixelBuffer = raw.unpack(Some("GRAY10LE"));
+        assert_eq!(gray.get_fourcc(), "GRAY10LE");
+        let expected = [0x004u16, 0x009, 0x00e, 0x013, 0x017];
+        for (x, value) in expected.iter().enumerate() {
+            assert_eq!(gray.get_pixel(x, 0).unwrap(), DigitalGray16Color{ v: value << 6 | value >> 4 });
+        }
+    }
+
+    #[test]
+    fn pack_bayer() {
+        let mut bayer = BayerPixelBuffer::new(6, 2, None, Some("RGGB16"));
+        for y in 0..2 {
+            for x in 0..6 {
+                let value = (x + y * 6) as u16 * 0x111;
+                bayer.set_pixel(x, y, DigitalGray16Color{ v: value << 4 | value >> 8 }).unwrap();
+            }
+        }
+
+        let raw = MipiRawPixelBuffer::pack(&bayer, Some(12), Some("RAW12"));
+        assert_eq!(raw.get_stride(), 12);
+        assert_eq!(raw.data[9..12], [0, 0, 0]);
+
+        let back: BayerPixelBuffer = raw.unpack(Some("RGGB16"));
+        assert_eq!(back, bayer);
+
+        let raw = MipiRawPixelBuffer::pack(&bayer, Some(12), Some("RAW12"));
+        let back: BayerPixelBuffer = raw.into();
+        assert_eq!(back, bayer);
+    }
+}
@@ -0,0 +1,54 @@
+//! Create iterator for P010PixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::P010PixelBuffer;
+use crate::color::DigitalYCbCr48Color;
+
+
+/// Pixel iterator for `P010PixelBuffer`
+///
+/// Items it will generate are of type `Pixel<DigitalYCbCr48Color>`
+#[derive(Debug, PartialEq)]
+pub struct P010PixelIterator<'a> {
+    base: P010PixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for P010PixelIterator<'a> {
+    type Item = Pixel<DigitalYCbCr48Color>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for P010PixelBuffer<'a> {
+    type Item = Pixel<DigitalYCbCr48Color>;
+    type IntoIter = P010PixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        P010PixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
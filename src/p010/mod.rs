@@ -0,0 +1,252 @@
+//! This module describes a semi-planar high bit depth YUV 4:2:0 pixel buffer (`P010`, `P012`, `P016`),
+//! the `NV12` layout with 16 bit little endian containers
+//!
+//! `P010` and `P012` store 10 and 12 significant bits in the most significant bits of
+//! each container, the remaining low bits are zero.
+
+use crate::*;
+pub use crate::color::DigitalYCbCr48Color;
+use crate::color::quantize_msb;
+
+/// High bit depth YUV Pixel buffer without alpha channel, one Y plane and one interleaved 2x2 subsampled U/V plane
+#[derive(Debug, PartialEq)]
+pub struct P010PixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    bits: u32,
+    data: Vec<u8>
+}
+
+impl<'a> P010PixelBuffer<'a> {
+    fn decode_bits(fourcc:&'a str) -> u32 {
+        match fourcc {
+            "P012" => 12,
+            "P016" => 16,
+            "P010" | _ => 10,
+        }
+    }
+
+    /// Default stride in bytes, the width is rounded up to the next even number
+    fn default_stride(width: usize) -> usize {
+        (width + 1) / 2 * 2 * 2
+    }
+
+    /// Line width of the interleaved chroma plane in bytes, rounded up to whole U/V pairs
+    fn chroma_stride(stride: usize) -> usize {
+        (stride + 3) / 4 * 4
+    }
+
+    /// Number of bytes needed for a buffer with given stride and height
+    fn buffer_size(stride: usize, height: usize) -> usize {
+        stride * height + P010PixelBuffer::chroma_stride(stride) * ((height + 1) / 2)
+    }
+
+    /// Offset of the U sample of the pixel at `x`, `y`, the V sample follows
+    fn chroma_offset(&self, x: usize, y: usize) -> usize {
+        self.stride * self.height + (y / 2) * P010PixelBuffer::chroma_stride(self.stride) + (x / 2) * 4
+    }
+
+    /// Quantize a 16 bit component to the significant bits of the format
+    fn quantize(&self, value: u16) -> u16 {
+        quantize_msb(value, self.bits)
+    }
+
+    fn read(&self, offset: usize) -> u16 {
+        u16::from_le_bytes([self.data[offset], self.data[offset + 1]])
+    }
+
+    fn write(&mut self, offset: usize, value: u16) {
+        let bytes = self.quantize(value).to_le_bytes();
+        self.data[offset] = bytes[0];
+        self.data[offset + 1] = bytes[1];
+    }
+}
+
+
+impl<'a> PixelBuffer<'a> for P010PixelBuffer<'a> {
+    type ColorType = DigitalYCbCr48Color;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of both planes in bytes if it differs from the
+    ///   default: `2 * width`, `width` rounded up to the next even number. The chroma plane
+    ///   rounds it up to whole U/V pairs
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `P010` (default), 10 significant bits
+    /// * `P012`, 12 significant bits
+    /// * `P016`, 16 significant bits
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `P010PixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("P010");
+        let line_width = stride.unwrap_or(P010PixelBuffer::default_stride(width));
+
+        P010PixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data: vec![0; P010PixelBuffer::buffer_size(line_width, height)],
+            fourcc: f,
+            bits: P010PixelBuffer::decode_bits(f)
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of both planes in bytes if it differs from the
+    ///   default: `2 * width`, `width` rounded up to the next even number. The chroma plane
+    ///   rounds it up to whole U/V pairs
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `P010` (default), 10 significant bits
+    /// * `P012`, 12 significant bits
+    /// * `P016`, 16 significant bits
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `P010PixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("P010");
+        let line_width = stride.unwrap_or(P010PixelBuffer::default_stride(width));
+
+        if data.len() < P010PixelBuffer::buffer_size(line_width, height) {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            P010PixelBuffer {
+                width,
+                height,
+                stride: line_width,
+                data,
+                fourcc: f,
+                bits: P010PixelBuffer::decode_bits(f)
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of both planes in bytes if it differs from the
+    ///   default: `2 * width`, `width` rounded up to the next even number. The chroma plane
+    ///   rounds it up to whole U/V pairs
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `P010` (default), 10 significant bits
+    /// * `P012`, 12 significant bits
+    /// * `P016`, 16 significant bits
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `P010PixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = P010PixelBuffer::new(width, height, stride, fourcc);
+        let chroma_line_width = P010PixelBuffer::chroma_stride(buffer.stride);
+        let chroma_width = (width + 1) / 2 * 4;
+
+        let mut y: Vec<u8> = buffer.quantize(color.y).to_le_bytes().repeat(width);
+        let mut uv: Vec<u8> = Vec::with_capacity(4);
+        uv.extend_from_slice(&buffer.quantize(color.cb).to_le_bytes());
+        uv.extend_from_slice(&buffer.quantize(color.cr).to_le_bytes());
+        let mut uv = uv.repeat(chroma_width / 4);
+
+        if buffer.stride > width * 2 {
+            y.extend([0].repeat(buffer.stride - width * 2));
+        }
+        if chroma_line_width > chroma_width {
+            uv.extend([0].repeat(chroma_line_width - chroma_width));
+        }
+
+        let mut data: Vec<u8> = Vec::with_capacity(buffer.data.len());
+        data.extend(y.repeat(height));
+        data.extend(uv.repeat((height + 1) / 2));
+        buffer.data = data;
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 2 + y * self.stride;
+        let chroma = self.chroma_offset(x, y);
+
+        self.write(start, color.y);
+
+        // the first pixel of a chroma block sets the chroma, the others average into it
+        if x % 2 == 0 && y % 2 == 0 {
+            self.write(chroma, color.cb);
+            self.write(chroma + 2, color.cr);
+        } else {
+            let u = ((self.read(chroma) as u32 + color.cb as u32) >> 1) as u16;
+            let v = ((self.read(chroma + 2) as u32 + color.cr as u32) >> 1) as u16;
+            self.write(chroma, u);
+            self.write(chroma + 2, v);
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 2 + y * self.stride;
+        let chroma = self.chroma_offset(x, y);
+
+        Ok(DigitalYCbCr48Color {
+            y: self.read(start), cb: self.read(chroma), cr: self.read(chroma + 2)
+        })
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,185 @@
+//
+// Tests
+//
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::p010::P010PixelBuffer;
+pub use crate::yuv444i::YUV444iPixelBuffer;
+pub use crate::color::DigitalYCbCr48Color;
+pub use grapho_color::DigitalYCbCrColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCbCr48Color{ y: 0xffc0, cb: 0x4000, cr: 0 };
+        let buffer = P010PixelBuffer::new_with_background(2, 2, color, None, Some("P010"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod p010 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = P010PixelBuffer::new(2, 2, None, Some("P010"));
+        assert_eq!(buffer.data.len(), 12);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 4);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 4);
+    }
+
+    #[test]
+    fn odd_size_buffer() {
+        let buffer = P010PixelBuffer::new(3, 3, None, Some("P010"));
+        assert_eq!(buffer.stride, 8);
+        assert_eq!(buffer.data.len(), 8 * 3 + 8 * 2);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 11];
+        match P010PixelBuffer::new_with_data(2, 2, data, None, Some("P010")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = P010PixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCr48Color{ y: 0x1234, cb: 0xffff, cr: 0x8000 },
+            Some(6), Some("P010")
+        );
+
+        // the chroma line is rounded up to whole U/V pairs
+        assert_eq!(buffer.data.len(), 20);
+        for y in 0..2 {
+            assert_eq!(buffer.data[y * 6..y * 6 + 6], [0x40, 0x12, 0x40, 0x12, 0, 0], "Y, y: {}", y);
+        }
+        assert_eq!(buffer.data[12..20], [0xc0, 0xff, 0x00, 0x80, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn odd_width_tight_stride() {
+        let color = DigitalYCbCr48Color{ y: 0x1234, cb: 0xffff, cr: 0x8000 };
+
+        let mut buffer = P010PixelBuffer::new(3, 2, Some(6), Some("P010"));
+        assert_eq!(buffer.data.len(), 6 * 2 + 8);
+        buffer.set_pixel(2, 0, color).unwrap();
+        match buffer.set_pixel(2, 1, color) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+        assert_eq!(buffer.data[16..20], [0xc0, 0xff, 0x00, 0x80]);
+
+        let buffer = P010PixelBuffer::new_with_background(3, 2, color, Some(6), Some("P010"));
+        assert_eq!(buffer.data.len(), 6 * 2 + 8);
+        assert_eq!(buffer.get_pixel(2, 1), Ok(DigitalYCbCr48Color{ y: 0x1240, cb: 0xffc0, cr: 0x8000 }));
+
+        let data = vec![0; 6 * 2 + 8];
+        match P010PixelBuffer::new_with_data(3, 2, data, Some(6), Some("P010")) {
+            Err(_error) => assert!(false),
+            Ok(buffer) => assert_eq!(buffer.get_stride(), 6)
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = P010PixelBuffer::new(2, 2, None, Some("P010"));
+
+        match buffer.set_pixel(1, 1, DigitalYCbCr48Color{ y: 0xffff, cb: 0x8000, cr: 0x4000 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(0, 2, DigitalYCbCr48Color{ y: 0, cb: 0, cr: 0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[6..8], [0xc0, 0xff]);
+        assert_eq!(buffer.data[8..12], [0x00, 0x40, 0x00, 0x20]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            0x40, 0x00, 0x80, 0x00,  // Y, y = 0
+            0xc0, 0x00, 0x00, 0x01,  // Y, y = 1
+            0x00, 0x80, 0xc0, 0xff,  // UV, y = 0, 1
+        ];
+        let buffer = P010PixelBuffer::new_with_data(2, 2, data, None, Some("P010")).unwrap();
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCr48Color{ y: 0x0100, cb: 0x8000, cr: 0xffc0 })
+        }
+
+        match buffer.get_pixel(2, 0) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod p012 {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = P010PixelBuffer::new(2, 2, None, Some("P012"));
+        buffer.set_pixel(0, 0, DigitalYCbCr48Color{ y: 0x1239, cb: 0, cr: 0 }).unwrap();
+
+        assert_eq!(buffer.data[0..2], [0x40, 0x12]);
+    }
+}
+
+#[cfg(test)]
+mod p016 {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = P010PixelBuffer::new(2, 2, None, Some("P016"));
+        buffer.set_pixel(0, 0, DigitalYCbCr48Color{ y: 0x1239, cb: 0, cr: 0 }).unwrap();
+
+        assert_eq!(buffer.data[0..2], [0x39, 0x12]);
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn yuv444i_roundtrip() {
+        let mut yuv = YUV444iPixelBuffer::new(2, 2, None, None);
+        for y in 0..2 {
+            for x in 0..2 {
+                yuv.set_pixel(x, y, DigitalYCbCrColor{ y: (x * 255) as u8, cb: 128, cr: (y * 255) as u8 }).unwrap();
+            }
+        }
+
+        let p010: P010PixelBuffer = yuv.into();
+        assert_eq!(p010.get_pixel(1, 0).unwrap().y, 0xff00);
+
+        let back: YUV444iPixelBuffer = p010.into();
+        for y in 0..2 {
+            for x in 0..2 {
+                assert_eq!(back.get_pixel(x, y).unwrap().y, (x * 255) as u8, "x: {}, y: {}", x, y);
+            }
+        }
+    }
+}
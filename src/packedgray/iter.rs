@@ -0,0 +1,53 @@
+//! Create iterator for PackedGrayPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::PackedGrayPixelBuffer;
+use grapho_color::DigitalGrayscaleColor;
+
+
+/// Pixel iterator for `PackedGrayPixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalGrayscaleColor>`
+#[derive(Debug, PartialEq)]
+pub struct PackedGrayPixelIterator<'a> {
+    base: PackedGrayPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for PackedGrayPixelIterator<'a> {
+    type Item = Pixel<DigitalGrayscaleColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for PackedGrayPixelBuffer<'a> {
+    type Item = Pixel<DigitalGrayscaleColor>;
+    type IntoIter = PackedGrayPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PackedGrayPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
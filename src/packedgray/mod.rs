@@ -0,0 +1,235 @@
+//! This module describes a grayscale buffer with 1, 2 or 4 bits per pixel packed into bytes
+//! (`GRAY1`, `GRAY2`, `GRAY4`) with MSB first or LSB first bit order
+//!
+//! Every line starts at a byte boundary. Reading expands the values to 8 bit by bit replication,
+//! writing rounds to the nearest representable value.
+
+use crate::*;
+pub use grapho_color::DigitalGrayscaleColor;
+use crate::color::{ expand_bits, reduce_bits };
+
+/// Packed grayscale Pixel buffer with less than 8 bits per pixel
+#[derive(Debug, PartialEq)]
+pub struct PackedGrayPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    bits: usize,
+    lsb_first: bool,
+    data: Vec<u8>
+}
+
+impl<'a> PackedGrayPixelBuffer<'a> {
+    /// Decode bits per pixel and bit order from `GRAY<bits>[MSB|LSB]`
+    fn decode_format(fourcc:&'a str) -> (usize, bool) {
+        let lsb_first = fourcc.ends_with("LSB");
+
+        let bits = match fourcc.trim_end_matches("LSB").trim_end_matches("MSB") {
+            "GRAY2" => 2,
+            "GRAY4" => 4,
+            "GRAY1" | _ => 1,
+        };
+
+        (bits, lsb_first)
+    }
+
+    /// Default stride in bytes, lines are padded to full bytes
+    fn default_stride(width: usize, bits: usize) -> usize {
+        (width * bits + 7) / 8
+    }
+
+    /// Byte offset and bit shift of the pixel at `x`, `y`
+    fn position(&self, x: usize, y: usize) -> (usize, usize) {
+        let bit = x * self.bits;
+        let shift =
+            if self.lsb_first {
+                bit % 8
+            } else {
+                8 - self.bits - bit % 8
+            };
+
+        (y * self.stride + bit / 8, shift)
+    }
+
+    fn mask(&self) -> u8 {
+        ((1u32 << self.bits) - 1) as u8
+    }
+}
+
+impl<'a> PixelBuffer<'a> for PackedGrayPixelBuffer<'a> {
+    type ColorType = DigitalGrayscaleColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<bits per pixel> * width` rounded up to full bytes
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `GRAY1` (default), `GRAY1MSB`, `GRAY1LSB`, 1 bit per pixel
+    /// * `GRAY2`, `GRAY2MSB`, `GRAY2LSB`, 2 bits per pixel
+    /// * `GRAY4`, `GRAY4MSB`, `GRAY4LSB`, 4 bits per pixel
+    ///
+    /// The first pixel is stored in the most significant bits of a byte unless the code
+    /// ends with `LSB`.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `PackedGrayPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("GRAY1");
+        let (bits, lsb_first) = PackedGrayPixelBuffer::decode_format(f);
+        let line_width = stride.unwrap_or(PackedGrayPixelBuffer::default_stride(width, bits));
+
+        PackedGrayPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            bits,
+            lsb_first
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<bits per pixel> * width` rounded up to full bytes
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `GRAY1` (default), `GRAY1MSB`, `GRAY1LSB`, 1 bit per pixel
+    /// * `GRAY2`, `GRAY2MSB`, `GRAY2LSB`, 2 bits per pixel
+    /// * `GRAY4`, `GRAY4MSB`, `GRAY4LSB`, 4 bits per pixel
+    ///
+    /// The first pixel is stored in the most significant bits of a byte unless the code
+    /// ends with `LSB`.
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `PackedGrayPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("GRAY1");
+        let (bits, lsb_first) = PackedGrayPixelBuffer::decode_format(f);
+        let line_width = stride.unwrap_or(PackedGrayPixelBuffer::default_stride(width, bits));
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            PackedGrayPixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                bits,
+                lsb_first
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<bits per pixel> * width` rounded up to full bytes
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `GRAY1` (default), `GRAY1MSB`, `GRAY1LSB`, 1 bit per pixel
+    /// * `GRAY2`, `GRAY2MSB`, `GRAY2LSB`, 2 bits per pixel
+    /// * `GRAY4`, `GRAY4MSB`, `GRAY4LSB`, 4 bits per pixel
+    ///
+    /// The first pixel is stored in the most significant bits of a byte unless the code
+    /// ends with `LSB`.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `PackedGrayPixelBuffer` with it's contents set to the
+    /// defined color. Unused bits in the last byte of a line are set to the color as well,
+    /// if stride is bigger than needed width the padding is filled with zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = PackedGrayPixelBuffer::new(width, height, stride, fourcc);
+        let value = reduce_bits(color.v, buffer.bits as u32) as u8;
+        let line_bytes = PackedGrayPixelBuffer::default_stride(width, buffer.bits);
+
+        let mut pattern: u8 = 0;
+        for i in 0..(8 / buffer.bits) {
+            pattern |= value << (i * buffer.bits);
+        }
+
+        let mut line = [pattern].repeat(line_bytes);
+        if buffer.stride > line_bytes {
+            line.extend([0].repeat(buffer.stride - line_bytes));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let (offset, shift) = self.position(x, y);
+        let mask = self.mask();
+        let value = reduce_bits(color.v, self.bits as u32) as u8;
+
+        self.data[offset] = (self.data[offset] & !(mask << shift)) | (value << shift);
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let (offset, shift) = self.position(x, y);
+        let value = (self.data[offset] >> shift) & self.mask();
+
+        Ok(DigitalGrayscaleColor::from(expand_bits(value as u32, self.bits as u32)))
+    }
+}
+
+pub mod iter;
+mod tests;
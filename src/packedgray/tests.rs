@@ -0,0 +1,221 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::packedgray::PackedGrayPixelBuffer;
+pub use crate::grayscale::GrayscalePixelBuffer;
+pub use grapho_color::DigitalGrayscaleColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalGrayscaleColor{ v: 0xaa };
+        let buffer = PackedGrayPixelBuffer::new_with_background(3, 2, color, None, Some("GRAY2"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod gray1 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = PackedGrayPixelBuffer::new(10, 2, None, None);
+        assert_eq!(buffer.data.len(), 4);
+        assert_eq!(buffer.width, 10);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2);
+        assert_eq!(buffer.get_width(), 10);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2);
+        assert_eq!(buffer.get_fourcc(), "GRAY1");
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 3];
+        match PackedGrayPixelBuffer::new_with_data(10, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = PackedGrayPixelBuffer::new_with_background(
+            10, 2,
+            DigitalGrayscaleColor{ v: 200 },
+            Some(3), Some("GRAY1")
+        );
+
+        assert_eq!(buffer.data, vec![0xff, 0xff, 0, 0xff, 0xff, 0]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = PackedGrayPixelBuffer::new(10, 2, None, Some("GRAY1MSB"));
+
+        match buffer.set_pixel(1, 1, DigitalGrayscaleColor{ v: 255 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(9, 0, DigitalGrayscaleColor{ v: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(10, 0, DigitalGrayscaleColor{ v: 255 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data, vec![0x00, 0x40, 0x40, 0x00]);
+
+        buffer.set_pixel(1, 1, DigitalGrayscaleColor{ v: 127 }).unwrap();
+        assert_eq!(buffer.data[2], 0x00);
+    }
+
+    #[test]
+    fn set_pixel_lsb() {
+        let mut buffer = PackedGrayPixelBuffer::new(10, 1, None, Some("GRAY1LSB"));
+        buffer.set_pixel(1, 0, DigitalGrayscaleColor{ v: 255 }).unwrap();
+        buffer.set_pixel(8, 0, DigitalGrayscaleColor{ v: 255 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0x02, 0x01]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0b1000_0001, 0b0100_0000];
+        let buffer = PackedGrayPixelBuffer::new_with_data(10, 1, data.clone(), None, None).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGrayscaleColor{ v: 255 });
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalGrayscaleColor{ v: 0 });
+        assert_eq!(buffer.get_pixel(7, 0).unwrap(), DigitalGrayscaleColor{ v: 255 });
+        assert_eq!(buffer.get_pixel(9, 0).unwrap(), DigitalGrayscaleColor{ v: 255 });
+
+        let buffer = PackedGrayPixelBuffer::new_with_data(10, 1, data, None, Some("GRAY1LSB")).unwrap();
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGrayscaleColor{ v: 255 });
+        assert_eq!(buffer.get_pixel(6, 0).unwrap(), DigitalGrayscaleColor{ v: 0 });
+        assert_eq!(buffer.get_pixel(9, 0).unwrap(), DigitalGrayscaleColor{ v: 0 });
+
+        match buffer.get_pixel(0, 1) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod gray2 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = PackedGrayPixelBuffer::new(5, 2, None, Some("GRAY2"));
+        assert_eq!(buffer.data.len(), 4);
+        assert_eq!(buffer.get_stride(), 2);
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = PackedGrayPixelBuffer::new_with_background(
+            4, 1,
+            DigitalGrayscaleColor{ v: 0x55 },
+            None, Some("GRAY2")
+        );
+
+        assert_eq!(buffer.data, vec![0x55]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = PackedGrayPixelBuffer::new(5, 1, None, Some("GRAY2"));
+        buffer.set_pixel(0, 0, DigitalGrayscaleColor{ v: 255 }).unwrap();
+        buffer.set_pixel(1, 0, DigitalGrayscaleColor{ v: 0x55 }).unwrap();
+        buffer.set_pixel(4, 0, DigitalGrayscaleColor{ v: 0xaa }).unwrap();
+
+        assert_eq!(buffer.data, vec![0b1101_0000, 0b1000_0000]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0b1110_0100];
+        let buffer = PackedGrayPixelBuffer::new_with_data(4, 1, data.clone(), None, Some("GRAY2MSB")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGrayscaleColor{ v: 0xff });
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalGrayscaleColor{ v: 0xaa });
+        assert_eq!(buffer.get_pixel(2, 0).unwrap(), DigitalGrayscaleColor{ v: 0x55 });
+        assert_eq!(buffer.get_pixel(3, 0).unwrap(), DigitalGrayscaleColor{ v: 0x00 });
+
+        let buffer = PackedGrayPixelBuffer::new_with_data(4, 1, data, None, Some("GRAY2LSB")).unwrap();
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGrayscaleColor{ v: 0x00 });
+        assert_eq!(buffer.get_pixel(3, 0).unwrap(), DigitalGrayscaleColor{ v: 0xff });
+    }
+}
+
+#[cfg(test)]
+mod gray4 {
+    use super::*;
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = PackedGrayPixelBuffer::new_with_background(
+            3, 2,
+            DigitalGrayscaleColor{ v: 0x77 },
+            Some(3), Some("GRAY4")
+        );
+
+        assert_eq!(buffer.data, vec![0x77, 0x77, 0, 0x77, 0x77, 0]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = PackedGrayPixelBuffer::new(3, 1, None, Some("GRAY4LSB"));
+        buffer.set_pixel(0, 0, DigitalGrayscaleColor{ v: 0x12 }).unwrap();
+        buffer.set_pixel(1, 0, DigitalGrayscaleColor{ v: 0xef }).unwrap();
+        buffer.set_pixel(2, 0, DigitalGrayscaleColor{ v: 0x88 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0xe1, 0x08]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0x1f, 0x80];
+        let buffer = PackedGrayPixelBuffer::new_with_data(3, 1, data, None, Some("GRAY4")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalGrayscaleColor{ v: 0x11 });
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalGrayscaleColor{ v: 0xff });
+        assert_eq!(buffer.get_pixel(2, 0).unwrap(), DigitalGrayscaleColor{ v: 0x88 });
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn grayscale_roundtrip() {
+        let mut gray = GrayscalePixelBuffer::new(16, 1, None, None);
+        for x in 0..16 {
+            gray.set_pixel(x, 0, DigitalGrayscaleColor{ v: (x * 17) as u8 }).unwrap();
+        }
+
+        let packed: PackedGrayPixelBuffer = gray.into();
+        assert_eq!(packed.get_fourcc(), "GRAY1");
+
+        let back: GrayscalePixelBuffer = packed.into();
+        for (x, _y, color) in back {
+            assert_eq!(color, DigitalGrayscaleColor{ v: if x < 8 { 0 } else { 255 } });
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Create iterator for PalettePixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::PalettePixelBuffer;
+use grapho_color::DigitalRGBAColor;
+
+
+/// Pixel iterator for `PalettePixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalRGBAColor>`
+#[derive(Debug, PartialEq)]
+pub struct PalettePixelIterator<'a> {
+    base: PalettePixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for PalettePixelIterator<'a> {
+    type Item = Pixel<DigitalRGBAColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for PalettePixelBuffer<'a> {
+    type Item = Pixel<DigitalRGBAColor>;
+    type IntoIter = PalettePixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PalettePixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
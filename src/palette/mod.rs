@@ -0,0 +1,376 @@
+//! This module describes an indexed pixel buffer with 1, 2, 4 or 8 bit indices (`PAL1`, `PAL2`, `PAL4`, `PAL8`)
+//! into a palette of up to 256 colors
+//!
+//! Indices smaller than 8 bit are packed with the first pixel in the most significant bits, every line
+//! starts at a byte boundary. New buffers get a grayscale ramp as palette, use `set_palette` to replace it.
+
+use crate::*;
+pub use grapho_color::DigitalRGBAColor;
+use crate::color::expand_bits;
+
+/// Indexed Pixel buffer, every pixel is an index into a palette of RGBA colors
+#[derive(Debug, PartialEq)]
+pub struct PalettePixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    bits: usize,
+    palette: Vec<DigitalRGBAColor>,
+    nearest_color: bool,
+    data: Vec<u8>
+}
+
+impl<'a> PalettePixelBuffer<'a> {
+    fn decode_bits(fourcc:&'a str) -> usize {
+        match fourcc {
+            "PAL1" => 1,
+            "PAL2" => 2,
+            "PAL4" => 4,
+            "PAL8" | _ => 8,
+        }
+    }
+
+    /// Default stride in bytes, lines are padded to full bytes
+    fn default_stride(width: usize, bits: usize) -> usize {
+        (width * bits + 7) / 8
+    }
+
+    /// Grayscale ramp with one entry for every possible index
+    fn default_palette(bits: usize) -> Vec<DigitalRGBAColor> {
+        (0..(1u32 << bits)).map(|i| {
+            let v = expand_bits(i, bits as u32);
+            DigitalRGBAColor{ r: v, g: v, b: v, a: 255 }
+        }).collect()
+    }
+
+    /// Byte offset and bit shift of the index at `x`, `y`
+    fn position(&self, x: usize, y: usize) -> (usize, usize) {
+        let bit = x * self.bits;
+
+        (y * self.stride + bit / 8, 8 - self.bits - bit % 8)
+    }
+
+    fn mask(&self) -> u8 {
+        ((1u32 << self.bits) - 1) as u8
+    }
+
+    /// Palette index of a color, either an exact match or the nearest color
+    /// if nearest color search is enabled
+    fn lookup(&self, color: DigitalRGBAColor) -> Option<u8> {
+        if let Some(index) = self.palette.iter().position(|entry| *entry == color) {
+            return Some(index as u8);
+        }
+
+        if !self.nearest_color {
+            return None;
+        }
+
+        let distance = |entry: &DigitalRGBAColor| {
+            let dr = entry.r as i32 - color.r as i32;
+            let dg = entry.g as i32 - color.g as i32;
+            let db = entry.b as i32 - color.b as i32;
+            let da = entry.a as i32 - color.a as i32;
+
+            dr * dr + dg * dg + db * db + da * da
+        };
+
+        self.palette.iter()
+            .enumerate()
+            .min_by_key(|(_index, entry)| distance(entry))
+            .map(|(index, _entry)| index as u8)
+    }
+
+    /// Build an indexed buffer from a list of pixels, the palette is made of the
+    /// distinct colors in order of appearance and the smallest fitting index size is used.
+    /// If there are more than 256 colors the remaining ones are mapped to the nearest
+    /// palette entry.
+    pub(crate) fn from_pixels(width: usize, height: usize, pixels: Vec<Pixel<DigitalRGBAColor>>) -> Self {
+        let mut palette: Vec<DigitalRGBAColor> = Vec::new();
+        let mut overflow = false;
+
+        for (_x, _y, color) in pixels.iter() {
+            if !palette.contains(color) {
+                if palette.len() < 256 {
+                    palette.push(*color);
+                } else {
+                    overflow = true;
+                }
+            }
+        }
+
+        let fourcc = match palette.len() {
+            0..=2 => "PAL1",
+            3..=4 => "PAL2",
+            5..=16 => "PAL4",
+            _ => "PAL8"
+        };
+
+        let mut buffer = PalettePixelBuffer::new(width, height, None, Some(fourcc));
+        buffer.palette = palette;
+        buffer.nearest_color = overflow;
+
+        for (x, y, color) in pixels {
+            buffer.set_pixel(x, y, color).unwrap();
+        }
+
+        buffer
+    }
+
+    /// Get the palette of the buffer
+    pub fn get_palette(&self) -> &[DigitalRGBAColor] {
+        &self.palette
+    }
+
+    /// Replace the palette of the buffer, existing indices are kept
+    ///
+    /// # Arguments
+    ///
+    /// * `palette` - the new palette, at most `2 ^ <bits per index>` entries
+    ///
+    /// # Returns
+    ///
+    /// A `Result`, either `()` if everything went ok, `PixelBufferError::PaletteTooLarge`
+    /// if the index size can not address all entries or `PixelBufferError::ColorNotInPalette`
+    /// if a pixel of the buffer uses an index that is not in the new palette
+    pub fn set_palette(&mut self, palette: Vec<DigitalRGBAColor>) -> Result<(), PixelBufferError> {
+        if palette.len() > (1 << self.bits) {
+            return Err(PixelBufferError::PaletteTooLarge);
+        }
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.get_index(x, y)? as usize >= palette.len() {
+                    return Err(PixelBufferError::ColorNotInPalette);
+                }
+            }
+        }
+
+        self.palette = palette;
+
+        Ok(())
+    }
+
+    /// Get whether `set_pixel` maps colors that are not in the palette to the nearest entry
+    pub fn get_nearest_color(&self) -> bool {
+        self.nearest_color
+    }
+
+    /// Enable or disable mapping colors that are not in the palette to the nearest entry
+    /// (by euclidean distance of the RGBA components) in `set_pixel`
+    pub fn set_nearest_color(&mut self, nearest_color: bool) {
+        self.nearest_color = nearest_color;
+    }
+
+    /// Set the palette index of a pixel
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x coordinate (from top left)
+    /// * `y` - y coordinate (from top left)
+    /// * `index` - palette index to set
+    ///
+    /// # Returns
+    ///
+    /// A `Result`, either `()` if everything went ok, `PixelBufferError::RequestOutOfBounds`
+    /// if the request was out of bounds or `PixelBufferError::ColorNotInPalette` if the
+    /// index is not in the palette
+    pub fn set_index(&mut self, x: usize, y: usize, index: u8) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+        if index as usize >= self.palette.len() {
+            return Err(PixelBufferError::ColorNotInPalette);
+        }
+
+        let (offset, shift) = self.position(x, y);
+        let mask = self.mask();
+
+        self.data[offset] = (self.data[offset] & !(mask << shift)) | (index << shift);
+
+        Ok(())
+    }
+
+    /// Get the palette index of a pixel
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x coordinate (from top left)
+    /// * `y` - y coordinate (from top left)
+    ///
+    /// # Returns
+    ///
+    /// A `Result`, either the index if everything went ok, or
+    /// `PixelBufferError::RequestOutOfBounds` if the request was out of bounds
+    pub fn get_index(&self, x: usize, y: usize) -> Result<u8, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let (offset, shift) = self.position(x, y);
+
+        Ok((self.data[offset] >> shift) & self.mask())
+    }
+}
+
+impl<'a> PixelBuffer<'a> for PalettePixelBuffer<'a> {
+    type ColorType = DigitalRGBAColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<bits per index> * width` rounded up to full bytes
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `PAL1`, 1 bit indices, 2 colors
+    /// * `PAL2`, 2 bit indices, 4 colors
+    /// * `PAL4`, 4 bit indices, 16 colors
+    /// * `PAL8` (default), 8 bit indices, 256 colors
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `PalettePixelBuffer` with it's contents set to zero
+    /// and a grayscale ramp as palette
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("PAL8");
+        let bits = PalettePixelBuffer::decode_bits(f);
+        let line_width = stride.unwrap_or(PalettePixelBuffer::default_stride(width, bits));
+
+        PalettePixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            bits,
+            palette: PalettePixelBuffer::default_palette(bits),
+            nearest_color: false
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<bits per index> * width` rounded up to full bytes
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `PAL1`, 1 bit indices, 2 colors
+    /// * `PAL2`, 2 bit indices, 4 colors
+    /// * `PAL4`, 4 bit indices, 16 colors
+    /// * `PAL8` (default), 8 bit indices, 256 colors
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `PalettePixelBuffer` with a
+    /// grayscale ramp as palette or `PixelBufferError::BufferTooSmall` if the buffer is
+    /// too small for the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("PAL8");
+        let bits = PalettePixelBuffer::decode_bits(f);
+        let line_width = stride.unwrap_or(PalettePixelBuffer::default_stride(width, bits));
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            PalettePixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                bits,
+                palette: PalettePixelBuffer::default_palette(bits),
+                nearest_color: false
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<bits per index> * width` rounded up to full bytes
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `PAL1`, 1 bit indices, 2 colors
+    /// * `PAL2`, 2 bit indices, 4 colors
+    /// * `PAL4`, 4 bit indices, 16 colors
+    /// * `PAL8` (default), 8 bit indices, 256 colors
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `PalettePixelBuffer` with a palette containing only
+    /// the fill color and all indices set to it. If stride is bigger than needed width
+    /// the padding is filled with zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = PalettePixelBuffer::new(width, height, stride, fourcc);
+        buffer.palette = vec![color];
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    /// Set a pixel to the palette entry matching `color`, if nearest color search is
+    /// disabled (default) and the color is not in the palette this returns
+    /// `PixelBufferError::ColorNotInPalette`
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        match self.lookup(color) {
+            Some(index) => self.set_index(x, y, index),
+            None => Err(PixelBufferError::ColorNotInPalette)
+        }
+    }
+
+    /// Get the palette color of a pixel, if the index is not in the palette this
+    /// returns `PixelBufferError::ColorNotInPalette`
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        let index = self.get_index(x, y)?;
+
+        match self.palette.get(index as usize) {
+            Some(color) => Ok(*color),
+            None => Err(PixelBufferError::ColorNotInPalette)
+        }
+    }
+}
+
+pub mod iter;
+mod tests;
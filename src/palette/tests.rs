@@ -0,0 +1,235 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::palette::PalettePixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalRGBAColor{ r: 255, g: 64, b: 0, a: 255 };
+        let buffer = PalettePixelBuffer::new_with_background(3, 2, color, None, Some("PAL2"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod pal8 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = PalettePixelBuffer::new(2, 2, None, None);
+        assert_eq!(buffer.data.len(), 4);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2);
+        assert_eq!(buffer.get_fourcc(), "PAL8");
+        assert_eq!(buffer.get_palette().len(), 256);
+        assert_eq!(buffer.get_palette()[128], DigitalRGBAColor{ r: 128, g: 128, b: 128, a: 255 });
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 3];
+        match PalettePixelBuffer::new_with_data(2, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let color = DigitalRGBAColor{ r: 1, g: 2, b: 3, a: 4 };
+        let buffer = PalettePixelBuffer::new_with_background(2, 2, color, Some(3), None);
+
+        assert_eq!(buffer.data, vec![0; 6]);
+        assert_eq!(buffer.get_palette(), &[color]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = PalettePixelBuffer::new(2, 2, None, None);
+        let red = DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 };
+        let blue = DigitalRGBAColor{ r: 0, g: 0, b: 255, a: 255 };
+        buffer.set_palette(vec![red, blue]).unwrap();
+
+        match buffer.set_pixel(1, 1, blue) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, blue) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        match buffer.set_pixel(0, 0, DigitalRGBAColor{ r: 200, g: 0, b: 0, a: 255 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::ColorNotInPalette),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data, vec![0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn set_palette_unused_index() {
+        let mut buffer = PalettePixelBuffer::new_with_data(2, 1, vec![0, 200], None, Some("PAL8")).unwrap();
+        let black = DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 255 };
+
+        match buffer.set_palette(vec![black]) {
+            Err(error) => assert_eq!(error, PixelBufferError::ColorNotInPalette),
+            _ => assert!(false)
+        }
+        assert_eq!(buffer.get_palette().len(), 256);
+
+        let rgb = RGBPixelBuffer::from(buffer);
+        assert_eq!(rgb.get_pixel(1, 0).unwrap(), DigitalRGBAColor{ r: 200, g: 200, b: 200, a: 255 });
+    }
+
+    #[test]
+    fn set_pixel_nearest() {
+        let mut buffer = PalettePixelBuffer::new(2, 1, None, None);
+        let red = DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 };
+        let blue = DigitalRGBAColor{ r: 0, g: 0, b: 255, a: 255 };
+        buffer.set_palette(vec![red, blue]).unwrap();
+        buffer.set_nearest_color(true);
+
+        buffer.set_pixel(0, 0, DigitalRGBAColor{ r: 20, g: 10, b: 200, a: 255 }).unwrap();
+        buffer.set_pixel(1, 0, DigitalRGBAColor{ r: 200, g: 10, b: 20, a: 255 }).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), blue);
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), red);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0, 1, 2, 255];
+        let mut buffer = PalettePixelBuffer::new_with_data(2, 2, data, None, None).unwrap();
+
+        assert_eq!(buffer.get_pixel(1, 1).unwrap(), DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 });
+
+        let red = DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 };
+        let blue = DigitalRGBAColor{ r: 0, g: 0, b: 255, a: 255 };
+        match buffer.set_palette(vec![red, blue]) {
+            Err(error) => assert_eq!(error, PixelBufferError::ColorNotInPalette),
+            _ => assert!(false)
+        }
+
+        buffer.set_index(0, 1, 1).unwrap();
+        buffer.set_index(1, 1, 0).unwrap();
+        buffer.set_palette(vec![red, blue]).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), red);
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), blue);
+        assert_eq!(buffer.get_pixel(0, 1).unwrap(), blue);
+        assert_eq!(buffer.get_pixel(1, 1).unwrap(), red);
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod pal1 {
+    use super::*;
+
+    #[test]
+    fn palette_too_large() {
+        let mut buffer = PalettePixelBuffer::new(10, 1, None, Some("PAL1"));
+        assert_eq!(buffer.get_stride(), 2);
+        assert_eq!(buffer.get_palette().len(), 2);
+
+        let color = DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 255 };
+        match buffer.set_palette(vec![color, color, color]) {
+            Err(error) => assert_eq!(error, PixelBufferError::PaletteTooLarge),
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn set_index() {
+        let mut buffer = PalettePixelBuffer::new(10, 1, None, Some("PAL1"));
+        buffer.set_index(0, 0, 1).unwrap();
+        buffer.set_index(9, 0, 1).unwrap();
+
+        match buffer.set_index(1, 0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::ColorNotInPalette),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data, vec![0x80, 0x40]);
+    }
+}
+
+#[cfg(test)]
+mod pal4 {
+    use super::*;
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0x0f, 0x80];
+        let buffer = PalettePixelBuffer::new_with_data(3, 1, data, None, Some("PAL4")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 255 });
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 });
+        assert_eq!(buffer.get_pixel(2, 0).unwrap(), DigitalRGBAColor{ r: 0x88, g: 0x88, b: 0x88, a: 255 });
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb_roundtrip() {
+        let mut rgb = RGBPixelBuffer::new(3, 2, None, Some("RGBA"));
+        let colors = [
+            DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 },
+            DigitalRGBAColor{ r: 0, g: 255, b: 0, a: 128 },
+            DigitalRGBAColor{ r: 0, g: 0, b: 255, a: 0 },
+        ];
+        for y in 0..2 {
+            for x in 0..3 {
+                rgb.set_pixel(x, y, colors[(x + y) % 3]).unwrap();
+            }
+        }
+
+        let palette: PalettePixelBuffer = rgb.into();
+        assert_eq!(palette.get_fourcc(), "PAL2");
+        assert_eq!(palette.get_palette(), &colors);
+
+        let back: RGBPixelBuffer = palette.into();
+        assert_eq!(back.get_fourcc(), "RGBA");
+        for (x, y, color) in back {
+            assert_eq!(color, colors[(x + y) % 3]);
+        }
+    }
+
+    #[test]
+    fn too_many_colors() {
+        let mut rgb = RGBPixelBuffer::new(300, 1, None, None);
+        for x in 0..300 {
+            rgb.set_pixel(x, 0, DigitalRGBAColor{ r: (x / 2) as u8, g: (x % 2) as u8, b: 0, a: 255 }).unwrap();
+        }
+
+        let palette: PalettePixelBuffer = rgb.into();
+        assert_eq!(palette.get_fourcc(), "PAL8");
+        assert_eq!(palette.get_palette().len(), 256);
+        assert_eq!(palette.get_pixel(299, 0).unwrap(), DigitalRGBAColor{ r: 127, g: 1, b: 0, a: 255 });
+    }
+}
@@ -33,6 +33,11 @@ impl<'a> RGBPixelBuffer<'a> {
             "RGB" | _ => vec![RGBComponent::Red, RGBComponent::Green, RGBComponent::Blue],
         }       
     }
+
+    /// Whether the buffer format stores an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        self.component_order.contains(&RGBComponent::Alpha)
+    }
 }
 
 impl<'a> PixelBuffer<'a> for RGBPixelBuffer<'a> {
@@ -205,7 +210,7 @@ impl<'a> PixelBuffer<'a> for RGBPixelBuffer<'a> {
     }
 
     fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
-        if (x >= self.width) || (y >= self.width) {
+        if (x >= self.width) || (y >= self.height) {
             return Err(PixelBufferError::RequestOutOfBounds);
         }
 
@@ -220,7 +225,7 @@ impl<'a> PixelBuffer<'a> for RGBPixelBuffer<'a> {
     }
 
     fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
-        if (x >= self.width) || (y >= self.width) {
+        if (x >= self.width) || (y >= self.height) {
             return Err(PixelBufferError::RequestOutOfBounds);
         }
 
@@ -19,6 +19,18 @@ mod iter {
             assert_eq!(pixel.2, color);
         }
     }
+
+    #[test]
+    fn iter_coordinates() {
+        let color = DigitalRGBAColor{ r: 255, g: 64, b: 0, a: 255 };
+        let mut buffer = RGBPixelBuffer::new(2, 2, None, Some("RGB"));
+        buffer.set_pixel(1, 0, color).unwrap();
+
+        let pixels: Vec<_> = buffer.into_iter().collect();
+        let coordinates: Vec<(usize, usize)> = pixels.iter().map(|pixel| (pixel.0, pixel.1)).collect();
+        assert_eq!(coordinates, vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+        assert_eq!(pixels[1].2, color);
+    }
 }
 
 #[cfg(test)]
@@ -129,6 +141,29 @@ mod rgb {
             Ok(_color) => assert!(false)
         }
     }
+
+    #[test]
+    fn non_square_bounds() {
+        let mut wide = RGBPixelBuffer::new(4, 2, None, Some("RGB"));
+        match wide.set_pixel(0, 3, DigitalRGBAColor{ r: 255, g: 64, b: 0, a: 255 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+        match wide.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+
+        let mut tall = RGBPixelBuffer::new(2, 4, None, Some("RGB"));
+        match tall.set_pixel(1, 3, DigitalRGBAColor{ r: 255, g: 64, b: 0, a: 255 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+        match tall.get_pixel(1, 3) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalRGBAColor{ r: 255, g: 64, b: 0, a: 255 })
+        }
+    }
 }
 
 #[cfg(test)]
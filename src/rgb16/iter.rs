@@ -0,0 +1,53 @@
+//! Create iterator for RGB16PixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::RGB16PixelBuffer;
+use grapho_color::DigitalRGBAColor;
+
+
+/// Pixel iterator for `RGB16PixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalRGBColor>`
+#[derive(Debug, PartialEq)]
+pub struct RGB16PixelIterator<'a> {
+    base: RGB16PixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for RGB16PixelIterator<'a> {
+    type Item = Pixel<DigitalRGBAColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for RGB16PixelBuffer<'a> {
+    type Item = Pixel<DigitalRGBAColor>;
+    type IntoIter = RGB16PixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RGB16PixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
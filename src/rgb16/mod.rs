@@ -0,0 +1,269 @@
+//! This module describes a packed 16 bit RGB pixel buffer (`RGB565`, `RGB555`, `ARGB1555`,
+//! `RGB444`, `ARGB4444`) in little or big endian byte order
+//!
+//! Components are expanded to 8 bit by replicating their high bits into the low bits
+//! when reading, and rounded to the nearest representable value when writing.
+
+use crate::*;
+pub use grapho_color::DigitalRGBAColor;
+pub use crate::rgb::RGBComponent;
+use crate::color::{ expand_bits, reduce_bits };
+
+/// Bit position and width of one component in the packed 16 bit pixel, a width of
+/// zero means the component is not present
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct PackedComponent {
+    shift: u32,
+    bits: u32
+}
+
+/// Packed 16 bit RGB Pixel buffer, with or without alpha channel
+#[derive(Debug, PartialEq)]
+pub struct RGB16PixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    layout: [PackedComponent; 4],
+    big_endian: bool,
+    data: Vec<u8>
+}
+
+impl<'a> RGB16PixelBuffer<'a> {
+    /// Decode the component layout, indexed by `RGBComponent`
+    fn decode_layout(fourcc:&'a str) -> [PackedComponent; 4] {
+        let c = |shift, bits| PackedComponent { shift, bits };
+
+        match fourcc.trim_end_matches("LE").trim_end_matches("BE") {
+            "RGB555" | "XRGB1555" => [c(10, 5), c(5, 5), c(0, 5), c(0, 0)],
+            "ARGB1555" => [c(10, 5), c(5, 5), c(0, 5), c(15, 1)],
+            "RGB444" | "XRGB4444" => [c(8, 4), c(4, 4), c(0, 4), c(0, 0)],
+            "ARGB4444" => [c(8, 4), c(4, 4), c(0, 4), c(12, 4)],
+            "RGB565" | _ => [c(11, 5), c(5, 6), c(0, 5), c(0, 0)],
+        }
+    }
+
+    fn decode_big_endian(fourcc:&'a str) -> bool {
+        fourcc.ends_with("BE")
+    }
+
+    /// Whether the buffer format stores an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        self.layout[3].bits > 0
+    }
+
+    fn pack(&self, color: DigitalRGBAColor) -> u16 {
+        let repr: [u8; 4] = color.into();
+        let mut packed: u16 = 0;
+
+        for i in 0..4 {
+            let component = self.layout[i];
+            if component.bits > 0 {
+                packed |= (reduce_bits(repr[i], component.bits) << component.shift) as u16;
+            }
+        }
+
+        packed
+    }
+
+    fn unpack(&self, packed: u16) -> DigitalRGBAColor {
+        let mut repr: [u8; 4] = [0, 0, 0, 255];
+
+        for i in 0..4 {
+            let component = self.layout[i];
+            if component.bits > 0 {
+                let value = (packed >> component.shift) & ((1 << component.bits) - 1);
+                repr[i] = expand_bits(value as u32, component.bits);
+            }
+        }
+
+        DigitalRGBAColor::from(repr)
+    }
+
+    fn encode(&self, value: u16) -> [u8; 2] {
+        if self.big_endian {
+            value.to_be_bytes()
+        } else {
+            value.to_le_bytes()
+        }
+    }
+
+    fn decode(&self, bytes: [u8; 2]) -> u16 {
+        if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        }
+    }
+}
+
+impl<'a> PixelBuffer<'a> for RGB16PixelBuffer<'a> {
+    type ColorType = DigitalRGBAColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `2 * width`
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGB565`, `RGB565LE` (default), `RGB565BE`
+    /// * `RGB555`, `RGB555LE`, `RGB555BE`, `XRGB1555`, `XRGB1555LE`, `XRGB1555BE`
+    /// * `ARGB1555`, `ARGB1555LE`, `ARGB1555BE`
+    /// * `RGB444`, `RGB444LE`, `RGB444BE`, `XRGB4444`, `XRGB4444LE`, `XRGB4444BE`
+    /// * `ARGB4444`, `ARGB4444LE`, `ARGB4444BE`
+    ///
+    /// Codes without an endianness suffix are little endian.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `RGB16PixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("RGB565LE");
+        let line_width = stride.unwrap_or(width * 2);
+
+        RGB16PixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            layout: RGB16PixelBuffer::decode_layout(f),
+            big_endian: RGB16PixelBuffer::decode_big_endian(f)
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `2 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGB565`, `RGB565LE` (default), `RGB565BE`
+    /// * `RGB555`, `RGB555LE`, `RGB555BE`, `XRGB1555`, `XRGB1555LE`, `XRGB1555BE`
+    /// * `ARGB1555`, `ARGB1555LE`, `ARGB1555BE`
+    /// * `RGB444`, `RGB444LE`, `RGB444BE`, `XRGB4444`, `XRGB4444LE`, `XRGB4444BE`
+    /// * `ARGB4444`, `ARGB4444LE`, `ARGB4444BE`
+    ///
+    /// Codes without an endianness suffix are little endian.
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `RGB16PixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("RGB565LE");
+        let line_width = stride.unwrap_or(width * 2);
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            RGB16PixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                layout: RGB16PixelBuffer::decode_layout(f),
+                big_endian: RGB16PixelBuffer::decode_big_endian(f)
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `2 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGB565`, `RGB565LE` (default), `RGB565BE`
+    /// * `RGB555`, `RGB555LE`, `RGB555BE`, `XRGB1555`, `XRGB1555LE`, `XRGB1555BE`
+    /// * `ARGB1555`, `ARGB1555LE`, `ARGB1555BE`
+    /// * `RGB444`, `RGB444LE`, `RGB444BE`, `XRGB4444`, `XRGB4444LE`, `XRGB4444BE`
+    /// * `ARGB4444`, `ARGB4444LE`, `ARGB4444BE`
+    ///
+    /// Codes without an endianness suffix are little endian.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `RGB16PixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = RGB16PixelBuffer::new(width, height, stride, fourcc);
+        let representation = buffer.encode(buffer.pack(color));
+
+        let mut line = representation.repeat(width);
+        if buffer.stride > width * 2 {
+            line.extend([0].repeat(buffer.stride - width * 2));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 2 + y * self.stride;
+        let bytes = self.encode(self.pack(color));
+
+        self.data[start] = bytes[0];
+        self.data[start + 1] = bytes[1];
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 2 + y * self.stride;
+
+        Ok(self.unpack(self.decode([self.data[start], self.data[start + 1]])))
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,185 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::rgb16::RGB16PixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalRGBAColor{ r: 255, g: 0, b: 255, a: 255 };
+        let buffer = RGB16PixelBuffer::new_with_background(2, 2, color, None, Some("RGB565"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod rgb565 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = RGB16PixelBuffer::new(2, 2, None, Some("RGB565"));
+        assert_eq!(buffer.data.len(), 8);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 4);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 4);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 7];
+        match RGB16PixelBuffer::new_with_data(2, 2, data, None, Some("RGB565")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = RGB16PixelBuffer::new_with_background(
+            2, 2,
+            DigitalRGBAColor{ r: 255, g: 0, b: 255, a: 255 },
+            Some(6), Some("RGB565LE")
+        );
+
+        assert_eq!(buffer.data.len(), 12);
+        for y in 0..2 {
+            assert_eq!(buffer.data[y * 6..y * 6 + 6], [0x1f, 0xf8, 0x1f, 0xf8, 0, 0], "y: {}", y);
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = RGB16PixelBuffer::new(2, 2, None, Some("RGB565"));
+
+        match buffer.set_pixel(1, 1, DigitalRGBAColor{ r: 4, g: 130, b: 251, a: 255 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(0, 2, DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 255 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        // r: 4 -> 0.49 -> 0, g: 130 -> 32.1 -> 32, b: 251 -> 30.5 -> 31
+        let expected: u16 = 0 << 11 | 32 << 5 | 31;
+        assert_eq!(buffer.data[6..8], expected.to_le_bytes());
+    }
+
+    #[test]
+    fn get_pixel() {
+        let value: u16 = 16 << 11 | 32 << 5 | 1;
+        let mut data = value.to_le_bytes().to_vec();
+        data.extend_from_slice(&0xffffu16.to_le_bytes());
+        let buffer = RGB16PixelBuffer::new_with_data(2, 1, data, None, Some("RGB565")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalRGBAColor{ r: 132, g: 130, b: 8, a: 255 })
+        }
+
+        match buffer.get_pixel(1, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 })
+        }
+    }
+
+    #[test]
+    fn big_endian() {
+        let mut buffer = RGB16PixelBuffer::new(1, 1, None, Some("RGB565BE"));
+        buffer.set_pixel(0, 0, DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0xf8, 0x00]);
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 255 });
+    }
+}
+
+#[cfg(test)]
+mod rgb555 {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = RGB16PixelBuffer::new(1, 1, None, Some("RGB555"));
+        buffer.set_pixel(0, 0, DigitalRGBAColor{ r: 255, g: 255, b: 0, a: 0 }).unwrap();
+
+        assert_eq!(buffer.data, 0x7fe0u16.to_le_bytes().to_vec());
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 255, b: 0, a: 255 });
+    }
+}
+
+#[cfg(test)]
+mod argb1555 {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = RGB16PixelBuffer::new(2, 1, None, Some("ARGB1555BE"));
+        buffer.set_pixel(0, 0, DigitalRGBAColor{ r: 0, g: 0, b: 255, a: 200 }).unwrap();
+        buffer.set_pixel(1, 0, DigitalRGBAColor{ r: 0, g: 0, b: 255, a: 100 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0x80, 0x1f, 0x00, 0x1f]);
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 0, g: 0, b: 255, a: 255 });
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalRGBAColor{ r: 0, g: 0, b: 255, a: 0 });
+    }
+}
+
+#[cfg(test)]
+mod argb4444 {
+    use super::*;
+
+    #[test]
+    fn get_pixel() {
+        let data = 0x1234u16.to_le_bytes().to_vec();
+        let buffer = RGB16PixelBuffer::new_with_data(1, 1, data, None, Some("ARGB4444")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 0x22, g: 0x33, b: 0x44, a: 0x11 });
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut buffer = RGB16PixelBuffer::new(1, 1, None, Some("ARGB4444"));
+        for v in 0..16 {
+            let color = DigitalRGBAColor{ r: v * 17, g: v * 17, b: v * 17, a: v * 17 };
+            buffer.set_pixel(0, 0, color).unwrap();
+            assert_eq!(buffer.get_pixel(0, 0).unwrap(), color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb_alpha() {
+        let rgba = RGBPixelBuffer::new_with_background(
+            2, 2,
+            DigitalRGBAColor{ r: 0x11, g: 0x22, b: 0x33, a: 0x44 },
+            None, Some("RGBA")
+        );
+
+        let rgb16: RGB16PixelBuffer = rgba.into();
+        assert_eq!(rgb16.get_fourcc(), "ARGB4444");
+
+        let back: RGBPixelBuffer = rgb16.into();
+        assert_eq!(back.get_fourcc(), "RGBA");
+        for (x, y, color) in back {
+            assert_eq!(color, DigitalRGBAColor{ r: 0x11, g: 0x22, b: 0x33, a: 0x44 }, "x: {}, y: {}", x, y);
+        }
+    }
+}
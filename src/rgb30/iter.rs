@@ -0,0 +1,53 @@
+//! Create iterator for RGB30PixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::RGB30PixelBuffer;
+use crate::color::DigitalRGBA64Color;
+
+
+/// Pixel iterator for `RGB30PixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalRGBA64Color>`
+#[derive(Debug, PartialEq)]
+pub struct RGB30PixelIterator<'a> {
+    base: RGB30PixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for RGB30PixelIterator<'a> {
+    type Item = Pixel<DigitalRGBA64Color>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for RGB30PixelBuffer<'a> {
+    type Item = Pixel<DigitalRGBA64Color>;
+    type IntoIter = RGB30PixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RGB30PixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
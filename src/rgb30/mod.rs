@@ -0,0 +1,225 @@
+//! This module describes a packed 10 bit per component RGB pixel buffer (`AR30`, `AB30`,
+//! `XR30`, `XB30`), 2:10:10:10 components in little endian 32 bit words
+//!
+//! Components are handled as `DigitalRGBA64Color`, reading expands the 10 bit values to 16 bit
+//! by bit replication, writing rounds to the nearest 10 bit value.
+
+use crate::*;
+pub use crate::color::DigitalRGBA64Color;
+pub use crate::rgb::RGBComponent;
+use crate::color::{ expand_bits16, reduce_bits16 };
+
+/// Packed 10 bit RGB Pixel buffer, with or without 2 bit alpha channel
+#[derive(Debug, PartialEq)]
+pub struct RGB30PixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: Vec<RGBComponent>,
+    data: Vec<u8>
+}
+
+impl<'a> RGB30PixelBuffer<'a> {
+    /// Decode component order from the least significant bits upwards, the first three
+    /// components have 10 bits, the optional last one 2 bits
+    fn decode_component_order(fourcc:&'a str) -> Vec<RGBComponent> {
+        match fourcc {
+            "AB30" => vec![RGBComponent::Red, RGBComponent::Green, RGBComponent::Blue, RGBComponent::Alpha],
+            "XB30" => vec![RGBComponent::Red, RGBComponent::Green, RGBComponent::Blue],
+            "XR30" => vec![RGBComponent::Blue, RGBComponent::Green, RGBComponent::Red],
+            "AR30" | _ => vec![RGBComponent::Blue, RGBComponent::Green, RGBComponent::Red, RGBComponent::Alpha],
+        }
+    }
+
+    /// Whether the buffer format stores an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        self.component_order.contains(&RGBComponent::Alpha)
+    }
+
+    fn pack(&self, color: DigitalRGBA64Color) -> u32 {
+        let repr: [u16; 4] = color.into();
+        let mut packed: u32 = 0;
+
+        for i in 0..self.component_order.len() {
+            let bits = if i == 3 { 2 } else { 10 };
+            packed |= reduce_bits16(repr[self.component_order[i] as usize], bits) << (i * 10);
+        }
+
+        packed
+    }
+
+    fn unpack(&self, packed: u32) -> DigitalRGBA64Color {
+        let mut repr: [u16; 4] = [0, 0, 0, 65535];
+
+        for i in 0..self.component_order.len() {
+            let bits = if i == 3 { 2 } else { 10 };
+            let value = (packed >> (i * 10)) & ((1 << bits) - 1);
+            repr[self.component_order[i] as usize] = expand_bits16(value, bits);
+        }
+
+        DigitalRGBA64Color::from(repr)
+    }
+}
+
+impl<'a> PixelBuffer<'a> for RGB30PixelBuffer<'a> {
+    type ColorType = DigitalRGBA64Color;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `4 * width`
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `AR30` (default), 2 bit alpha, 10 bit red, green and blue
+    /// * `AB30`, 2 bit alpha, 10 bit blue, green and red
+    /// * `XR30`, 2 bit unused, 10 bit red, green and blue
+    /// * `XB30`, 2 bit unused, 10 bit blue, green and red
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `RGB30PixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("AR30");
+        let line_width = stride.unwrap_or(width * 4);
+
+        RGB30PixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            component_order: RGB30PixelBuffer::decode_component_order(f)
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `4 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `AR30` (default), 2 bit alpha, 10 bit red, green and blue
+    /// * `AB30`, 2 bit alpha, 10 bit blue, green and red
+    /// * `XR30`, 2 bit unused, 10 bit red, green and blue
+    /// * `XB30`, 2 bit unused, 10 bit blue, green and red
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `RGB30PixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("AR30");
+        let line_width = stride.unwrap_or(width * 4);
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            RGB30PixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                component_order: RGB30PixelBuffer::decode_component_order(f)
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `4 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `AR30` (default), 2 bit alpha, 10 bit red, green and blue
+    /// * `AB30`, 2 bit alpha, 10 bit blue, green and red
+    /// * `XR30`, 2 bit unused, 10 bit red, green and blue
+    /// * `XB30`, 2 bit unused, 10 bit blue, green and red
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `RGB30PixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = RGB30PixelBuffer::new(width, height, stride, fourcc);
+        let representation = buffer.pack(color).to_le_bytes();
+
+        let mut line = representation.repeat(width);
+        if buffer.stride > width * 4 {
+            line.extend([0].repeat(buffer.stride - width * 4));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 4 + y * self.stride;
+        let bytes = self.pack(color).to_le_bytes();
+
+        self.data[start..start + 4].copy_from_slice(&bytes);
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 4 + y * self.stride;
+        let packed = u32::from_le_bytes([
+            self.data[start], self.data[start + 1], self.data[start + 2], self.data[start + 3]
+        ]);
+
+        Ok(self.unpack(packed))
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,155 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::rgb30::RGB30PixelBuffer;
+pub use crate::rgb48::RGB48PixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::color::DigitalRGBA64Color;
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalRGBA64Color{ r: 65535, g: 0, b: 0x8020, a: 0x5555 };
+        let buffer = RGB30PixelBuffer::new_with_background(2, 2, color, None, Some("AR30"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod ar30 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = RGB30PixelBuffer::new(2, 2, None, Some("AR30"));
+        assert_eq!(buffer.data.len(), 16);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 8);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 8);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 15];
+        match RGB30PixelBuffer::new_with_data(2, 2, data, None, Some("AR30")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = RGB30PixelBuffer::new_with_background(
+            1, 2,
+            DigitalRGBA64Color{ r: 65535, g: 0, b: 65535, a: 65535 },
+            Some(6), Some("AR30")
+        );
+
+        let value: u32 = 3 << 30 | 1023 << 20 | 1023;
+        let bytes = value.to_le_bytes();
+        assert_eq!(buffer.data, vec![bytes[0], bytes[1], bytes[2], bytes[3], 0, 0, bytes[0], bytes[1], bytes[2], bytes[3], 0, 0]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = RGB30PixelBuffer::new(2, 2, None, Some("AR30"));
+
+        match buffer.set_pixel(1, 1, DigitalRGBA64Color{ r: 0x8000, g: 0x0021, b: 0x001f, a: 0x6000 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(0, 2, DigitalRGBA64Color{ r: 0, g: 0, b: 0, a: 0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        // r: 512.01 -> 512, g: 0.52 -> 1, b: 0.48 -> 0, a: 1.13 -> 1
+        let value: u32 = 1 << 30 | 512 << 20 | 1 << 10;
+        assert_eq!(buffer.data[12..16], value.to_le_bytes());
+    }
+
+    #[test]
+    fn get_pixel() {
+        let value: u32 = 2 << 30 | 512 << 20 | 1 << 10 | 1023;
+        let buffer = RGB30PixelBuffer::new_with_data(1, 1, value.to_le_bytes().to_vec(), None, Some("AR30")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalRGBA64Color{ r: 0x8020, g: 0x0040, b: 65535, a: 0xaaaa })
+        }
+    }
+}
+
+#[cfg(test)]
+mod ab30 {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = RGB30PixelBuffer::new(1, 1, None, Some("AB30"));
+        buffer.set_pixel(0, 0, DigitalRGBA64Color{ r: 65535, g: 0, b: 0, a: 65535 }).unwrap();
+
+        let value: u32 = 3 << 30 | 1023;
+        assert_eq!(buffer.data, value.to_le_bytes().to_vec());
+    }
+}
+
+#[cfg(test)]
+mod xr30 {
+    use super::*;
+
+    #[test]
+    fn get_pixel() {
+        let value: u32 = 0 << 30 | 1023 << 20;
+        let buffer = RGB30PixelBuffer::new_with_data(1, 1, value.to_le_bytes().to_vec(), None, Some("XR30")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalRGBA64Color{ r: 65535, g: 0, b: 0, a: 65535 });
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb_roundtrip() {
+        let mut rgb = RGBPixelBuffer::new(256, 1, None, Some("RGB"));
+        for x in 0..256 {
+            rgb.set_pixel(x, 0, DigitalRGBAColor{ r: x as u8, g: 255 - x as u8, b: x as u8, a: 255 }).unwrap();
+        }
+
+        let rgb30: RGB30PixelBuffer = rgb.into();
+        assert_eq!(rgb30.get_fourcc(), "XR30");
+
+        let back: RGBPixelBuffer = rgb30.into();
+        for (x, _y, color) in back {
+            assert_eq!(color, DigitalRGBAColor{ r: x as u8, g: 255 - x as u8, b: x as u8, a: 255 });
+        }
+    }
+
+    #[test]
+    fn rgb48() {
+        let rgb30 = RGB30PixelBuffer::new_with_background(
+            1, 1,
+            DigitalRGBA64Color{ r: 0x8020, g: 0x0040, b: 65535, a: 0xaaaa },
+            None, Some("AR30")
+        );
+
+        let rgb48: RGB48PixelBuffer = rgb30.into();
+        assert_eq!(rgb48.get_fourcc(), "RGBA64LE");
+        assert_eq!(rgb48.get_pixel(0, 0).unwrap(), DigitalRGBA64Color{ r: 0x8020, g: 0x0040, b: 65535, a: 0xaaaa });
+    }
+}
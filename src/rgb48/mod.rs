@@ -0,0 +1,244 @@
+//! This module describes an interleaved RGB pixel buffer with 16 bit per component
+//! (`RGB48` and `RGBA64`) in little or big endian byte order
+
+use crate::*;
+pub use crate::color::DigitalRGBA64Color;
+pub use crate::rgb::RGBComponent;
+
+/// RGB Pixel buffer with 16 bit components, with or without alpha channel
+#[derive(Debug, PartialEq)]
+pub struct RGB48PixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: Vec<RGBComponent>,
+    big_endian: bool,
+    data: Vec<u8>
+}
+
+impl<'a> RGB48PixelBuffer<'a> {
+    fn decode_component_order(fourcc:&'a str) -> Vec<RGBComponent> {
+        match fourcc {
+            "BGR48" | "BGR48LE" | "BGR48BE" =>
+                vec![RGBComponent::Blue, RGBComponent::Green, RGBComponent::Red],
+            "RGBA64" | "RGBA64LE" | "RGBA64BE" =>
+                vec![RGBComponent::Red, RGBComponent::Green, RGBComponent::Blue, RGBComponent::Alpha],
+            "BGRA64" | "BGRA64LE" | "BGRA64BE" =>
+                vec![RGBComponent::Blue, RGBComponent::Green, RGBComponent::Red, RGBComponent::Alpha],
+            "RGB48" | "RGB48LE" | "RGB48BE" | _ =>
+                vec![RGBComponent::Red, RGBComponent::Green, RGBComponent::Blue],
+        }
+    }
+
+    /// Whether the buffer format stores an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        self.component_order.contains(&RGBComponent::Alpha)
+    }
+
+    fn decode_big_endian(fourcc:&'a str) -> bool {
+        fourcc.ends_with("BE")
+    }
+
+    fn encode(&self, value: u16) -> [u8; 2] {
+        if self.big_endian {
+            value.to_be_bytes()
+        } else {
+            value.to_le_bytes()
+        }
+    }
+
+    fn decode(&self, bytes: [u8; 2]) -> u16 {
+        if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        }
+    }
+}
+
+impl<'a> PixelBuffer<'a> for RGB48PixelBuffer<'a> {
+    type ColorType = DigitalRGBA64Color;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `2 * <number of components> * width`
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGB48`, `RGB48LE` (default), `RGB48BE`
+    /// * `BGR48`, `BGR48LE`, `BGR48BE`
+    /// * `RGBA64`, `RGBA64LE`, `RGBA64BE`
+    /// * `BGRA64`, `BGRA64LE`, `BGRA64BE`
+    ///
+    /// Codes without an endianness suffix are little endian.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `RGB48PixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("RGB48LE");
+        let component_order = RGB48PixelBuffer::decode_component_order(f);
+        let line_width = stride.unwrap_or(width * component_order.len() * 2);
+
+        RGB48PixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            component_order,
+            big_endian: RGB48PixelBuffer::decode_big_endian(f)
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `2 * <number of components> * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGB48`, `RGB48LE` (default), `RGB48BE`
+    /// * `BGR48`, `BGR48LE`, `BGR48BE`
+    /// * `RGBA64`, `RGBA64LE`, `RGBA64BE`
+    /// * `BGRA64`, `BGRA64LE`, `BGRA64BE`
+    ///
+    /// Codes without an endianness suffix are little endian.
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `RGB48PixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("RGB48LE");
+        let component_order = RGB48PixelBuffer::decode_component_order(f);
+        let line_width = stride.unwrap_or(width * component_order.len() * 2);
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            RGB48PixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                component_order,
+                big_endian: RGB48PixelBuffer::decode_big_endian(f)
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `2 * <number of components> * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGB48`, `RGB48LE` (default), `RGB48BE`
+    /// * `BGR48`, `BGR48LE`, `BGR48BE`
+    /// * `RGBA64`, `RGBA64LE`, `RGBA64BE`
+    /// * `BGRA64`, `BGRA64LE`, `BGRA64BE`
+    ///
+    /// Codes without an endianness suffix are little endian.
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `RGB48PixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = RGB48PixelBuffer::new(width, height, stride, fourcc);
+        let rep: [u16; 4] = color.into();
+        let pixel_width = buffer.component_order.len() * 2;
+
+        let mut representation: Vec<u8> = Vec::with_capacity(pixel_width);
+        for component in buffer.component_order.iter() {
+            representation.extend_from_slice(&buffer.encode(rep[*component as usize]));
+        }
+
+        let mut line = representation.repeat(width);
+        if buffer.stride > width * pixel_width {
+            line.extend([0].repeat(buffer.stride - width * pixel_width));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * self.component_order.len() * 2 + y * self.stride;
+        let repr: [u16; 4] = color.into();
+
+        for i in 0..self.component_order.len() {
+            let bytes = self.encode(repr[self.component_order[i] as usize]);
+            self.data[start + i * 2] = bytes[0];
+            self.data[start + i * 2 + 1] = bytes[1];
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * self.component_order.len() * 2 + y * self.stride;
+        let mut color: [u16; 4] = [0, 0, 0, 65535];
+        for i in 0..self.component_order.len() {
+            color[self.component_order[i] as usize] = self.decode([
+                self.data[start + i * 2],
+                self.data[start + i * 2 + 1]
+            ]);
+        }
+
+        Ok(DigitalRGBA64Color::from(color))
+    }
+}
+
+pub mod iter;
+mod tests;
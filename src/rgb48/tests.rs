@@ -0,0 +1,187 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::rgb48::RGB48PixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::color::DigitalRGBA64Color;
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalRGBA64Color{ r: 65535, g: 4096, b: 0, a: 65535 };
+        let buffer = RGB48PixelBuffer::new_with_background(2, 2, color, None, Some("RGB48LE"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod rgb48le {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = RGB48PixelBuffer::new(2, 2, None, Some("RGB48LE"));
+        assert_eq!(buffer.data.len(), 24);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2 * 6);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2 * 6);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 23];
+        match RGB48PixelBuffer::new_with_data(2, 2, data, None, Some("RGB48LE")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = RGB48PixelBuffer::new_with_background(
+            2, 2,
+            DigitalRGBA64Color{ r: 0x1234, g: 0x5678, b: 0x9abc, a: 65535 },
+            Some(16), Some("RGB48LE")
+        );
+
+        assert_eq!(buffer.data.len(), 32);
+        for y in 0..2 {
+            for x in 0..2 {
+                let start = x * 6 + y * 16;
+                assert_eq!(buffer.data[start..start + 6], [0x34, 0x12, 0x78, 0x56, 0xbc, 0x9a], "x: {}, y: {}", x, y);
+            }
+            for p in 12..16 {
+                assert_eq!(buffer.data[p + y * 16], 0);
+            }
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = RGB48PixelBuffer::new(2, 2, None, Some("RGB48LE"));
+
+        match buffer.set_pixel(1, 1, DigitalRGBA64Color{ r: 0x1234, g: 0x5678, b: 0x9abc, a: 65535 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(0, 2, DigitalRGBA64Color{ r: 0, g: 0, b: 0, a: 65535 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[18..24], [0x34, 0x12, 0x78, 0x56, 0xbc, 0x9a]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            1, 0, 2, 0, 3, 0,  4, 0, 5, 0, 6, 0,
+            7, 0, 8, 0, 9, 0,  0, 1, 0, 2, 0, 3
+        ];
+        let buffer = RGB48PixelBuffer::new_with_data(2, 2, data, None, Some("RGB48LE")).unwrap();
+
+        match buffer.get_pixel(1, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalRGBA64Color{ r: 4, g: 5, b: 6, a: 65535 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalRGBA64Color{ r: 256, g: 512, b: 768, a: 65535 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod rgb48be {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = RGB48PixelBuffer::new(1, 1, None, Some("RGB48BE"));
+        buffer.set_pixel(0, 0, DigitalRGBA64Color{ r: 0x1234, g: 0x5678, b: 0x9abc, a: 65535 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0, 1, 0, 2, 0, 3];
+        let buffer = RGB48PixelBuffer::new_with_data(1, 1, data, None, Some("RGB48BE")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalRGBA64Color{ r: 1, g: 2, b: 3, a: 65535 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod rgba64 {
+    use super::*;
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = RGB48PixelBuffer::new_with_background(
+            1, 2,
+            DigitalRGBA64Color{ r: 0x1234, g: 0x5678, b: 0x9abc, a: 0xdef0 },
+            None, Some("BGRA64BE")
+        );
+
+        assert_eq!(buffer.stride, 8);
+        for y in 0..2 {
+            assert_eq!(buffer.data[y * 8..y * 8 + 8], [0x9a, 0xbc, 0x56, 0x78, 0x12, 0x34, 0xde, 0xf0]);
+        }
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![1, 0, 2, 0, 3, 0, 4, 0];
+        let buffer = RGB48PixelBuffer::new_with_data(1, 1, data, None, Some("RGBA64LE")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalRGBA64Color{ r: 1, g: 2, b: 3, a: 4 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb_roundtrip() {
+        let mut rgb = RGBPixelBuffer::new(16, 16, None, Some("RGBA"));
+        for y in 0..16 {
+            for x in 0..16 {
+                rgb.set_pixel(x, y, DigitalRGBAColor{ r: (x * 16) as u8, g: (y * 16 + x) as u8, b: 255, a: (y * 16) as u8 }).unwrap();
+            }
+        }
+
+        let rgb48: RGB48PixelBuffer = rgb.into();
+        assert_eq!(rgb48.get_fourcc(), "RGBA64LE");
+        assert_eq!(rgb48.get_pixel(1, 1).unwrap(), DigitalRGBA64Color{ r: 4112, g: 4369, b: 65535, a: 4112 });
+
+        let back: RGBPixelBuffer = rgb48.into();
+        for y in 0..16 {
+            for x in 0..16 {
+                let color = DigitalRGBAColor{ r: (x * 16) as u8, g: (y * 16 + x) as u8, b: 255, a: (y * 16) as u8 };
+                assert_eq!(back.get_pixel(x, y).unwrap(), color, "x: {}, y: {}", x, y);
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Create iterator for RGBFloatPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::RGBFloatPixelBuffer;
+use crate::color::DigitalRGBAFloatColor;
+
+
+/// Pixel iterator for `RGBFloatPixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalRGBAFloatColor>`
+#[derive(Debug, PartialEq)]
+pub struct RGBFloatPixelIterator<'a> {
+    base: RGBFloatPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for RGBFloatPixelIterator<'a> {
+    type Item = Pixel<DigitalRGBAFloatColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for RGBFloatPixelBuffer<'a> {
+    type Item = Pixel<DigitalRGBAFloatColor>;
+    type IntoIter = RGBFloatPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RGBFloatPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! This module describes an interleaved floating point RGB pixel buffer in linear light with
+//! 32 bit (`RGBA32F`) or 16 bit (`RGBA16F`) float components
+//!
+//! Components are stored as little endian IEEE 754 floats. Conversions from and to the integer
+//! buffers assume sRGB encoded integer components, use `from_rgb` and `into_rgb` to select
+//! another transfer function.
+
+use crate::*;
+pub use crate::color::{ DigitalRGBAFloatColor, TransferFunction };
+pub use crate::rgb::RGBComponent;
+use crate::rgb::RGBPixelBuffer;
+use crate::color::{ f16_to_f32, f32_to_f16 };
+
+/// RGB Pixel buffer with floating point components in linear light, with or without alpha channel
+#[derive(Debug, PartialEq)]
+pub struct RGBFloatPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: Vec<RGBComponent>,
+    half: bool,
+    data: Vec<u8>
+}
+
+impl<'a> RGBFloatPixelBuffer<'a> {
+    fn decode_component_order(fourcc:&'a str) -> Vec<RGBComponent> {
+        match fourcc {
+            "RGB32F" | "RGB16F" =>
+                vec![RGBComponent::Red, RGBComponent::Green, RGBComponent::Blue],
+            "RGBA32F" | "RGBA16F" | _ =>
+                vec![RGBComponent::Red, RGBComponent::Green, RGBComponent::Blue, RGBComponent::Alpha],
+        }
+    }
+
+    /// Whether the buffer format stores an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        self.component_order.contains(&RGBComponent::Alpha)
+    }
+
+    fn decode_half(fourcc:&'a str) -> bool {
+        fourcc.ends_with("16F")
+    }
+
+    /// Size of one component in bytes
+    fn component_size(&self) -> usize {
+        if self.half { 2 } else { 4 }
+    }
+
+    fn encode(&self, value: f32) -> Vec<u8> {
+        if self.half {
+            f32_to_f16(value).to_le_bytes().to_vec()
+        } else {
+            value.to_le_bytes().to_vec()
+        }
+    }
+
+    fn decode(&self, bytes: &[u8]) -> f32 {
+        if self.half {
+            f16_to_f32(u16::from_le_bytes([bytes[0], bytes[1]]))
+        } else {
+            f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+        }
+    }
+
+    /// Convert an 8 bit RGB buffer with components encoded by `transfer` to linear light
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - the buffer to convert
+    /// * `transfer` - transfer function the components of `buffer` are encoded with
+    ///
+    /// # Returns
+    ///
+    /// A new `RGBA32F` buffer if `buffer` has an alpha channel, else a `RGB32F` buffer
+    pub fn from_rgb(buffer: RGBPixelBuffer<'_>, transfer: TransferFunction) -> Self {
+        let fourcc = if buffer.has_alpha() { "RGBA32F" } else { "RGB32F" };
+        let mut result = RGBFloatPixelBuffer::new(buffer.get_width(), buffer.get_height(), None, Some(fourcc));
+        for (x, y, color) in buffer {
+            result.set_pixel(x, y, DigitalRGBAFloatColor::from_digital(color, transfer)).unwrap();
+        }
+
+        result
+    }
+
+    /// Convert to an 8 bit RGB buffer with components encoded by `transfer`,
+    /// out of range values are clamped
+    ///
+    /// # Arguments
+    ///
+    /// * `transfer` - transfer function to encode the components with
+    /// * `fourcc` - optional, data representation format of the new buffer, defaults to
+    ///   `RGBA` if this buffer has an alpha channel, else `RGB`
+    ///
+    /// # Returns
+    ///
+    /// A new `RGBPixelBuffer` with the converted contents
+    pub fn into_rgb<'b>(self, transfer: TransferFunction, fourcc: Option<&'b str>) -> RGBPixelBuffer<'b> {
+        let default = if self.component_order.len() == 4 { "RGBA" } else { "RGB" };
+        let mut result = RGBPixelBuffer::new(self.width, self.height, None, Some(fourcc.unwrap_or(default)));
+        for (x, y, color) in self {
+            result.set_pixel(x, y, color.to_digital(transfer)).unwrap();
+        }
+
+        result
+    }
+}
+
+impl<'a> PixelBuffer<'a> for RGBFloatPixelBuffer<'a> {
+    type ColorType = DigitalRGBAFloatColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<component size> * <number of components> * width`
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGBA32F` (default), `RGB32F`, 32 bit float components
+    /// * `RGBA16F`, `RGB16F`, 16 bit float components
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `RGBFloatPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("RGBA32F");
+        let component_order = RGBFloatPixelBuffer::decode_component_order(f);
+        let half = RGBFloatPixelBuffer::decode_half(f);
+        let line_width = stride.unwrap_or(width * component_order.len() * if half { 2 } else { 4 });
+
+        RGBFloatPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            component_order,
+            half
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<component size> * <number of components> * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGBA32F` (default), `RGB32F`, 32 bit float components
+    /// * `RGBA16F`, `RGB16F`, 16 bit float components
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `RGBFloatPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("RGBA32F");
+        let component_order = RGBFloatPixelBuffer::decode_component_order(f);
+        let half = RGBFloatPixelBuffer::decode_half(f);
+        let line_width = stride.unwrap_or(width * component_order.len() * if half { 2 } else { 4 });
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            RGBFloatPixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                component_order,
+                half
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `<component size> * <number of components> * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `RGBA32F` (default), `RGB32F`, 32 bit float components
+    /// * `RGBA16F`, `RGB16F`, 16 bit float components
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `RGBFloatPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = RGBFloatPixelBuffer::new(width, height, stride, fourcc);
+        let rep: [f32; 4] = color.into();
+        let pixel_width = buffer.component_order.len() * buffer.component_size();
+
+        let mut representation: Vec<u8> = Vec::with_capacity(pixel_width);
+        for component in buffer.component_order.iter() {
+            representation.extend(buffer.encode(rep[*component as usize]));
+        }
+
+        let mut line = representation.repeat(width);
+        if buffer.stride > width * pixel_width {
+            line.extend([0].repeat(buffer.stride - width * pixel_width));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let size = self.component_size();
+        let start = x * self.component_order.len() * size + y * self.stride;
+        let repr: [f32; 4] = color.into();
+
+        for i in 0..self.component_order.len() {
+            let bytes = self.encode(repr[self.component_order[i] as usize]);
+            let offset = start + i * size;
+            self.data[offset..offset + size].copy_from_slice(&bytes);
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let size = self.component_size();
+        let start = x * self.component_order.len() * size + y * self.stride;
+        let mut color: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
+        for i in 0..self.component_order.len() {
+            let offset = start + i * size;
+            color[self.component_order[i] as usize] = self.decode(&self.data[offset..offset + size]);
+        }
+
+        Ok(DigitalRGBAFloatColor::from(color))
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,178 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::rgbfloat::RGBFloatPixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::color::{ DigitalRGBAFloatColor, TransferFunction };
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalRGBAFloatColor{ r: 0.25, g: 0.5, b: 2.0, a: 1.0 };
+        let buffer = RGBFloatPixelBuffer::new_with_background(2, 2, color, None, Some("RGBA16F"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod rgba32f {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = RGBFloatPixelBuffer::new(2, 2, None, None);
+        assert_eq!(buffer.data.len(), 64);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 32);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 32);
+        assert_eq!(buffer.get_fourcc(), "RGBA32F");
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 63];
+        match RGBFloatPixelBuffer::new_with_data(2, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = RGBFloatPixelBuffer::new_with_background(
+            1, 2,
+            DigitalRGBAFloatColor{ r: 1.0, g: 0.0, b: 0.5, a: 1.0 },
+            Some(20), None
+        );
+
+        let mut line: Vec<u8> = Vec::new();
+        for value in [1.0f32, 0.0, 0.5, 1.0].iter() {
+            line.extend_from_slice(&value.to_le_bytes());
+        }
+        line.extend([0; 4].iter());
+
+        assert_eq!(buffer.data, line.repeat(2));
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = RGBFloatPixelBuffer::new(2, 2, None, None);
+
+        match buffer.set_pixel(1, 1, DigitalRGBAFloatColor{ r: 0.5, g: 1.5, b: -0.25, a: 1.0 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalRGBAFloatColor{ r: 0.0, g: 0.0, b: 0.0, a: 0.0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[48..52], 0.5f32.to_le_bytes());
+        assert_eq!(buffer.data[52..56], 1.5f32.to_le_bytes());
+        assert_eq!(buffer.data[56..60], (-0.25f32).to_le_bytes());
+        assert_eq!(buffer.data[60..64], 1.0f32.to_le_bytes());
+    }
+
+    #[test]
+    fn get_pixel() {
+        let mut data: Vec<u8> = Vec::new();
+        for value in [0.25f32, 0.5, 0.75, 0.0].iter() {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        let buffer = RGBFloatPixelBuffer::new_with_data(1, 1, data, None, None).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalRGBAFloatColor{ r: 0.25, g: 0.5, b: 0.75, a: 0.0 })
+        }
+
+        match buffer.get_pixel(0, 1) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod rgb16f {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = RGBFloatPixelBuffer::new(2, 2, None, Some("RGB16F"));
+        assert_eq!(buffer.data.len(), 24);
+        assert_eq!(buffer.get_stride(), 12);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = RGBFloatPixelBuffer::new(1, 1, None, Some("RGB16F"));
+        buffer.set_pixel(0, 0, DigitalRGBAFloatColor{ r: 1.0, g: -2.0, b: 0.5, a: 0.5 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0x00, 0x3c, 0x00, 0xc0, 0x00, 0x38]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0x00, 0x3c, 0x00, 0xc0, 0x00, 0x38];
+        let buffer = RGBFloatPixelBuffer::new_with_data(1, 1, data, None, Some("RGB16F")).unwrap();
+
+        assert_eq!(buffer.get_pixel(0, 0).unwrap(), DigitalRGBAFloatColor{ r: 1.0, g: -2.0, b: 0.5, a: 1.0 });
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb_srgb() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            2, 1,
+            DigitalRGBAColor{ r: 255, g: 128, b: 0, a: 255 },
+            None, Some("RGB")
+        );
+
+        let float: RGBFloatPixelBuffer = rgb.into();
+        assert_eq!(float.get_fourcc(), "RGB32F");
+        let color = float.get_pixel(1, 0).unwrap();
+        assert!((color.g - 0.215_861).abs() < 1e-5);
+
+        let back: RGBPixelBuffer = float.into();
+        assert_eq!(back.get_fourcc(), "RGB");
+        for (_x, _y, color) in back {
+            assert_eq!(color, DigitalRGBAColor{ r: 255, g: 128, b: 0, a: 255 });
+        }
+    }
+
+    #[test]
+    fn rgb_transfer_function() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            1, 1,
+            DigitalRGBAColor{ r: 255, g: 128, b: 0, a: 64 },
+            None, Some("RGBA")
+        );
+
+        let float = RGBFloatPixelBuffer::from_rgb(rgb, TransferFunction::Linear);
+        assert_eq!(float.get_fourcc(), "RGBA32F");
+        let color = float.get_pixel(0, 0).unwrap();
+        assert!((color.g - 128.0 / 255.0).abs() < 1e-6);
+
+        let back = float.into_rgb(TransferFunction::SRGB, Some("BGRA"));
+        assert_eq!(back.get_fourcc(), "BGRA");
+        assert_eq!(back.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 188, b: 0, a: 64 });
+    }
+}
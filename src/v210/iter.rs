@@ -0,0 +1,54 @@
+//! Create iterator for V210PixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::V210PixelBuffer;
+use crate::color::DigitalYCbCr48Color;
+
+
+/// Pixel iterator for `V210PixelBuffer`
+///
+/// Items it will generate are of type `Pixel<DigitalYCbCr48Color>`
+#[derive(Debug, PartialEq)]
+pub struct V210PixelIterator<'a> {
+    base: V210PixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for V210PixelIterator<'a> {
+    type Item = Pixel<DigitalYCbCr48Color>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for V210PixelBuffer<'a> {
+    type Item = Pixel<DigitalYCbCr48Color>;
+    type IntoIter = V210PixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        V210PixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! This module describes a packed 10 bit YUV 4:2:2 pixel buffer (`v210`)
+//!
+//! Every block of 16 bytes holds 6 pixels as four little endian `u32` words with three
+//! 10 bit samples each (bits 0-9, 10-19 and 20-29). The samples are ordered
+//! `U0 Y0 V0 Y1 U2 Y2 V2 Y3 U4 Y4 V4 Y5`. Lines are padded to a multiple of 128 bytes.
+
+use crate::*;
+pub use crate::color::DigitalYCbCr48Color;
+use crate::color::quantize_msb;
+
+/// Sample index of the Y component for each pixel in a block
+const Y_SAMPLES: [usize; 6] = [1, 3, 5, 7, 9, 11];
+
+/// Sample index of the U component for each pixel pair in a block, V follows two samples later
+const U_SAMPLES: [usize; 3] = [0, 4, 8];
+
+/// Packed 10 bit YUV Pixel buffer without alpha channel, half horizontal resolution color subsampling
+#[derive(Debug, PartialEq)]
+pub struct V210PixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    data: Vec<u8>
+}
+
+impl<'a> V210PixelBuffer<'a> {
+    /// Stride in bytes, at least 16 bytes for every 6 pixels, aligned to 128 bytes
+    fn aligned_stride(width: usize, stride: Option<usize>) -> usize {
+        let line_width = stride.unwrap_or(0).max((width + 5) / 6 * 16);
+
+        (line_width + 127) / 128 * 128
+    }
+
+    /// Byte offset of the word containing `sample` and the bit shift within that word
+    fn sample_position(&self, x: usize, y: usize, sample: usize) -> (usize, u32) {
+        let block = y * self.stride + x / 6 * 16;
+
+        (block + sample / 3 * 4, (sample % 3) as u32 * 10)
+    }
+
+    fn read(&self, x: usize, y: usize, sample: usize) -> u16 {
+        let (offset, shift) = self.sample_position(x, y, sample);
+        let word = u32::from_le_bytes([
+            self.data[offset], self.data[offset + 1], self.data[offset + 2], self.data[offset + 3]
+        ]);
+
+        (((word >> shift) & 0x3ff) << 6) as u16
+    }
+
+    fn write(&mut self, x: usize, y: usize, sample: usize, value: u16) {
+        let (offset, shift) = self.sample_position(x, y, sample);
+        let mut word = u32::from_le_bytes([
+            self.data[offset], self.data[offset + 1], self.data[offset + 2], self.data[offset + 3]
+        ]);
+        let quantized = (quantize_msb(value, 10) >> 6) as u32;
+
+        word = (word & !(0x3ff << shift)) | (quantized << shift);
+        self.data[offset..offset + 4].copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+
+impl<'a> PixelBuffer<'a> for V210PixelBuffer<'a> {
+    type ColorType = DigitalYCbCr48Color;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: 16 bytes per 6 pixels, rounded up to a multiple of 128. A given stride
+    ///   is rounded up to the next multiple of 128 as well.
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `v210` (default)
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `V210PixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let line_width = V210PixelBuffer::aligned_stride(width, stride);
+
+        V210PixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data: vec![0; line_width * height],
+            fourcc: fourcc.unwrap_or("v210")
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: 16 bytes per 6 pixels, rounded up to a multiple of 128. A given stride
+    ///   is rounded up to the next multiple of 128 as well.
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `v210` (default)
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `V210PixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let line_width = V210PixelBuffer::aligned_stride(width, stride);
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            V210PixelBuffer {
+                width,
+                height,
+                stride: line_width,
+                data,
+                fourcc: fourcc.unwrap_or("v210")
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: 16 bytes per 6 pixels, rounded up to a multiple of 128. A given stride
+    ///   is rounded up to the next multiple of 128 as well.
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `v210` (default)
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `V210PixelBuffer` with it's contents set to the
+    /// defined color. The padding at the end of each line is filled with zeroes, the
+    /// unused samples of a partially filled last block are set to the color as well.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let [y, cb, cr] = [color.y, color.cb, color.cr].map(|value| (quantize_msb(value, 10) >> 6) as u32);
+
+        let mut block: Vec<u8> = Vec::with_capacity(16);
+        for samples in [[cb, y, cr], [y, cb, y], [cr, y, cb], [y, cr, y]] {
+            let word = samples[0] | (samples[1] << 10) | (samples[2] << 20);
+            block.extend_from_slice(&word.to_le_bytes());
+        }
+
+        let line_width = V210PixelBuffer::aligned_stride(width, stride);
+        let blocks = (width + 5) / 6;
+        let mut line = block.repeat(blocks);
+        line.extend([0].repeat(line_width - blocks * 16));
+
+        V210PixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data: line.repeat(height),
+            fourcc: fourcc.unwrap_or("v210")
+        }
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let u_sample = U_SAMPLES[(x % 6) / 2];
+        // the first pixel of a chroma block sets the chroma, the others average into it
+        let (u, v) = if x % 2 == 0 {
+            (color.cb, color.cr)
+        } else {
+            (
+                ((self.read(x, y, u_sample) as u32 + color.cb as u32) >> 1) as u16,
+                ((self.read(x, y, u_sample + 2) as u32 + color.cr as u32) >> 1) as u16
+            )
+        };
+
+        self.write(x, y, Y_SAMPLES[x % 6], color.y);
+        self.write(x, y, u_sample, u);
+        self.write(x, y, u_sample + 2, v);
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let u_sample = U_SAMPLES[(x % 6) / 2];
+
+        Ok(DigitalYCbCr48Color {
+            y: self.read(x, y, Y_SAMPLES[x % 6]),
+            cb: self.read(x, y, u_sample),
+            cr: self.read(x, y, u_sample + 2)
+        })
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,201 @@
+//
+// Tests
+//
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::v210::V210PixelBuffer;
+pub use crate::yuv422i::YUV422iPixelBuffer;
+pub use crate::yuv444p::YUV444pPixelBuffer;
+pub use crate::yuv420p::YUV420pPixelBuffer;
+pub use crate::color::DigitalYCbCr48Color;
+pub use grapho_color::DigitalYCbCrColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCbCr48Color{ y: 0xffc0, cb: 0x4000, cr: 0 };
+        let buffer = V210PixelBuffer::new_with_background(7, 2, color, None, None);
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+
+    #[test]
+    fn iter_coordinates() {
+        let buffer = V210PixelBuffer::new(3, 2, None, None);
+        let coordinates: Vec<(usize, usize)> = buffer.into_iter().map(|p| (p.0, p.1)).collect();
+
+        assert_eq!(coordinates, vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+    }
+}
+
+#[cfg(test)]
+mod v210 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = V210PixelBuffer::new(6, 2, None, None);
+        assert_eq!(buffer.data.len(), 256);
+        assert_eq!(buffer.width, 6);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 128);
+        assert_eq!(buffer.get_width(), 6);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 128);
+        assert_eq!(buffer.get_fourcc(), "v210");
+    }
+
+    #[test]
+    fn stride_alignment() {
+        assert_eq!(V210PixelBuffer::new(48, 1, None, None).stride, 128);
+        assert_eq!(V210PixelBuffer::new(49, 1, None, None).stride, 256);
+        assert_eq!(V210PixelBuffer::new(1920, 1, None, None).stride, 5120);
+        assert_eq!(V210PixelBuffer::new(1280, 1, None, None).stride, 3456);
+        assert_eq!(V210PixelBuffer::new(6, 1, Some(200), None).stride, 256);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 255];
+        match V210PixelBuffer::new_with_data(6, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = V210PixelBuffer::new_with_background(
+            6, 1,
+            DigitalYCbCr48Color{ y: 0x0040, cb: 0x0080, cr: 0x00c0 },
+            None, None
+        );
+
+        assert_eq!(buffer.data.len(), 128);
+        // U Y V, Y U Y, V Y U, Y V Y with Y = 1, U = 2, V = 3
+        let words: [u32; 4] = [
+            2 | 1 << 10 | 3 << 20,
+            1 | 2 << 10 | 1 << 20,
+            3 | 1 << 10 | 2 << 20,
+            1 | 3 << 10 | 1 << 20,
+        ];
+        for i in 0..4 {
+            assert_eq!(buffer.data[i * 4..i * 4 + 4], words[i].to_le_bytes(), "word {}", i);
+        }
+        for p in 16..128 {
+            assert_eq!(buffer.data[p], 0);
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = V210PixelBuffer::new(6, 2, None, None);
+
+        match buffer.set_pixel(5, 1, DigitalYCbCr48Color{ y: 0xffff, cb: 0x8000, cr: 0x4000 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(6, 0, DigitalYCbCr48Color{ y: 0, cb: 0, cr: 0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[128 + 8..128 + 12], (256u32 << 20).to_le_bytes());
+        assert_eq!(buffer.data[128 + 12..128 + 16], (128u32 << 10 | 1023 << 20).to_le_bytes());
+    }
+
+    #[test]
+    fn get_pixel() {
+        let words: [u32; 4] = [
+            10 | 11 << 10 | 12 << 20,
+            13 | 14 << 10 | 15 << 20,
+            16 | 17 << 10 | 18 << 20,
+            19 | 20 << 10 | 21 << 20,
+        ];
+        let mut data: Vec<u8> = Vec::new();
+        for word in words.iter() {
+            data.extend_from_slice(&word.to_le_bytes());
+        }
+        data.extend([0].repeat(112));
+        let buffer = V210PixelBuffer::new_with_data(6, 1, data, None, None).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCr48Color{ y: 11 << 6, cb: 10 << 6, cr: 12 << 6 })
+        }
+
+        match buffer.get_pixel(3, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCr48Color{ y: 17 << 6, cb: 14 << 6, cr: 16 << 6 })
+        }
+
+        match buffer.get_pixel(4, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCr48Color{ y: 19 << 6, cb: 18 << 6, cr: 20 << 6 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn uyvy() {
+        let v210 = V210PixelBuffer::new_with_background(
+            8, 2,
+            DigitalYCbCr48Color{ y: 0xff00, cb: 0x8000, cr: 0x4000 },
+            None, None
+        );
+
+        let uyvy: YUV422iPixelBuffer = v210.into();
+        assert_eq!(uyvy.get_fourcc(), "UYVY");
+        for (x, y, color) in uyvy {
+            assert_eq!(color, DigitalYCbCrColor{ y: 255, cb: 128, cr: 64 }, "x: {}, y: {}", x, y);
+        }
+    }
+
+    #[test]
+    fn uyvy_pairs() {
+        let mut v210 = V210PixelBuffer::new(2, 1, None, None);
+        v210.set_pixel(0, 0, DigitalYCbCr48Color{ y: 0x1000, cb: 0x8000, cr: 0x4000 }).unwrap();
+        v210.set_pixel(1, 0, DigitalYCbCr48Color{ y: 0x2000, cb: 0x6000, cr: 0x4000 }).unwrap();
+
+        let uyvy: YUV422iPixelBuffer = v210.into();
+        assert_eq!(uyvy.get_pixel(0, 0), Ok(DigitalYCbCrColor{ y: 0x10, cb: 0x70, cr: 0x40 }));
+        assert_eq!(uyvy.get_pixel(1, 0), Ok(DigitalYCbCrColor{ y: 0x20, cb: 0x70, cr: 0x40 }));
+    }
+
+    #[test]
+    fn yuv420p() {
+        let v210 = V210PixelBuffer::new_with_background(
+            8, 2,
+            DigitalYCbCr48Color{ y: 0xff00, cb: 0x8000, cr: 0x4000 },
+            None, None
+        );
+
+        let yuv: YUV420pPixelBuffer = v210.into();
+        for (x, y, color) in yuv {
+            assert_eq!(color, DigitalYCbCrColor{ y: 255, cb: 128, cr: 64 }, "x: {}, y: {}", x, y);
+        }
+    }
+
+    #[test]
+    fn yuv444p() {
+        let v210 = V210PixelBuffer::new_with_background(
+            8, 2,
+            DigitalYCbCr48Color{ y: 0xff00, cb: 0x8000, cr: 0x4000 },
+            None, None
+        );
+
+        let yuv: YUV444pPixelBuffer = v210.into();
+        for (x, y, color) in yuv {
+            assert_eq!(color, DigitalYCbCrColor{ y: 255, cb: 128, cr: 64 }, "x: {}, y: {}", x, y);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Create iterator for XYZPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::XYZPixelBuffer;
+use crate::color::DigitalXYZColor;
+
+
+/// Pixel iterator for `XYZPixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalXYZColor>`
+#[derive(Debug, PartialEq)]
+pub struct XYZPixelIterator<'a> {
+    base: XYZPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for XYZPixelIterator<'a> {
+    type Item = Pixel<DigitalXYZColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for XYZPixelBuffer<'a> {
+    type Item = Pixel<DigitalXYZColor>;
+    type IntoIter = XYZPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        XYZPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
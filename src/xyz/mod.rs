@@ -0,0 +1,243 @@
+//! This module describes an interleaved CIE XYZ pixel buffer with 32 bit float components
+//!
+//! Components are stored as little endian IEEE 754 floats, the reference white of the
+//! buffer is part of the format. Conversions from and to the other buffers assume sRGB
+//! primaries and a D65 white, use `from_rgb` and `into_rgb` to select other primaries.
+
+use crate::*;
+pub use crate::color::{ DigitalXYZColor, WhitePoint, RGBPrimaries, TransferFunction };
+use crate::color::DigitalRGBAFloatColor;
+use crate::rgb::RGBPixelBuffer;
+
+/// XYZ Pixel buffer without alpha channel
+#[derive(Debug, PartialEq)]
+pub struct XYZPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    white_point: WhitePoint,
+    data: Vec<u8>
+}
+
+impl<'a> XYZPixelBuffer<'a> {
+    pub(crate) fn decode_white_point(fourcc:&'a str) -> WhitePoint {
+        match fourcc {
+            "XYZD50" | "LABD50" => WhitePoint::D50,
+            "XYZD65" | "LABD65" | _ => WhitePoint::D65,
+        }
+    }
+
+    pub(crate) fn encode(color: [f32; 3]) -> Vec<u8> {
+        color.iter().flat_map(|value| value.to_le_bytes()).collect()
+    }
+
+    pub(crate) fn decode(bytes: &[u8]) -> [f32; 3] {
+        let mut color = [0.0; 3];
+        for (i, value) in color.iter_mut().enumerate() {
+            *value = f32::from_le_bytes([bytes[i * 4], bytes[i * 4 + 1], bytes[i * 4 + 2], bytes[i * 4 + 3]]);
+        }
+
+        color
+    }
+
+    /// Reference white of the colors in this buffer
+    pub fn get_white_point(&self) -> WhitePoint {
+        self.white_point
+    }
+
+    /// Convert a 8 bit RGB buffer, alpha is ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - the buffer to convert
+    /// * `transfer` - transfer function the components of `buffer` are encoded with
+    /// * `primaries` - primaries of the RGB color space of `buffer`
+    /// * `fourcc` - optional, data representation format of the new buffer
+    ///
+    /// # Returns
+    ///
+    /// A new `XYZPixelBuffer` with the same dimensions, the RGB white is adapted to the
+    /// white point of the new buffer
+    pub fn from_rgb(buffer: RGBPixelBuffer<'_>, transfer: TransferFunction, primaries: RGBPrimaries, fourcc: Option<&'a str>) -> Self {
+        let mut result = XYZPixelBuffer::new(buffer.get_width(), buffer.get_height(), None, fourcc);
+        let white = result.white_point;
+        for (x, y, color) in buffer {
+            let linear = DigitalRGBAFloatColor::from_digital(color, transfer);
+            result.set_pixel(x, y, DigitalXYZColor::from_linear_rgb(linear, primaries, white)).unwrap();
+        }
+
+        result
+    }
+
+    /// Convert to a 8 bit RGB buffer, out of gamut colors are clipped
+    ///
+    /// # Arguments
+    ///
+    /// * `transfer` - transfer function to encode the components with
+    /// * `primaries` - primaries of the RGB color space of the new buffer
+    /// * `fourcc` - optional, data representation format of the new buffer
+    ///
+    /// # Returns
+    ///
+    /// A new `RGBPixelBuffer` with the converted contents
+    pub fn into_rgb<'b>(self, transfer: TransferFunction, primaries: RGBPrimaries, fourcc: Option<&'b str>) -> RGBPixelBuffer<'b> {
+        let white = self.white_point;
+        let mut result = RGBPixelBuffer::new(self.width, self.height, None, fourcc);
+        for (x, y, color) in self {
+            result.set_pixel(x, y, color.to_linear_rgb(primaries, white).to_digital(transfer)).unwrap();
+        }
+
+        result
+    }
+}
+
+impl<'a> PixelBuffer<'a> for XYZPixelBuffer<'a> {
+    type ColorType = DigitalXYZColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `12 * width`
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `XYZD65` (default), D65 reference white
+    /// * `XYZD50`, D50 reference white
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `XYZPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("XYZD65");
+        let line_width = stride.unwrap_or(width * 12);
+
+        XYZPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            white_point: XYZPixelBuffer::decode_white_point(f)
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `12 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `XYZD65` (default), D65 reference white
+    /// * `XYZD50`, D50 reference white
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `XYZPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("XYZD65");
+        let line_width = stride.unwrap_or(width * 12);
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            XYZPixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f,
+                white_point: XYZPixelBuffer::decode_white_point(f)
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `12 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `XYZD65` (default), D65 reference white
+    /// * `XYZD50`, D50 reference white
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `XYZPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = XYZPixelBuffer::new(width, height, stride, fourcc);
+
+        let mut line = XYZPixelBuffer::encode(color.into()).repeat(width);
+        if buffer.stride > width * 12 {
+            line.extend([0].repeat(buffer.stride - width * 12));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 12 + y * self.stride;
+        self.data[start..start + 12].copy_from_slice(&XYZPixelBuffer::encode(color.into()));
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 12 + y * self.stride;
+
+        Ok(DigitalXYZColor::from(XYZPixelBuffer::decode(&self.data[start..start + 12])))
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,168 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::xyz::{ XYZPixelBuffer, WhitePoint, RGBPrimaries, TransferFunction };
+pub use crate::lab::LabPixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::color::{ DigitalXYZColor, DigitalLabColor };
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalXYZColor{ x: 0.25, y: 0.5, z: 1.5 };
+        let buffer = XYZPixelBuffer::new_with_background(2, 2, color, None, None);
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod xyzd65 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = XYZPixelBuffer::new(2, 2, None, None);
+        assert_eq!(buffer.data.len(), 48);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 24);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 24);
+        assert_eq!(buffer.get_fourcc(), "XYZD65");
+        assert_eq!(buffer.get_white_point(), WhitePoint::D65);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 47];
+        match XYZPixelBuffer::new_with_data(2, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = XYZPixelBuffer::new_with_background(
+            1, 2,
+            DigitalXYZColor{ x: 0.5, y: 1.0, z: 2.0 },
+            Some(16), None
+        );
+
+        let mut line = vec![0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0x40];
+        line.extend([0, 0, 0, 0]);
+        assert_eq!(buffer.data, line.repeat(2));
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = XYZPixelBuffer::new(2, 2, None, None);
+
+        match buffer.set_pixel(1, 1, DigitalXYZColor{ x: 0.5, y: 1.0, z: 2.0 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalXYZColor{ x: 0.5, y: 1.0, z: 2.0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[36..48], [0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0x40]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0x00, 0x00, 0x80, 0x3e, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0xc0, 0x3f];
+        let buffer = XYZPixelBuffer::new_with_data(1, 1, data, None, None).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalXYZColor{ x: 0.25, y: 0.5, z: 1.5 })
+        }
+
+        match buffer.get_pixel(0, 1) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod xyzd50 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = XYZPixelBuffer::new(1, 1, None, Some("XYZD50"));
+        assert_eq!(buffer.get_stride(), 12);
+        assert_eq!(buffer.get_white_point(), WhitePoint::D50);
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgb_srgb() {
+        let rgb = RGBPixelBuffer::new_with_background(
+            2, 2,
+            DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 },
+            None, Some("RGB")
+        );
+
+        let xyz = XYZPixelBuffer::from_rgb(rgb, TransferFunction::SRGB, RGBPrimaries::SRGB, Some("XYZD50"));
+        let white = WhitePoint::D50.xyz();
+        for (_x, _y, color) in xyz {
+            assert!((color.x - white.x).abs() < 1e-5);
+            assert!((color.y - white.y).abs() < 1e-5);
+            assert!((color.z - white.z).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn rgb_roundtrip() {
+        let mut rgb = RGBPixelBuffer::new(2, 1, None, Some("RGB"));
+        rgb.set_pixel(0, 0, DigitalRGBAColor{ r: 12, g: 34, b: 56, a: 255 }).unwrap();
+        rgb.set_pixel(1, 0, DigitalRGBAColor{ r: 255, g: 128, b: 0, a: 255 }).unwrap();
+
+        let xyz = XYZPixelBuffer::from_rgb(rgb, TransferFunction::SRGB, RGBPrimaries::DISPLAY_P3, Some("XYZD50"));
+        let back = xyz.into_rgb(TransferFunction::SRGB, RGBPrimaries::DISPLAY_P3, Some("RGB"));
+        assert_eq!(back.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 12, g: 34, b: 56, a: 255 });
+        assert_eq!(back.get_pixel(1, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 128, b: 0, a: 255 });
+    }
+
+    #[test]
+    fn adapted() {
+        // a D50 buffer is adapted to the D65 white the other buffers assume
+        let xyz = XYZPixelBuffer::new_with_background(1, 1, WhitePoint::D50.xyz(), None, Some("XYZD50"));
+        let rgb: RGBPixelBuffer = xyz.into();
+        assert_eq!(rgb.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 255 });
+    }
+
+    #[test]
+    fn lab() {
+        let xyz = XYZPixelBuffer::new_with_background(1, 1, WhitePoint::D50.xyz(), None, Some("XYZD50"));
+        let lab: LabPixelBuffer = xyz.into();
+        assert_eq!(lab.get_fourcc(), "LABD50");
+
+        let color = lab.get_pixel(0, 0).unwrap();
+        assert!((color.l - 100.0).abs() < 1e-3);
+        assert!(color.a.abs() < 1e-3);
+        assert!(color.b.abs() < 1e-3);
+
+        let xyz: XYZPixelBuffer = lab.into();
+        assert_eq!(xyz.get_fourcc(), "XYZD50");
+    }
+}
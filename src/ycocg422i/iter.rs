@@ -0,0 +1,54 @@
+//! Create iterator for YCoCg422iPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::YCoCg422iPixelBuffer;
+use crate::color::DigitalYCoCgColor;
+
+
+/// Pixel iterator for `YCoCg422iPixelBuffer`
+///
+/// Items it will generate are of type `Pixel<DigitalYCoCgColor>`
+#[derive(Debug, PartialEq)]
+pub struct YCoCg422iPixelIterator<'a> {
+    base: YCoCg422iPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for YCoCg422iPixelIterator<'a> {
+    type Item = Pixel<DigitalYCoCgColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for YCoCg422iPixelBuffer<'a> {
+    type Item = Pixel<DigitalYCoCgColor>;
+    type IntoIter = YCoCg422iPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        YCoCg422iPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! This module describes an interleaved YCoCg pixel buffer with color subsampling (half horizontal resolution)
+
+use crate::*;
+pub use crate::color::DigitalYCoCgColor;
+pub use crate::ycocg444i::YCoCgComponent;
+
+/// YCoCg Pixel buffer without alpha channel, half resolution color subsampling
+#[derive(Debug, PartialEq)]
+pub struct YCoCg422iPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: [Vec<YCoCgComponent>; 2],
+    data: Vec<u8>
+}
+
+impl<'a> YCoCg422iPixelBuffer<'a> {
+    fn decode_component_order(fourcc:&'a str) -> [Vec<YCoCgComponent>; 2] {
+        match fourcc {
+            "CoYCgY" => [
+                vec![YCoCgComponent::Co, YCoCgComponent::Y, YCoCgComponent::Cg, YCoCgComponent::Ignore],
+                vec![YCoCgComponent::Co, YCoCgComponent::Ignore, YCoCgComponent::Cg, YCoCgComponent::Y]
+            ],
+            "YCoYCg" | "YCoCg422" | _ => [
+                vec![YCoCgComponent::Y, YCoCgComponent::Co, YCoCgComponent::Ignore, YCoCgComponent::Cg],
+                vec![YCoCgComponent::Ignore, YCoCgComponent::Co, YCoCgComponent::Y, YCoCgComponent::Cg]
+            ],
+        }
+    }
+
+    /// Default stride, a macropixel of 4 bytes contains 2 pixels
+    fn default_stride(width: usize) -> usize {
+        (width + 1) / 2 * 4
+    }
+}
+
+
+impl<'a> PixelBuffer<'a> for YCoCg422iPixelBuffer<'a> {
+    type ColorType = DigitalYCoCgColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `<length of color type representation> * width`
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `CoYCgY`
+    /// * `YCoYCg`, `YCoCg422` (default)
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YCoCg422iPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YCoYCg");
+        let component_order = YCoCg422iPixelBuffer::decode_component_order(f);
+        let line_width = stride.unwrap_or(YCoCg422iPixelBuffer::default_stride(width));
+
+        YCoCg422iPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data: vec![0; line_width * height],
+            fourcc: f,
+            component_order
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `<length of color type representation> * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `CoYCgY`
+    /// * `YCoYCg`, `YCoCg422` (default)
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `YCoCg422iPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let line_width = stride.unwrap_or(YCoCg422iPixelBuffer::default_stride(width));
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        let f = fourcc.unwrap_or("YCoYCg");
+        let component_order = YCoCg422iPixelBuffer::decode_component_order(f);
+
+        Ok(
+            YCoCg422iPixelBuffer {
+                width,
+                height,
+                stride: line_width,
+                data,
+                fourcc: f,
+                component_order
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `<length of color type representation> * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `CoYCgY`
+    /// * `YCoYCg`, `YCoCg422` (default)
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YCoCg422iPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YCoYCg");
+        let component_order = YCoCg422iPixelBuffer::decode_component_order(f);
+        let min_width = YCoCg422iPixelBuffer::default_stride(width);
+        let line_width = stride.unwrap_or(min_width);
+        let data:Vec<u8>;
+
+        let mut representation = vec![0; 4];
+        for i in 0..4 {
+            representation[i] =
+                match component_order[0][i] {
+                    YCoCgComponent::Y | YCoCgComponent::Ignore => color.y,
+                    YCoCgComponent::Co => color.co,
+                    YCoCgComponent::Cg => color.cg
+                };
+        }
+
+        if line_width > min_width {
+            let mut line = representation.repeat(min_width / 4);
+            line.extend([0].repeat(line_width - min_width));
+            data = line.repeat(height);
+        } else {
+            data = representation.repeat(min_width / 4 * height);
+        }
+
+        YCoCg422iPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data,
+            fourcc: f,
+            component_order
+        }
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = (x / 2) * 4 + y * self.stride;
+        let repr: [u8; 3] = color.into();
+
+        let order = &self.component_order[x % 2];
+        // the first pixel of a chroma block sets the chroma, the others average into it
+        let first = x % 2 == 0;
+        for i in 0..4 {
+            let c = match order[i] {
+                YCoCgComponent::Y => {
+                    self.data[start + i] = repr[0];
+                    continue;
+                },
+                YCoCgComponent::Co => repr[1],
+                YCoCgComponent::Cg => repr[2],
+                YCoCgComponent::Ignore => continue
+            };
+            self.data[start + i] = if first { c } else { ((self.data[start + i] as u16 + c as u16) >> 1) as u8 };
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = (x / 2) * 4 + y * self.stride;
+        let mut color: [u8; 3] = [0, 0, 0];
+
+        let order = &self.component_order[x % 2];
+        for i in 0..4 {
+            match order[i] {
+                YCoCgComponent::Ignore => (),
+                component => color[component as usize] = self.data[start + i]
+            }
+        }
+
+        Ok(DigitalYCoCgColor::from(color))
+    }
+}
+
+pub mod iter;
+mod tests;
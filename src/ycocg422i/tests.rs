@@ -0,0 +1,239 @@
+//
+// Tests
+//
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::ycocg422i::YCoCg422iPixelBuffer;
+pub use crate::color::DigitalYCoCgColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCoCgColor{ y: 255, co: 64, cg: 0 };
+        let buffer = YCoCg422iPixelBuffer::new_with_background(2, 2, color, None, Some("YCoYCg"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod coycgy {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YCoCg422iPixelBuffer::new(2, 2, None, Some("CoYCgY"));
+        assert_eq!(buffer.data.len(), 8);
+        assert_eq!(buffer.data[0], 0);
+        assert_eq!(buffer.data[1], 0);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2 * 2);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2 * 2);
+    }
+
+    #[test]
+    fn prefilled_buffer() {
+        let data = vec![255, 64, 128, 64, 255, 64, 128, 64];
+        let copy = data.clone();
+        let buffer = YCoCg422iPixelBuffer::new_with_data(2, 2, data, None, Some("CoYCgY")).unwrap();
+        assert_eq!(buffer.data.len(), 8);
+        for x in 0..buffer.data.len() {
+            assert_eq!(copy[x], buffer.data[x]);
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YCoCg422iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 64, co: 255, cg: 128 },
+            None, Some("CoYCgY")
+        );
+        assert_eq!(buffer.data.len(), 8);
+        for x in (0..8).step_by(4) {
+            assert_eq!(buffer.data[x + 0], 255);
+            assert_eq!(buffer.data[x + 1], 64);
+            assert_eq!(buffer.data[x + 2], 128);
+            assert_eq!(buffer.data[x + 3], 64);
+        }
+    }
+    
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YCoCg422iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 64, co: 255, cg: 128 },
+            Some(12), Some("CoYCgY")
+        );
+
+        assert_eq!(buffer.data.len(), 24);
+        for y in 0..2 {
+            assert_eq!(buffer.data[y * 12 + 0], 255, "x: {}, y: {}", 1, y);
+            assert_eq!(buffer.data[y * 12 + 1], 64, "x: {}, y: {}", 1, y);
+            assert_eq!(buffer.data[y * 12 + 2], 128, "x: {}, y: {}", 2, y);
+            assert_eq!(buffer.data[y * 12 + 3], 64, "x: {}, y: {}", 2, y);
+            for p in 4..12 {
+                assert_eq!(buffer.data[p + y * 12], 0);
+            }
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YCoCg422iPixelBuffer::new(2, 2, None, Some("CoYCgY"));
+        assert_eq!(buffer.data[6], 0);
+        assert_eq!(buffer.data[7], 0);
+        
+        match buffer.set_pixel(1, 1, DigitalYCoCgColor{ y: 64, co: 255, cg: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCoCgColor{ y: 64, co: 255, cg: 128 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[4], 127);
+        assert_eq!(buffer.data[5], 0);
+        assert_eq!(buffer.data[6], 64);
+        assert_eq!(buffer.data[7], 64);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0, 1, 2, 3, 0, 0, 0, 0, 4, 5, 6, 7, 0, 0, 0, 0];
+        let buffer = YCoCg422iPixelBuffer::new_with_data(2, 2, data, Some(8), Some("CoYCgY")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 1, co: 0, cg: 2 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 7, co: 4, cg: 6 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod ycoycg {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YCoCg422iPixelBuffer::new(2, 2, None, Some("YCoYCg"));
+        assert_eq!(buffer.data.len(), 8);
+        assert_eq!(buffer.data[0], 0);
+        assert_eq!(buffer.data[1], 0);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2 * 2);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2 * 2);
+    }
+
+    #[test]
+    fn prefilled_buffer() {
+        let data = vec![255, 64, 128, 64, 255, 64, 128, 64];
+        let copy = data.clone();
+        let buffer = YCoCg422iPixelBuffer::new_with_data(2, 2, data, None, Some("YCoYCg")).unwrap();
+        assert_eq!(buffer.data.len(), 8);
+        for x in 0..buffer.data.len() {
+            assert_eq!(copy[x], buffer.data[x]);
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YCoCg422iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 64, co: 255, cg: 128 },
+            None, Some("YCoYCg")
+        );
+        assert_eq!(buffer.data.len(), 8);
+        for x in (0..8).step_by(4) {
+            assert_eq!(buffer.data[x + 0], 64);
+            assert_eq!(buffer.data[x + 1], 255);
+            assert_eq!(buffer.data[x + 2], 64);
+            assert_eq!(buffer.data[x + 3], 128);
+        }
+    }
+    
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YCoCg422iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 64, co: 255, cg: 128 },
+            Some(12), Some("YCoYCg")
+        );
+
+        assert_eq!(buffer.data.len(), 24);
+        for y in 0..2 {
+            assert_eq!(buffer.data[y * 12 + 0], 64, "x: {}, y: {}", 1, y);
+            assert_eq!(buffer.data[y * 12 + 1], 255, "x: {}, y: {}", 1, y);
+            assert_eq!(buffer.data[y * 12 + 2], 64, "x: {}, y: {}", 2, y);
+            assert_eq!(buffer.data[y * 12 + 3], 128, "x: {}, y: {}", 2, y);
+            for p in 4..12 {
+                assert_eq!(buffer.data[p + y * 12], 0);
+            }
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YCoCg422iPixelBuffer::new(2, 2, None, Some("YCoYCg"));
+        assert_eq!(buffer.data[6], 0);
+        assert_eq!(buffer.data[7], 0);
+        
+        match buffer.set_pixel(1, 1, DigitalYCoCgColor{ y: 64, co: 255, cg: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCoCgColor{ y: 64, co: 255, cg: 128 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[4], 0);
+        assert_eq!(buffer.data[5], 127);
+        assert_eq!(buffer.data[6], 64);
+        assert_eq!(buffer.data[7], 64);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0, 1, 2, 3, 0, 0, 0, 0, 4, 5, 6, 7, 0, 0, 0, 0];
+        let buffer = YCoCg422iPixelBuffer::new_with_data(2, 2, data, Some(8), Some("YCoYCg")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 0, co: 1, cg: 3 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 6, co: 5, cg: 7 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
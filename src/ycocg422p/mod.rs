@@ -0,0 +1,236 @@
+//! This module describes a planar YCoCg pixel buffer with color subsampling (half horizontal resolution)
+
+use crate::*;
+pub use crate::color::DigitalYCoCgColor;
+pub use crate::ycocg444i::YCoCgComponent;
+
+/// YCoCg Pixel buffer without alpha channel, half horizontal resolution color subsampling
+#[derive(Debug, PartialEq)]
+pub struct YCoCg422pPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: Vec<YCoCgComponent>,
+    data: Vec<u8>
+}
+
+impl<'a> YCoCg422pPixelBuffer<'a> {
+    fn decode_component_order(fourcc:&'a str) -> Vec<YCoCgComponent> {
+        match fourcc {
+            "YCgCo" =>
+                vec![YCoCgComponent::Cg, YCoCgComponent::Co],
+            "YCoCg" | _ =>
+                vec![YCoCgComponent::Co, YCoCgComponent::Cg],
+        }
+    }
+
+    /// Line width of the chroma planes
+    fn chroma_stride(stride: usize) -> usize {
+        (stride + 1) / 2
+    }
+
+    /// Number of bytes needed for a buffer with given stride and height
+    fn buffer_size(stride: usize, height: usize) -> usize {
+        (stride + YCoCg422pPixelBuffer::chroma_stride(stride) * 2) * height
+    }
+}
+
+
+impl<'a> PixelBuffer<'a> for YCoCg422pPixelBuffer<'a> {
+    type ColorType = DigitalYCoCgColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width`, the chroma planes use half of it
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCg` (default)
+    /// * `YCgCo`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YCoCg422pPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YCoCg");
+        let component_order = YCoCg422pPixelBuffer::decode_component_order(f);
+        let line_width = stride.unwrap_or(width);
+
+        YCoCg422pPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data: vec![0; YCoCg422pPixelBuffer::buffer_size(line_width, height)],
+            fourcc: f,
+            component_order
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width`, the chroma planes use half of it
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCg` (default)
+    /// * `YCgCo`
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `YCoCg422pPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let line_width = stride.unwrap_or(width);
+
+        if data.len() < YCoCg422pPixelBuffer::buffer_size(line_width, height) {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        let f = fourcc.unwrap_or("YCoCg");
+        let component_order = YCoCg422pPixelBuffer::decode_component_order(f);
+
+        Ok(
+            YCoCg422pPixelBuffer {
+                width,
+                height,
+                stride: line_width,
+                data,
+                fourcc: f,
+                component_order
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width`, the chroma planes use half of it
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCg` (default)
+    /// * `YCgCo`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YCoCg422pPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YCoCg");
+        let component_order = YCoCg422pPixelBuffer::decode_component_order(f);
+        let line_width = stride.unwrap_or(width);
+        let chroma_line_width = YCoCg422pPixelBuffer::chroma_stride(line_width);
+        let chroma_width = (width + 1) / 2;
+        let rep: [u8; 3] = color.into();
+
+        let mut data: Vec<u8> = Vec::with_capacity(YCoCg422pPixelBuffer::buffer_size(line_width, height));
+
+        let mut y = [color.y].repeat(width);
+        if line_width > width {
+            y.extend([0].repeat(line_width - width));
+        }
+        data.extend(y.repeat(height));
+
+        for component in component_order.iter() {
+            let mut line = [rep[*component as usize]].repeat(chroma_width);
+            if chroma_line_width > chroma_width {
+                line.extend([0].repeat(chroma_line_width - chroma_width));
+            }
+            data.extend(line.repeat(height));
+        }
+
+        YCoCg422pPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data,
+            fourcc: f,
+            component_order
+        }
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let chroma_stride = YCoCg422pPixelBuffer::chroma_stride(self.stride);
+        let start = x + y * self.stride;
+        let plane1 = self.stride * self.height + x / 2 + y * chroma_stride;
+        let plane2 = plane1 + chroma_stride * self.height;
+        let repr: [u8; 3] = color.into();
+
+        let c1 = repr[self.component_order[0] as usize] as u16;
+        let c2 = repr[self.component_order[1] as usize] as u16;
+
+        self.data[start] = color.y;
+        // the first pixel of a chroma block sets the chroma, the others average into it
+        if x % 2 == 0 {
+            self.data[plane1] = c1 as u8;
+            self.data[plane2] = c2 as u8;
+        } else {
+            self.data[plane1] = ((self.data[plane1] as u16 + c1) >> 1) as u8;
+            self.data[plane2] = ((self.data[plane2] as u16 + c2) >> 1) as u8;
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let chroma_stride = YCoCg422pPixelBuffer::chroma_stride(self.stride);
+        let start = x + y * self.stride;
+        let plane1 = self.stride * self.height + x / 2 + y * chroma_stride;
+        let plane2 = plane1 + chroma_stride * self.height;
+
+        let mut color: [u8; 3] = [self.data[start], 0, 0];
+        color[self.component_order[0] as usize] = self.data[plane1];
+        color[self.component_order[1] as usize] = self.data[plane2];
+
+        Ok(DigitalYCoCgColor::from(color))
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,167 @@
+//
+// Tests
+//
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::ycocg422p::YCoCg422pPixelBuffer;
+pub use crate::color::DigitalYCoCgColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCoCgColor{ y: 255, co: 64, cg: 0 };
+        let buffer = YCoCg422pPixelBuffer::new_with_background(2, 2, color, None, Some("YCoCg"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod ycocg {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YCoCg422pPixelBuffer::new(2, 2, None, Some("YCoCg"));
+        assert_eq!(buffer.data.len(), 8);
+        assert_eq!(buffer.data[0], 0);
+        assert_eq!(buffer.data[1], 0);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2);
+    }
+
+    #[test]
+    fn prefilled_buffer() {
+        let data = vec![255, 64, 128, 64, 255, 64, 128, 64];
+        let copy = data.clone();
+        let buffer = YCoCg422pPixelBuffer::new_with_data(2, 2, data, None, Some("YCoCg")).unwrap();
+        assert_eq!(buffer.data.len(), 8);
+        for x in 0..buffer.data.len() {
+            assert_eq!(copy[x], buffer.data[x]);
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YCoCg422pPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 64, co: 255, cg: 128 },
+            None, Some("YCoCg")
+        );
+        assert_eq!(buffer.data.len(), 8);
+        for y in 0..2 {
+            for x in 0..2 {
+                assert_eq!(buffer.data[y * 2 + x], 64, "Y, x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[4 + y + x / 2], 255, "Co, x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[6 + y + x / 2], 128, "Cg, x: {}, y: {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YCoCg422pPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 64, co: 255, cg: 128 },
+            Some(4), Some("YCoCg")
+        );
+
+        assert_eq!(buffer.data.len(), 16);
+        for y in 0..2 {
+            assert_eq!(buffer.data[y * 4 + 0], 64, "Y, x: {}, y: {}", 0, y);
+            assert_eq!(buffer.data[y * 4 + 1], 64, "Y, x: {}, y: {}", 1, y);
+            assert_eq!(buffer.data[y * 4 + 2], 0, "Y padding, y: {}", y);
+
+            assert_eq!(buffer.data[8 + y * 2 + 0], 255, "Co, x: 0/1, y: {}", y);
+            assert_eq!(buffer.data[8 + y * 2 + 1], 0, "Co padding, y: {}", y);
+
+            assert_eq!(buffer.data[12 + y * 2 + 0], 128, "Cg, x: 0/1, y: {}", y);
+            assert_eq!(buffer.data[12 + y * 2 + 1], 0, "Cg padding, y: {}", y);
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YCoCg422pPixelBuffer::new(2, 2, None, Some("YCoCg"));
+
+        match buffer.set_pixel(1, 1, DigitalYCoCgColor{ y: 64, co: 255, cg: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCoCgColor{ y: 64, co: 255, cg: 128 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[3], 64);
+        assert_eq!(buffer.data[5], 127);
+        assert_eq!(buffer.data[7], 64);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            1, 2, 0, 0,  // Y, y = 0
+            3, 4, 0, 0,  // Y, y = 1
+            5, 0,        // Co, y = 0
+            6, 0,        // Co, y = 1
+            7, 0,        // Cg, y = 0
+            8, 0,        // Cg, y = 1
+        ];
+        let buffer = YCoCg422pPixelBuffer::new_with_data(2, 2, data, Some(4), Some("YCoCg")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 1, co: 5, cg: 7 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 4, co: 6, cg: 8 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod ycgco {
+    use super::*;
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YCoCg422pPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 64, co: 255, cg: 128 },
+            None, Some("YCgCo")
+        );
+        assert_eq!(buffer.data.len(), 8);
+        for y in 0..2 {
+            assert_eq!(buffer.data[4 + y], 128, "Cg, y: {}", y);
+            assert_eq!(buffer.data[6 + y], 255, "Co, y: {}", y);
+        }
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let buffer = YCoCg422pPixelBuffer::new_with_data(2, 2, data, None, Some("YCgCo")).unwrap();
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 4, co: 8, cg: 6 })
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! This module describes an interleaved YCoCg pixel buffer with full resolution
+
+use crate::*;
+pub use crate::color::DigitalYCoCgColor;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum YCoCgComponent {
+    Y = 0,
+    Co = 1,
+    Cg = 2,
+    Ignore = -1
+}
+
+/// YCoCg Pixel buffer without alpha channel
+#[derive(Debug, PartialEq)]
+pub struct YCoCg444iPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: Vec<YCoCgComponent>,
+    data: Vec<u8>
+}
+
+impl<'a> YCoCg444iPixelBuffer<'a> {
+    fn decode_component_order(fourcc:&'a str) -> Vec<YCoCgComponent> {
+        match fourcc {
+            "YCgCo" => vec![YCoCgComponent::Y, YCoCgComponent::Cg, YCoCgComponent::Co],
+            "CoCgY" => vec![YCoCgComponent::Co, YCoCgComponent::Cg, YCoCgComponent::Y],
+            "YCoCg" | "YCoCg444" | _ => vec![YCoCgComponent::Y, YCoCgComponent::Co, YCoCgComponent::Cg],
+        }
+    }
+}
+
+impl<'a> PixelBuffer<'a> for YCoCg444iPixelBuffer<'a> {
+    type ColorType = DigitalYCoCgColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `<length of color type representation> * width`
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCg`, `YCoCg444` (default)
+    /// * `YCgCo`
+    /// * `CoCgY`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YCoCg444iPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YCoCg");
+        let component_order = YCoCg444iPixelBuffer::decode_component_order(f);
+        let line_width = stride.unwrap_or(width * 3);
+
+        YCoCg444iPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            component_order
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `<length of color type representation> * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCg`, `YCoCg444` (default)
+    /// * `YCgCo`
+    /// * `CoCgY`
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `YCoCg444iPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("YCoCg");
+        let component_order = YCoCg444iPixelBuffer::decode_component_order(f);
+
+        if data.len() < stride.unwrap_or(width * 3) * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            YCoCg444iPixelBuffer {
+                width,
+                height,
+                data,
+                stride: stride.unwrap_or(width * 3),
+                fourcc: f,
+                component_order
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `<length of color type representation> * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCg`, `YCoCg444` (default)
+    /// * `YCgCo`
+    /// * `CoCgY`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YCoCg444iPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YCoCg");
+        let component_order = YCoCg444iPixelBuffer::decode_component_order(f);
+        let rep: [u8; 3] = color.into();
+        let line_width = stride.unwrap_or(width * component_order.len());
+        let data:Vec<u8>;
+
+        let representation = vec![
+            rep[component_order[0] as usize],
+            rep[component_order[1] as usize],
+            rep[component_order[2] as usize],
+        ];
+
+        if line_width > width * 3 {
+            let mut line = representation.repeat(width);
+            line.extend([0].repeat(line_width - width * 3));
+            data = line.repeat(height);
+        } else {
+            data = representation.repeat(width * height);
+        }
+
+        YCoCg444iPixelBuffer {
+            width,
+            height,
+            data,
+            stride: line_width,
+            fourcc: f,
+            component_order
+        }
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * self.component_order.len() + y * self.stride;
+        let repr: [u8; 3] = color.into();
+
+        for i in 0..self.component_order.len() {
+            self.data[start + i] = repr[self.component_order[i] as usize];
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 3 + y * self.stride;
+        let mut color: [u8; 3] = [0, 0, 0];
+        for i in 0..self.component_order.len() {
+            color[self.component_order[i] as usize] = self.data[start + i];
+        }
+
+        Ok(DigitalYCoCgColor::from(color))
+    }
+}
+
+pub mod iter;
+mod tests;
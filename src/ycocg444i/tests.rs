@@ -0,0 +1,352 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::ycocg444i::YCoCg444iPixelBuffer;
+pub use crate::color::DigitalYCoCgColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCoCgColor{ y: 255, co: 64, cg: 0 };
+        let buffer = YCoCg444iPixelBuffer::new_with_background(2, 2, color, None, Some("YCoCg"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod ycocg {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YCoCg444iPixelBuffer::new(2, 2, None, Some("YCoCg"));
+        assert_eq!(buffer.data.len(), 12);
+        assert_eq!(buffer.data[0], 0);
+        assert_eq!(buffer.data[1], 0);
+        assert_eq!(buffer.data[2], 0);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2 * 3);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2 * 3);
+    }
+
+    #[test]
+    fn prefilled_buffer() {
+        let data = vec![0, 255, 64, 0, 255, 64, 0, 255, 64, 0, 255, 64];
+        let copy = data.clone();
+        let buffer = YCoCg444iPixelBuffer::new_with_data(2, 2, data, None, Some("YCoCg")).unwrap();
+        assert_eq!(buffer.data.len(), 12);
+        for x in 0..buffer.data.len() {
+            assert_eq!(copy[x], buffer.data[x]);
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YCoCg444iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 255, co: 64, cg: 0 },
+            None, Some("YCoCg")
+        );
+        assert_eq!(buffer.data.len(), 12);
+        for x in (0..12).step_by(3) {
+            assert_eq!(buffer.data[x + 0], 255);
+            assert_eq!(buffer.data[x + 1], 64);
+            assert_eq!(buffer.data[x + 2], 0);
+        }
+    }
+    
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YCoCg444iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 255, co: 64, cg: 0 },
+            Some(12), Some("YCoCg")
+        );
+
+        assert_eq!(buffer.data.len(), 24);
+        for y in 0..2 {
+            for x in 0..2 {
+                assert_eq!(buffer.data[x * 3 + y * 12 + 0], 255, "x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[x * 3 + y * 12 + 1], 64, "x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[x * 3 + y * 12 + 2], 0, "x: {}, y: {}", x, y);
+            }
+            for p in 6..12 {
+                assert_eq!(buffer.data[p + y * 12], 0);
+            }
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YCoCg444iPixelBuffer::new(2, 2, None, Some("YCoCg"));
+        assert_eq!(buffer.data[9], 0);
+        assert_eq!(buffer.data[10], 0);
+        assert_eq!(buffer.data[11], 0);
+        
+        match buffer.set_pixel(1, 1, DigitalYCoCgColor{ y: 255, co: 64, cg: 0 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCoCgColor{ y: 255, co: 64, cg: 0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[9], 255);
+        assert_eq!(buffer.data[10], 64);
+        assert_eq!(buffer.data[11], 0);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0, 1, 2, 3, 4, 5, 0, 0, 6, 7, 8, 9, 10, 11, 0, 0];
+        let buffer = YCoCg444iPixelBuffer::new_with_data(2, 2, data, Some(8), Some("YCoCg")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 0, co: 1, cg: 2 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 9, co: 10, cg: 11 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod ycgco {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YCoCg444iPixelBuffer::new(2, 2, None, Some("YCgCo"));
+        assert_eq!(buffer.data.len(), 12);
+        assert_eq!(buffer.data[0], 0);
+        assert_eq!(buffer.data[1], 0);
+        assert_eq!(buffer.data[2], 0);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2 * 3);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2 * 3);
+    }
+
+    #[test]
+    fn prefilled_buffer() {
+        let data = vec![0, 255, 64, 0, 255, 64, 0, 255, 64, 0, 255, 64];
+        let copy = data.clone();
+        let buffer = YCoCg444iPixelBuffer::new_with_data(2, 2, data, None, Some("YCgCo")).unwrap();
+        assert_eq!(buffer.data.len(), 12);
+        for x in 0..buffer.data.len() {
+            assert_eq!(copy[x], buffer.data[x]);
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YCoCg444iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 255, co: 64, cg: 0 },
+            None, Some("YCgCo")
+        );
+        assert_eq!(buffer.data.len(), 12);
+        for x in (0..12).step_by(3) {
+            assert_eq!(buffer.data[x + 0], 255);
+            assert_eq!(buffer.data[x + 1], 0);
+            assert_eq!(buffer.data[x + 2], 64);
+        }
+    }
+    
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YCoCg444iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 255, co: 64, cg: 0 },
+            Some(12), Some("YCgCo")
+        );
+
+        assert_eq!(buffer.data.len(), 24);
+        for y in 0..2 {
+            for x in 0..2 {
+                assert_eq!(buffer.data[x * 3 + y * 12 + 0], 255, "x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[x * 3 + y * 12 + 1], 0, "x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[x * 3 + y * 12 + 2], 64, "x: {}, y: {}", x, y);
+            }
+            for p in 6..12 {
+                assert_eq!(buffer.data[p + y * 12], 0);
+            }
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YCoCg444iPixelBuffer::new(2, 2, None, Some("YCgCo"));
+        assert_eq!(buffer.data[9], 0);
+        assert_eq!(buffer.data[10], 0);
+        assert_eq!(buffer.data[11], 0);
+        
+        match buffer.set_pixel(1, 1, DigitalYCoCgColor{ y: 255, co: 64, cg: 0 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCoCgColor{ y: 255, co: 64, cg: 0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[9], 255);
+        assert_eq!(buffer.data[10], 0);
+        assert_eq!(buffer.data[11], 64);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0, 1, 2, 3, 4, 5, 0, 0, 6, 7, 8, 9, 10, 11, 0, 0];
+        let buffer = YCoCg444iPixelBuffer::new_with_data(2, 2, data, Some(8), Some("YCgCo")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 0, co: 2, cg: 1 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 9, co: 11, cg: 10 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod cocgy {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YCoCg444iPixelBuffer::new(2, 2, None, Some("CoCgY"));
+        assert_eq!(buffer.data.len(), 12);
+        assert_eq!(buffer.data[0], 0);
+        assert_eq!(buffer.data[1], 0);
+        assert_eq!(buffer.data[2], 0);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2 * 3);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2 * 3);
+    }
+
+    #[test]
+    fn prefilled_buffer() {
+        let data = vec![0, 255, 64, 0, 255, 64, 0, 255, 64, 0, 255, 64];
+        let copy = data.clone();
+        let buffer = YCoCg444iPixelBuffer::new_with_data(2, 2, data, None, Some("CoCgY")).unwrap();
+        assert_eq!(buffer.data.len(), 12);
+        for x in 0..buffer.data.len() {
+            assert_eq!(copy[x], buffer.data[x]);
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YCoCg444iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 255, co: 64, cg: 0 },
+            None, Some("CoCgY")
+        );
+        assert_eq!(buffer.data.len(), 12);
+        for x in (0..12).step_by(3) {
+            assert_eq!(buffer.data[x + 0], 64);
+            assert_eq!(buffer.data[x + 1], 0);
+            assert_eq!(buffer.data[x + 2], 255);
+        }
+    }
+    
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YCoCg444iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgColor{ y: 255, co: 64, cg: 0 },
+            Some(12), Some("CoCgY")
+        );
+
+        assert_eq!(buffer.data.len(), 24);
+        for y in 0..2 {
+            for x in 0..2 {
+                assert_eq!(buffer.data[x * 3 + y * 12 + 0], 64, "x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[x * 3 + y * 12 + 1], 0, "x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[x * 3 + y * 12 + 2], 255, "x: {}, y: {}", x, y);
+            }
+            for p in 6..12 {
+                assert_eq!(buffer.data[p + y * 12], 0);
+            }
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YCoCg444iPixelBuffer::new(2, 2, None, Some("CoCgY"));
+        assert_eq!(buffer.data[9], 0);
+        assert_eq!(buffer.data[10], 0);
+        assert_eq!(buffer.data[11], 0);
+        
+        match buffer.set_pixel(1, 1, DigitalYCoCgColor{ y: 255, co: 64, cg: 0 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCoCgColor{ y: 255, co: 64, cg: 0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[9], 64);
+        assert_eq!(buffer.data[10], 0);
+        assert_eq!(buffer.data[11], 255);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![0, 1, 2, 3, 4, 5, 0, 0, 6, 7, 8, 9, 10, 11, 0, 0];
+        let buffer = YCoCg444iPixelBuffer::new_with_data(2, 2, data, Some(8), Some("CoCgY")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 2, co: 0, cg: 1 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgColor{ y: 11, co: 9, cg: 10 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Create iterator for YCoCg444pPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::YCoCg444pPixelBuffer;
+use crate::color::DigitalYCoCgColor;
+
+
+/// Pixel iterator for `YCoCg444pPixelBuffer`
+///
+/// Items it will generate are of type `Pixel<DigitalYCoCgColor>`
+#[derive(Debug, PartialEq)]
+pub struct YCoCg444pPixelIterator<'a> {
+    base: YCoCg444pPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for YCoCg444pPixelIterator<'a> {
+    type Item = Pixel<DigitalYCoCgColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for YCoCg444pPixelBuffer<'a> {
+    type Item = Pixel<DigitalYCoCgColor>;
+    type IntoIter = YCoCg444pPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        YCoCg444pPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
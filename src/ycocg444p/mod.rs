@@ -0,0 +1,212 @@
+//! This module describes a planar YCoCg pixel buffer with full resolution
+
+use crate::*;
+pub use crate::color::DigitalYCoCgColor;
+pub use crate::ycocg444i::YCoCgComponent;
+
+/// YCoCg Pixel buffer without alpha channel, three full resolution planes
+#[derive(Debug, PartialEq)]
+pub struct YCoCg444pPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: Vec<YCoCgComponent>,
+    data: Vec<u8>
+}
+
+impl<'a> YCoCg444pPixelBuffer<'a> {
+    fn decode_component_order(fourcc:&'a str) -> Vec<YCoCgComponent> {
+        match fourcc {
+            "YCgCo" =>
+                vec![YCoCgComponent::Cg, YCoCgComponent::Co],
+            "YCoCg" | _ =>
+                vec![YCoCgComponent::Co, YCoCgComponent::Cg],
+        }
+    }
+}
+
+
+impl<'a> PixelBuffer<'a> for YCoCg444pPixelBuffer<'a> {
+    type ColorType = DigitalYCoCgColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `width`, used for all planes
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCg` (default)
+    /// * `YCgCo`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YCoCg444pPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YCoCg");
+        let component_order = YCoCg444pPixelBuffer::decode_component_order(f);
+        let line_width = stride.unwrap_or(width);
+
+        YCoCg444pPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data: vec![0; line_width * height * 3],
+            fourcc: f,
+            component_order
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `width`, used for all planes
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCg` (default)
+    /// * `YCgCo`
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `YCoCg444pPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+
+        if data.len() < stride.unwrap_or(width) * height * 3 {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        let f = fourcc.unwrap_or("YCoCg");
+        let component_order = YCoCg444pPixelBuffer::decode_component_order(f);
+
+        Ok(
+            YCoCg444pPixelBuffer {
+                width,
+                height,
+                stride: stride.unwrap_or(width),
+                data,
+                fourcc: f,
+                component_order
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: `width`, used for all planes
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCg` (default)
+    /// * `YCgCo`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YCoCg444pPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YCoCg");
+        let component_order = YCoCg444pPixelBuffer::decode_component_order(f);
+        let line_width = stride.unwrap_or(width);
+        let rep: [u8; 3] = color.into();
+
+        let mut data: Vec<u8> = Vec::with_capacity(line_width * height * 3);
+
+        let planes = [
+            YCoCgComponent::Y,
+            component_order[0],
+            component_order[1]
+        ];
+
+        for component in planes.iter() {
+            let mut line = [rep[*component as usize]].repeat(width);
+            if line_width > width {
+                line.extend([0].repeat(line_width - width));
+            }
+            data.extend(line.repeat(height));
+        }
+
+        YCoCg444pPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data,
+            fourcc: f,
+            component_order
+        }
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x + y * self.stride;
+        let plane_size = self.stride * self.height;
+        let repr: [u8; 3] = color.into();
+
+        self.data[start] = repr[0];
+        for i in 0..2 {
+            self.data[start + plane_size * (i + 1)] = repr[self.component_order[i] as usize];
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x + y * self.stride;
+        let plane_size = self.stride * self.height;
+        let mut color: [u8; 3] = [self.data[start], 0, 0];
+
+        for i in 0..2 {
+            color[self.component_order[i] as usize] = self.data[start + plane_size * (i + 1)];
+        }
+
+        Ok(DigitalYCoCgColor::from(color))
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,54 @@
+//! Create iterator for YCoCgRPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::YCoCgRPixelBuffer;
+use crate::color::DigitalYCoCgRColor;
+
+
+/// Pixel iterator for `YCoCgRPixelBuffer`
+///
+/// Items it will generate are of type `Pixel<DigitalYCoCgRColor>`
+#[derive(Debug, PartialEq)]
+pub struct YCoCgRPixelIterator<'a> {
+    base: YCoCgRPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for YCoCgRPixelIterator<'a> {
+    type Item = Pixel<DigitalYCoCgRColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for YCoCgRPixelBuffer<'a> {
+    type Item = Pixel<DigitalYCoCgRColor>;
+    type IntoIter = YCoCgRPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        YCoCgRPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
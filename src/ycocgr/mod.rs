@@ -0,0 +1,217 @@
+//! This module describes an interleaved YCoCg-R pixel buffer with full resolution
+//!
+//! YCoCg-R is the reversible lifting variant of YCoCg, converting from RGB and back
+//! is bit-exact. Co and Cg need 9 bits, so every component is stored as a signed
+//! 16 bit little endian value.
+
+use crate::*;
+pub use crate::color::DigitalYCoCgRColor;
+pub use crate::ycocg444i::YCoCgComponent;
+
+/// YCoCg-R Pixel buffer without alpha channel
+#[derive(Debug, PartialEq)]
+pub struct YCoCgRPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: Vec<YCoCgComponent>,
+    data: Vec<u8>
+}
+
+impl<'a> YCoCgRPixelBuffer<'a> {
+    fn decode_component_order(fourcc:&'a str) -> Vec<YCoCgComponent> {
+        match fourcc {
+            "YCgCoR" => vec![YCoCgComponent::Y, YCoCgComponent::Cg, YCoCgComponent::Co],
+            "YCoCgR" | "YCoCg-R" | _ => vec![YCoCgComponent::Y, YCoCgComponent::Co, YCoCgComponent::Cg],
+        }
+    }
+
+    fn representation(color: DigitalYCoCgRColor) -> [i16; 3] {
+        [color.y as i16, color.co, color.cg]
+    }
+}
+
+impl<'a> PixelBuffer<'a> for YCoCgRPixelBuffer<'a> {
+    type ColorType = DigitalYCoCgRColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `6 * width`
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCgR`, `YCoCg-R` (default)
+    /// * `YCgCoR`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YCoCgRPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YCoCgR");
+        let component_order = YCoCgRPixelBuffer::decode_component_order(f);
+        let line_width = stride.unwrap_or(width * 6);
+
+        YCoCgRPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f,
+            component_order
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `6 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCgR`, `YCoCg-R` (default)
+    /// * `YCgCoR`
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `YCoCgRPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("YCoCgR");
+        let component_order = YCoCgRPixelBuffer::decode_component_order(f);
+
+        if data.len() < stride.unwrap_or(width * 6) * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            YCoCgRPixelBuffer {
+                width,
+                height,
+                data,
+                stride: stride.unwrap_or(width * 6),
+                fourcc: f,
+                component_order
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `6 * width`
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YCoCgR`, `YCoCg-R` (default)
+    /// * `YCgCoR`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YCoCgRPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YCoCgR");
+        let component_order = YCoCgRPixelBuffer::decode_component_order(f);
+        let rep = YCoCgRPixelBuffer::representation(color);
+        let line_width = stride.unwrap_or(width * 6);
+        let data:Vec<u8>;
+
+        let mut representation: Vec<u8> = Vec::with_capacity(6);
+        for component in component_order.iter() {
+            representation.extend_from_slice(&rep[*component as usize].to_le_bytes());
+        }
+
+        if line_width > width * 6 {
+            let mut line = representation.repeat(width);
+            line.extend([0].repeat(line_width - width * 6));
+            data = line.repeat(height);
+        } else {
+            data = representation.repeat(width * height);
+        }
+
+        YCoCgRPixelBuffer {
+            width,
+            height,
+            data,
+            stride: line_width,
+            fourcc: f,
+            component_order
+        }
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 6 + y * self.stride;
+        let repr = YCoCgRPixelBuffer::representation(color);
+
+        for i in 0..self.component_order.len() {
+            let bytes = repr[self.component_order[i] as usize].to_le_bytes();
+            self.data[start + i * 2] = bytes[0];
+            self.data[start + i * 2 + 1] = bytes[1];
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x * 6 + y * self.stride;
+        let mut color: [i16; 3] = [0, 0, 0];
+        for i in 0..self.component_order.len() {
+            color[self.component_order[i] as usize] = i16::from_le_bytes([
+                self.data[start + i * 2],
+                self.data[start + i * 2 + 1]
+            ]);
+        }
+
+        Ok(DigitalYCoCgRColor {
+            y: color[0] as u8, co: color[1], cg: color[2]
+        })
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,175 @@
+//
+// Tests
+//
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::ycocgr::YCoCgRPixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::color::DigitalYCoCgRColor;
+pub use grapho_color::DigitalRGBAColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCoCgRColor{ y: 63, co: 255, cg: -127 };
+        let buffer = YCoCgRPixelBuffer::new_with_background(2, 2, color, None, Some("YCoCgR"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod ycocgr {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YCoCgRPixelBuffer::new(2, 2, None, Some("YCoCgR"));
+        assert_eq!(buffer.data.len(), 24);
+        assert_eq!(buffer.data[0], 0);
+        assert_eq!(buffer.data[1], 0);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2 * 6);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2 * 6);
+    }
+
+    #[test]
+    fn prefilled_buffer() {
+        let data = vec![63, 0, 255, 0, 129, 255].repeat(4);
+        let copy = data.clone();
+        let buffer = YCoCgRPixelBuffer::new_with_data(2, 2, data, None, Some("YCoCgR")).unwrap();
+        assert_eq!(buffer.data.len(), 24);
+        for x in 0..buffer.data.len() {
+            assert_eq!(copy[x], buffer.data[x]);
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YCoCgRPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgRColor{ y: 63, co: 255, cg: -127 },
+            None, Some("YCoCgR")
+        );
+        assert_eq!(buffer.data.len(), 24);
+        for x in (0..24).step_by(6) {
+            assert_eq!(buffer.data[x + 0], 63);
+            assert_eq!(buffer.data[x + 1], 0);
+            assert_eq!(buffer.data[x + 2], 255);
+            assert_eq!(buffer.data[x + 3], 0);
+            assert_eq!(buffer.data[x + 4], 129);
+            assert_eq!(buffer.data[x + 5], 255);
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YCoCgRPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCoCgRColor{ y: 63, co: 255, cg: -127 },
+            Some(16), Some("YCoCgR")
+        );
+
+        assert_eq!(buffer.data.len(), 32);
+        for y in 0..2 {
+            for x in 0..2 {
+                assert_eq!(buffer.data[x * 6 + y * 16 + 0], 63, "x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[x * 6 + y * 16 + 2], 255, "x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[x * 6 + y * 16 + 4], 129, "x: {}, y: {}", x, y);
+            }
+            for p in 12..16 {
+                assert_eq!(buffer.data[p + y * 16], 0);
+            }
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YCoCgRPixelBuffer::new(2, 2, None, Some("YCoCgR"));
+
+        match buffer.set_pixel(1, 1, DigitalYCoCgRColor{ y: 63, co: -255, cg: -127 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCoCgRColor{ y: 63, co: -255, cg: -127 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(&buffer.data[18..24], &[63, 0, 1, 255, 129, 255]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            1, 0, 2, 0, 3, 0,  4, 0, 5, 0, 6, 0,  0, 0,
+            7, 0, 8, 0, 9, 0,  10, 0, 255, 255, 0, 1,  0, 0
+        ];
+        let buffer = YCoCgRPixelBuffer::new_with_data(2, 2, data, Some(14), Some("YCoCgR")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgRColor{ y: 1, co: 2, cg: 3 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgRColor{ y: 10, co: -1, cg: 256 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod ycgcor {
+    use super::*;
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![1, 0, 2, 0, 3, 0];
+        let buffer = YCoCgRPixelBuffer::new_with_data(1, 1, data, None, Some("YCgCoR")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCoCgRColor{ y: 1, co: 3, cg: 2 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn lossless_rgb_roundtrip() {
+        let mut rgb = RGBPixelBuffer::new(16, 16, None, Some("RGB"));
+        for y in 0..16 {
+            for x in 0..16 {
+                let color = DigitalRGBAColor{ r: (x * 16) as u8, g: (y * 16 + x) as u8, b: (255 - y * 16) as u8, a: 255 };
+                rgb.set_pixel(x, y, color).unwrap();
+            }
+        }
+
+        let ycocgr: YCoCgRPixelBuffer = rgb.into();
+        let back: RGBPixelBuffer = ycocgr.into();
+
+        for y in 0..16 {
+            for x in 0..16 {
+                let color = DigitalRGBAColor{ r: (x * 16) as u8, g: (y * 16 + x) as u8, b: (255 - y * 16) as u8, a: 255 };
+                assert_eq!(back.get_pixel(x, y).unwrap(), color, "x: {}, y: {}", x, y);
+            }
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! This module describes a packed YUV pixel buffer with 4:1:1 color subsampling (quarter
+//! horizontal resolution)
+//!
+//! `Y41P` stores 8 pixels in a 12 byte macropixel: `U0 Y0 V0 Y1 U4 Y2 V4 Y3 Y4 Y5 Y6 Y7`,
+//! pixels 0 to 3 share `U0`/`V0` and pixels 4 to 7 share `U4`/`V4`.
+
+use crate::*;
+pub use grapho_color::DigitalYCbCrColor;
+
+/// Offsets of the Y samples of the 8 pixels within a macropixel
+const Y_OFFSETS: [usize; 8] = [1, 3, 5, 7, 8, 9, 10, 11];
+
+/// YUV Pixel buffer without alpha channel, quarter horizontal resolution color subsampling
+#[derive(Debug, PartialEq)]
+pub struct YUV411iPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    data: Vec<u8>
+}
+
+impl<'a> YUV411iPixelBuffer<'a> {
+    /// Default stride, a line always consists of complete macropixels
+    fn default_stride(width: usize) -> usize {
+        (width + 7) / 8 * 12
+    }
+
+    /// Offsets of the Y sample and both chroma samples of the pixel at `x`, `y`
+    fn offsets(&self, x: usize, y: usize) -> (usize, usize, usize) {
+        let macropixel = (x / 8) * 12 + y * self.stride;
+        let u = macropixel + ((x % 8) / 4) * 4;
+
+        (macropixel + Y_OFFSETS[x % 8], u, u + 2)
+    }
+}
+
+
+impl<'a> PixelBuffer<'a> for YUV411iPixelBuffer<'a> {
+    type ColorType = DigitalYCbCrColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: 12 bytes for every started group of 8 pixels
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `Y41P` (default)
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YUV411iPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("Y41P");
+        let line_width = stride.unwrap_or(YUV411iPixelBuffer::default_stride(width));
+
+        YUV411iPixelBuffer {
+            width,
+            height,
+            data: vec![0; line_width * height],
+            stride: line_width,
+            fourcc: f
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: 12 bytes for every started group of 8 pixels
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `Y41P` (default)
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `YUV411iPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("Y41P");
+        let line_width = stride.unwrap_or(YUV411iPixelBuffer::default_stride(width));
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            YUV411iPixelBuffer {
+                width,
+                height,
+                data,
+                stride: line_width,
+                fourcc: f
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer if it differs from the
+    ///   default: 12 bytes for every started group of 8 pixels
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `Y41P` (default)
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YUV411iPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("Y41P");
+        let macropixels = (width + 7) / 8;
+        let line_width = stride.unwrap_or(YUV411iPixelBuffer::default_stride(width));
+        let data:Vec<u8>;
+
+        let representation = vec![
+            color.cb, color.y, color.cr, color.y, color.cb, color.y,
+            color.cr, color.y, color.y, color.y, color.y, color.y
+        ];
+
+        if line_width > macropixels * 12 {
+            let mut line = representation.repeat(macropixels);
+            line.extend([0].repeat(line_width - macropixels * 12));
+            data = line.repeat(height);
+        } else {
+            data = representation.repeat(macropixels * height);
+        }
+
+        YUV411iPixelBuffer {
+            width,
+            height,
+            data,
+            stride: line_width,
+            fourcc: f
+        }
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let (start, u, v) = self.offsets(x, y);
+
+        self.data[start] = color.y;
+        // the first pixel of a chroma block sets the chroma, the others average into it
+        if x % 4 == 0 {
+            self.data[u] = color.cb;
+            self.data[v] = color.cr;
+        } else {
+            self.data[u] = ((self.data[u] as u16 + color.cb as u16) >> 1) as u8;
+            self.data[v] = ((self.data[v] as u16 + color.cr as u16) >> 1) as u8;
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let (start, u, v) = self.offsets(x, y);
+
+        Ok(DigitalYCbCrColor {
+            y: self.data[start], cb: self.data[u], cr: self.data[v]
+        })
+    }
+}
+
+pub mod iter;
+mod tests;
@@ -0,0 +1,171 @@
+//
+// Tests
+//
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::yuv411i::YUV411iPixelBuffer;
+pub use crate::yuv411p::YUV411pPixelBuffer;
+pub use crate::yuv422i::YUV422iPixelBuffer;
+pub use grapho_color::DigitalYCbCrColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 };
+        let buffer = YUV411iPixelBuffer::new_with_background(10, 2, color, None, Some("Y41P"));
+
+        let mut count = 0;
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+            count += 1;
+        }
+        assert_eq!(count, 20);
+    }
+}
+
+#[cfg(test)]
+mod y41p {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUV411iPixelBuffer::new(8, 2, None, Some("Y41P"));
+        assert_eq!(buffer.data.len(), 24);
+        assert_eq!(buffer.width, 8);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 12);
+        assert_eq!(buffer.get_width(), 8);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 12);
+        assert_eq!(buffer.get_fourcc(), "Y41P");
+    }
+
+    #[test]
+    fn odd_size_buffer() {
+        let buffer = YUV411iPixelBuffer::new(9, 1, None, None);
+        assert_eq!(buffer.stride, 24);
+        assert_eq!(buffer.data.len(), 24);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 23];
+        match YUV411iPixelBuffer::new_with_data(8, 2, data, None, Some("Y41P")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YUV411iPixelBuffer::new_with_background(
+            8, 1,
+            DigitalYCbCrColor{ y: 255, cb: 64, cr: 1 },
+            None, Some("Y41P")
+        );
+        assert_eq!(buffer.data, vec![64, 255, 1, 255, 64, 255, 1, 255, 255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YUV411iPixelBuffer::new_with_background(
+            8, 2,
+            DigitalYCbCrColor{ y: 255, cb: 64, cr: 1 },
+            Some(16), Some("Y41P")
+        );
+
+        assert_eq!(buffer.data.len(), 32);
+        for y in 0..2 {
+            assert_eq!(buffer.data[y * 16 + 0], 64, "y: {}", y);
+            assert_eq!(buffer.data[y * 16 + 11], 255, "y: {}", y);
+            for x in 12..16 {
+                assert_eq!(buffer.data[x + y * 16], 0, "x: {}, y: {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV411iPixelBuffer::new(8, 2, None, Some("Y41P"));
+
+        match buffer.set_pixel(5, 1, DigitalYCbCrColor{ y: 255, cb: 64, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(8, 0, DigitalYCbCrColor{ y: 255, cb: 64, cr: 128 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[12 + 9], 255);
+        assert_eq!(buffer.data[12 + 4], 32);
+        assert_eq!(buffer.data[12 + 6], 64);
+        assert_eq!(buffer.data[12 + 0], 0);
+        assert_eq!(buffer.data[12 + 2], 0);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            20, 0, 30, 1, 21, 2, 31, 3, 4, 5, 6, 7, // y = 0
+            22, 8, 32, 9, 23, 10, 33, 11, 12, 13, 14, 15, // y = 1
+        ];
+        let buffer = YUV411iPixelBuffer::new_with_data(8, 2, data, None, Some("Y41P")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 0, cb: 20, cr: 30 })
+        }
+
+        match buffer.get_pixel(3, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 3, cb: 20, cr: 30 })
+        }
+
+        match buffer.get_pixel(4, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 12, cb: 23, cr: 33 })
+        }
+
+        match buffer.get_pixel(7, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 15, cb: 23, cr: 33 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn yuv411p() {
+        let color = DigitalYCbCrColor{ y: 200, cb: 64, cr: 128 };
+        let buffer = YUV411iPixelBuffer::new_with_background(8, 2, color, None, None);
+
+        let planar: YUV411pPixelBuffer = buffer.into();
+        assert_eq!(planar.get_fourcc(), "411P");
+        for (_x, _y, c) in planar {
+            assert_eq!(c, color);
+        }
+    }
+
+    #[test]
+    fn yuv422i() {
+        let color = DigitalYCbCrColor{ y: 200, cb: 64, cr: 128 };
+        let buffer = YUV422iPixelBuffer::new_with_background(8, 1, color, None, None);
+
+        let packed: YUV411iPixelBuffer = buffer.into();
+        for (_x, _y, c) in packed {
+            assert_eq!(c, color);
+        }
+    }
+}
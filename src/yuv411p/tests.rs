@@ -0,0 +1,237 @@
+//
+// Tests
+//
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::yuv411p::YUV411pPixelBuffer;
+pub use crate::yuv420p::YUV420pPixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use grapho_color::{ DigitalYCbCrColor, DigitalRGBAColor };
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 };
+        let buffer = YUV411pPixelBuffer::new_with_background(5, 5, color, None, Some("YVU9"));
+
+        let mut count = 0;
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+            count += 1;
+        }
+        assert_eq!(count, 25);
+    }
+}
+
+#[cfg(test)]
+mod p411 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUV411pPixelBuffer::new(4, 2, None, Some("411P"));
+        assert_eq!(buffer.data.len(), 12);
+        assert_eq!(buffer.width, 4);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 4);
+        assert_eq!(buffer.get_width(), 4);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 4);
+    }
+
+    #[test]
+    fn odd_size_buffer() {
+        let buffer = YUV411pPixelBuffer::new(5, 3, None, Some("Y41B"));
+        assert_eq!(buffer.data.len(), 5 * 3 + 2 * 3 * 2);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 11];
+        match YUV411pPixelBuffer::new_with_data(4, 2, data, None, Some("411P")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YUV411pPixelBuffer::new_with_background(
+            4, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            Some(8), Some("411P")
+        );
+
+        assert_eq!(buffer.data.len(), 24);
+        for y in 0..2 {
+            for x in 0..4 {
+                assert_eq!(buffer.data[y * 8 + x], 64, "Y, x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[y * 8 + x + 4], 0, "Y padding, x: {}, y: {}", x, y);
+            }
+            assert_eq!(buffer.data[16 + y * 2], 255, "U, y: {}", y);
+            assert_eq!(buffer.data[17 + y * 2], 0, "U padding, y: {}", y);
+            assert_eq!(buffer.data[20 + y * 2], 128, "V, y: {}", y);
+            assert_eq!(buffer.data[21 + y * 2], 0, "V padding, y: {}", y);
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV411pPixelBuffer::new(4, 2, None, Some("411P"));
+
+        match buffer.set_pixel(3, 1, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(4, 0, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[7], 64);
+        assert_eq!(buffer.data[8], 0);
+        assert_eq!(buffer.data[9], 127);
+        assert_eq!(buffer.data[10], 0);
+        assert_eq!(buffer.data[11], 64);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            0, 1, 2, 3, 4, 5, 6, 7,  // Y, y = 0
+            8, 9, 10, 11, 12, 13, 14, 15,  // Y, y = 1
+            20, 21,  // U, y = 0
+            22, 23,  // U, y = 1
+            30, 31,  // V, y = 0
+            32, 33,  // V, y = 1
+        ];
+        let buffer = YUV411pPixelBuffer::new_with_data(8, 2, data, None, Some("411P")).unwrap();
+
+        match buffer.get_pixel(3, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 3, cb: 20, cr: 30 })
+        }
+
+        match buffer.get_pixel(4, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 12, cb: 23, cr: 33 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod yvu9 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUV411pPixelBuffer::new(8, 8, None, Some("YVU9"));
+        assert_eq!(buffer.data.len(), 64 + 4 * 2);
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YUV411pPixelBuffer::new_with_background(
+            4, 4,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            None, Some("YVU9")
+        );
+        assert_eq!(buffer.data.len(), 18);
+        for x in 0..16 {
+            assert_eq!(buffer.data[x], 64, "Y, x: {}", x);
+        }
+        assert_eq!(buffer.data[16], 128);
+        assert_eq!(buffer.data[17], 255);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV411pPixelBuffer::new(4, 4, None, Some("YVU9"));
+
+        match buffer.set_pixel(3, 3, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        assert_eq!(buffer.data[15], 64);
+        assert_eq!(buffer.data[16], 64);
+        assert_eq!(buffer.data[17], 127);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            0, 1, 2, 3, 4, 5, 6, 7,  // Y, y = 0
+            8, 9, 10, 11, 12, 13, 14, 15,  // Y, y = 1
+            16, 17, 18, 19, 20, 21, 22, 23,  // Y, y = 2
+            24, 25, 26, 27, 28, 29, 30, 31,  // Y, y = 3
+            32, 33, 34, 35, 36, 37, 38, 39,  // Y, y = 4
+            30, 31,  // V, y = 0..3
+            32, 33,  // V, y = 4
+            20, 21,  // U, y = 0..3
+            22, 23,  // U, y = 4
+        ];
+        let buffer = YUV411pPixelBuffer::new_with_data(8, 5, data, None, Some("YVU9")).unwrap();
+
+        match buffer.get_pixel(1, 3) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 25, cb: 20, cr: 30 })
+        }
+
+        match buffer.get_pixel(7, 4) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 39, cb: 23, cr: 33 })
+        }
+    }
+
+    #[test]
+    fn yuv9() {
+        let buffer = YUV411pPixelBuffer::new_with_background(
+            4, 4,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            None, Some("YUV9")
+        );
+        assert_eq!(buffer.data.len(), 18);
+        assert_eq!(buffer.data[16], 255);
+        assert_eq!(buffer.data[17], 128);
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn yuv420p() {
+        let color = DigitalYCbCrColor{ y: 200, cb: 64, cr: 128 };
+        let buffer = YUV420pPixelBuffer::new_with_background(4, 4, color, None, None);
+
+        let planar: YUV411pPixelBuffer = buffer.into();
+        assert_eq!(planar.get_fourcc(), "411P");
+        for (_x, _y, c) in planar {
+            assert_eq!(c, color);
+        }
+    }
+
+    #[test]
+    fn rgb() {
+        let buffer = YUV411pPixelBuffer::new_with_background(
+            4, 4,
+            DigitalYCbCrColor{ y: 235, cb: 128, cr: 128 },
+            None, Some("YVU9")
+        );
+
+        let rgb: RGBPixelBuffer = buffer.into();
+        for (_x, _y, c) in rgb {
+            assert_eq!(c, DigitalRGBAColor{ r: 235, g: 235, b: 235, a: 255 });
+        }
+    }
+}
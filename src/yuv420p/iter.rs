@@ -0,0 +1,54 @@
+//! Create iterator for YUV420pPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::YUV420pPixelBuffer;
+use grapho_color::DigitalYCbCrColor;
+
+
+/// Pixel iterator for `YUV420pPixelBuffer`
+///
+/// Items it will generate are of type `Pixel<DigitalYCbCrColor>`
+#[derive(Debug, PartialEq)]
+pub struct YUV420pPixelIterator<'a> {
+    base: YUV420pPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for YUV420pPixelIterator<'a> {
+    type Item = Pixel<DigitalYCbCrColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for YUV420pPixelBuffer<'a> {
+    type Item = Pixel<DigitalYCbCrColor>;
+    type IntoIter = YUV420pPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        YUV420pPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! This module describes a planar YUV pixel buffer with color subsampling (half horizontal and
+//! half vertical resolution)
+
+use crate::*;
+pub use grapho_color::DigitalYCbCrColor;
+pub use crate::yuv422i::YUVComponent;
+
+/// YUV Pixel buffer without alpha channel, 2x2 color subsampling
+#[derive(Debug, PartialEq)]
+pub struct YUV420pPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: Vec<YUVComponent>,
+    data: Vec<u8>
+}
+
+impl<'a> YUV420pPixelBuffer<'a> {
+    fn decode_component_order(fourcc:&'a str) -> Vec<YUVComponent> {
+        match fourcc {
+            "YV12" | "YVU" =>
+                vec![YUVComponent::V, YUVComponent::U],
+            "I420" | "IYUV" | "YUV" | _ =>
+                vec![YUVComponent::U, YUVComponent::V],
+        }
+    }
+
+    /// Line width of the chroma planes
+    fn chroma_stride(stride: usize) -> usize {
+        (stride + 1) / 2
+    }
+
+    /// Number of lines of the chroma planes
+    fn chroma_height(height: usize) -> usize {
+        (height + 1) / 2
+    }
+
+    /// Number of bytes needed for a buffer with given stride and height
+    fn buffer_size(stride: usize, height: usize) -> usize {
+        stride * height
+            + YUV420pPixelBuffer::chroma_stride(stride) * YUV420pPixelBuffer::chroma_height(height) * 2
+    }
+
+    /// Offsets of the Y sample and both chroma samples of the pixel at `x`, `y`
+    fn offsets(&self, x: usize, y: usize) -> (usize, usize, usize) {
+        let chroma_stride = YUV420pPixelBuffer::chroma_stride(self.stride);
+        let start = x + y * self.stride;
+        let plane1 = self.stride * self.height + x / 2 + (y / 2) * chroma_stride;
+        let plane2 = plane1 + chroma_stride * YUV420pPixelBuffer::chroma_height(self.height);
+
+        (start, plane1, plane2)
+    }
+}
+
+
+impl<'a> PixelBuffer<'a> for YUV420pPixelBuffer<'a> {
+    type ColorType = DigitalYCbCrColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width`, the chroma planes use half of it
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `I420`, `IYUV`, `YUV` (default)
+    /// * `YV12`, `YVU`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YUV420pPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("I420");
+        let component_order = YUV420pPixelBuffer::decode_component_order(f);
+        let line_width = stride.unwrap_or(width);
+
+        YUV420pPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data: vec![0; YUV420pPixelBuffer::buffer_size(line_width, height)],
+            fourcc: f,
+            component_order
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width`, the chroma planes use half of it
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `I420`, `IYUV`, `YUV` (default)
+    /// * `YV12`, `YVU`
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `YUV420pPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let line_width = stride.unwrap_or(width);
+
+        if data.len() < YUV420pPixelBuffer::buffer_size(line_width, height) {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        let f = fourcc.unwrap_or("I420");
+        let component_order = YUV420pPixelBuffer::decode_component_order(f);
+
+        Ok(
+            YUV420pPixelBuffer {
+                width,
+                height,
+                stride: line_width,
+                data,
+                fourcc: f,
+                component_order
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width`, the chroma planes use half of it
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `I420`, `IYUV`, `YUV` (default)
+    /// * `YV12`, `YVU`
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YUV420pPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("I420");
+        let component_order = YUV420pPixelBuffer::decode_component_order(f);
+        let line_width = stride.unwrap_or(width);
+        let chroma_line_width = YUV420pPixelBuffer::chroma_stride(line_width);
+        let chroma_width = (width + 1) / 2;
+        let chroma_height = YUV420pPixelBuffer::chroma_height(height);
+
+        let mut data: Vec<u8> = Vec::with_capacity(YUV420pPixelBuffer::buffer_size(line_width, height));
+
+        let mut y = [color.y].repeat(width);
+        let mut u = [color.cb].repeat(chroma_width);
+        let mut v = [color.cr].repeat(chroma_width);
+
+        if line_width > width {
+            y.extend([0].repeat(line_width - width));
+        }
+        if chroma_line_width > chroma_width {
+            u.extend([0].repeat(chroma_line_width - chroma_width));
+            v.extend([0].repeat(chroma_line_width - chroma_width));
+        }
+
+        data.extend(y.repeat(height));
+
+        if component_order[0] == YUVComponent::U {
+            data.extend(u.repeat(chroma_height));
+            data.extend(v.repeat(chroma_height));
+        } else {
+            data.extend(v.repeat(chroma_height));
+            data.extend(u.repeat(chroma_height));
+        }
+
+        YUV420pPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data,
+            fourcc: f,
+            component_order
+        }
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let (start, plane1, plane2) = self.offsets(x, y);
+
+        let (u, v) = if self.component_order[0] == YUVComponent::U { (plane1, plane2) } else { (plane2, plane1) };
+
+        self.data[start] = color.y;
+        // the first pixel of a chroma block sets the chroma, the others average into it
+        if x % 2 == 0 && y % 2 == 0 {
+            self.data[u] = color.cb;
+            self.data[v] = color.cr;
+        } else {
+            self.data[u] = ((self.data[u] as u16 + color.cb as u16) >> 1) as u8;
+            self.data[v] = ((self.data[v] as u16 + color.cr as u16) >> 1) as u8;
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let (start, plane1, plane2) = self.offsets(x, y);
+
+        let u: u8;
+        let v: u8;
+
+        if self.component_order[0] == YUVComponent::U {
+            u = self.data[plane1];
+            v = self.data[plane2];
+        } else {
+            v = self.data[plane1];
+            u = self.data[plane2];
+        }
+
+        Ok(DigitalYCbCrColor {
+            y: self.data[start], cb: u, cr: v
+        })
+    }
+}
+
+pub mod iter;
+mod tests;
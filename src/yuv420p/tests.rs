@@ -0,0 +1,211 @@
+//
+// Tests
+//
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::yuv420p::YUV420pPixelBuffer;
+pub use grapho_color::DigitalYCbCrColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 };
+        let buffer = YUV420pPixelBuffer::new_with_background(2, 2, color, None, Some("I420"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod i420 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUV420pPixelBuffer::new(2, 2, None, Some("I420"));
+        assert_eq!(buffer.data.len(), 6);
+        assert_eq!(buffer.data[0], 0);
+        assert_eq!(buffer.data[1], 0);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2);
+    }
+
+    #[test]
+    fn odd_size_buffer() {
+        let buffer = YUV420pPixelBuffer::new(3, 3, None, Some("I420"));
+        assert_eq!(buffer.data.len(), 3 * 3 + 2 * 2 * 2);
+    }
+
+    #[test]
+    fn prefilled_buffer() {
+        let data = vec![255, 64, 128, 64, 255, 64];
+        let copy = data.clone();
+        let buffer = YUV420pPixelBuffer::new_with_data(2, 2, data, None, Some("I420")).unwrap();
+        assert_eq!(buffer.data.len(), 6);
+        for x in 0..buffer.data.len() {
+            assert_eq!(copy[x], buffer.data[x]);
+        }
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![255, 64, 128, 64, 255];
+        match YUV420pPixelBuffer::new_with_data(2, 2, data, None, Some("I420")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YUV420pPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            None, Some("I420")
+        );
+        assert_eq!(buffer.data.len(), 6);
+        for x in 0..4 {
+            assert_eq!(buffer.data[x], 64, "Y, x: {}", x);
+        }
+        assert_eq!(buffer.data[4], 255);
+        assert_eq!(buffer.data[5], 128);
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YUV420pPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            Some(4), Some("I420")
+        );
+
+        assert_eq!(buffer.data.len(), 12);
+        for y in 0..2 {
+            assert_eq!(buffer.data[y * 4 + 0], 64, "Y, x: {}, y: {}", 0, y);
+            assert_eq!(buffer.data[y * 4 + 1], 64, "Y, x: {}, y: {}", 1, y);
+            assert_eq!(buffer.data[y * 4 + 2], 0, "Y padding, y: {}", y);
+            assert_eq!(buffer.data[y * 4 + 3], 0, "Y padding, y: {}", y);
+        }
+        assert_eq!(buffer.data[8], 255);
+        assert_eq!(buffer.data[9], 0);
+        assert_eq!(buffer.data[10], 128);
+        assert_eq!(buffer.data[11], 0);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV420pPixelBuffer::new(2, 2, None, Some("I420"));
+
+        match buffer.set_pixel(1, 1, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[3], 64);
+        assert_eq!(buffer.data[4], 127);
+        assert_eq!(buffer.data[5], 64);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            1, 2, 3, 4,  // Y, y = 0
+            5, 6, 7, 8,  // Y, y = 1
+            9, 10, 11, 12,  // Y, y = 2
+            13, 14, 15, 16,  // Y, y = 3
+            20, 21,  // U, y = 0, 1
+            22, 23,  // U, y = 2, 3
+            30, 31,  // V, y = 0, 1
+            32, 33,  // V, y = 2, 3
+        ];
+        let buffer = YUV420pPixelBuffer::new_with_data(4, 4, data, None, Some("I420")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 1, cb: 20, cr: 30 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 6, cb: 20, cr: 30 })
+        }
+
+        match buffer.get_pixel(2, 3) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 15, cb: 23, cr: 33 })
+        }
+
+        match buffer.get_pixel(0, 4) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod yv12 {
+    use super::*;
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YUV420pPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            None, Some("YV12")
+        );
+        assert_eq!(buffer.data.len(), 6);
+        for x in 0..4 {
+            assert_eq!(buffer.data[x], 64, "Y, x: {}", x);
+        }
+        assert_eq!(buffer.data[4], 128);
+        assert_eq!(buffer.data[5], 255);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV420pPixelBuffer::new(2, 2, None, Some("YV12"));
+
+        match buffer.set_pixel(1, 1, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        assert_eq!(buffer.data[3], 64);
+        assert_eq!(buffer.data[4], 64);
+        assert_eq!(buffer.data[5], 127);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            1, 2, 0, 0,  // Y, y = 0
+            3, 4, 0, 0,  // Y, y = 1
+            5, 0,        // V, y = 0, 1
+            6, 0,        // U, y = 0, 1
+        ];
+        let buffer = YUV420pPixelBuffer::new_with_data(2, 2, data, Some(4), Some("YV12")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 1, cb: 6, cr: 5 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 4, cb: 6, cr: 5 })
+        }
+    }
+}
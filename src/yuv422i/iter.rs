@@ -23,7 +23,8 @@ impl<'a> Iterator for YUV422iPixelIterator<'a> {
             return None;
         }
 
-        let color = self.base.get_pixel(self.x, self.y).unwrap();
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
 
         self.x += 1;
         if self.x >= self.base.width {
@@ -31,7 +32,7 @@ impl<'a> Iterator for YUV422iPixelIterator<'a> {
             self.y += 1;
         }
 
-        Some((self.x, self.y, color))
+        Some((x, y, color))
     }
 
     fn count(self) -> usize {
@@ -8,6 +8,7 @@ pub enum YUVComponent {
     Y = 0,
     U = 1,
     V = 2,
+    Alpha = 3,
     Ignore = -1
 }
 
@@ -120,7 +121,7 @@ impl<'a> PixelBuffer<'a> for YUV422iPixelBuffer<'a> {
             YUV422iPixelBuffer {
                 width,
                 height,
-                stride: stride.unwrap_or(width * 3),
+                stride: stride.unwrap_or(width * 2),
                 data,
                 fourcc: f,
                 component_order
@@ -161,7 +162,7 @@ impl<'a> PixelBuffer<'a> for YUV422iPixelBuffer<'a> {
         for i in 0..4 {
             representation[i] = 
                 match component_order[0][i] {
-                    YUVComponent::Y | YUVComponent::Ignore => color.y,
+                    YUVComponent::Y | YUVComponent::Alpha | YUVComponent::Ignore => color.y,
                     YUVComponent::U => color.cb,
                     YUVComponent::V => color.cr
                 };
@@ -202,7 +203,7 @@ impl<'a> PixelBuffer<'a> for YUV422iPixelBuffer<'a> {
     }
 
     fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
-        if (x >= self.width) || (y >= self.width) {
+        if (x >= self.width) || (y >= self.height) {
             return Err(PixelBufferError::RequestOutOfBounds);
         }
 
@@ -210,20 +211,26 @@ impl<'a> PixelBuffer<'a> for YUV422iPixelBuffer<'a> {
         let repr: [u8; 3] = color.into();
 
         let order = &self.component_order[x % 2];
+        // the first pixel of a chroma block sets the chroma, the others average into it
+        let first = x % 2 == 0;
         for i in 0..4 {
-            match order[i] {
-                YUVComponent::Y => self.data[start + i] = repr[0],
-                YUVComponent::U => self.data[start + i] = (self.data[start + i] + repr[1]) >> 1,
-                YUVComponent::V => self.data[start + i] = (self.data[start + i] + repr[2]) >> 1,
-                YUVComponent::Ignore => ()
-            }
+            let c = match order[i] {
+                YUVComponent::Y => {
+                    self.data[start + i] = repr[0];
+                    continue;
+                },
+                YUVComponent::U => repr[1],
+                YUVComponent::V => repr[2],
+                YUVComponent::Alpha | YUVComponent::Ignore => continue
+            };
+            self.data[start + i] = if first { c } else { ((self.data[start + i] as u16 + c as u16) >> 1) as u8 };
         }
 
         Ok(())
     }
 
     fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
-        if (x >= self.width) || (y >= self.width) {
+        if (x >= self.width) || (y >= self.height) {
             return Err(PixelBufferError::RequestOutOfBounds);
         }
 
@@ -239,7 +246,7 @@ impl<'a> PixelBuffer<'a> for YUV422iPixelBuffer<'a> {
                 YUVComponent::Y => y = self.data[start + i],
                 YUVComponent::U => u = self.data[start + i],
                 YUVComponent::V => v = self.data[start + i],
-                YUVComponent::Ignore => ()
+                YUVComponent::Alpha | YUVComponent::Ignore => ()
             }
         }
 
@@ -251,3 +258,6 @@ impl<'a> PixelBuffer<'a> for YUV422iPixelBuffer<'a> {
 
 pub mod iter;
 mod tests;
+
+pub mod y210;
+pub use y210::Y210PixelBuffer;
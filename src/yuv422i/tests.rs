@@ -18,6 +18,14 @@ mod iter {
             assert_eq!(pixel.2, color);
         }
     }
+
+    #[test]
+    fn iter_coordinates() {
+        let buffer = YUV422iPixelBuffer::new_with_data(2, 2, vec![0, 1, 0, 2, 0, 3, 0, 4], Some(4), Some("UYVY")).unwrap();
+
+        let pixels: Vec<_> = buffer.into_iter().map(|pixel| (pixel.0, pixel.1, pixel.2.y)).collect();
+        assert_eq!(pixels, vec![(0, 0, 1), (1, 0, 2), (0, 1, 3), (1, 1, 4)]);
+    }
 }
 
 #[cfg(test)]
@@ -127,6 +135,49 @@ mod uyvy {
             Ok(_color) => assert!(false)
         }
     }
+
+    #[test]
+    fn non_square_bounds() {
+        let mut wide = YUV422iPixelBuffer::new(4, 2, None, Some("UYVY"));
+        match wide.set_pixel(0, 3, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        let tall = YUV422iPixelBuffer::new(2, 4, None, Some("UYVY"));
+        match tall.get_pixel(1, 3) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 0, cb: 0, cr: 0 })
+        }
+    }
+
+    #[test]
+    fn set_pixel_chroma_overflow() {
+        let mut buffer = YUV422iPixelBuffer::new_with_background(
+            2, 1,
+            DigitalYCbCrColor{ y: 64, cb: 200, cr: 250 },
+            None, Some("UYVY")
+        );
+
+        match buffer.set_pixel(1, 0, DigitalYCbCrColor{ y: 64, cb: 200, cr: 250 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        assert_eq!(buffer.data, vec![200, 64, 250, 64]);
+    }
+
+    #[test]
+    fn prefilled_buffer_default_stride() {
+        let data = vec![0, 1, 0, 2, 0, 3, 0, 4];
+        let buffer = YUV422iPixelBuffer::new_with_data(2, 2, data, None, Some("UYVY")).unwrap();
+        assert_eq!(buffer.get_stride(), 2 * 2);
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color.y, 4)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,54 @@
+//! Create iterator for Y210PixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::Y210PixelBuffer;
+use crate::color::DigitalYCbCr48Color;
+
+
+/// Pixel iterator for `Y210PixelBuffer`
+///
+/// Items it will generate are of type `Pixel<DigitalYCbCr48Color>`
+#[derive(Debug, PartialEq)]
+pub struct Y210PixelIterator<'a> {
+    base: Y210PixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for Y210PixelIterator<'a> {
+    type Item = Pixel<DigitalYCbCr48Color>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for Y210PixelBuffer<'a> {
+    type Item = Pixel<DigitalYCbCr48Color>;
+    type IntoIter = Y210PixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Y210PixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
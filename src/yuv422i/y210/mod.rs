@@ -0,0 +1,256 @@
+//! This module describes an interleaved high bit depth YUV 4:2:2 pixel buffer (`Y210`, `Y216`),
+//! the `YUYV` macropixel layout with 16 bit little endian containers
+//!
+//! `Y210` stores 10 significant bits in the most significant bits of each container,
+//! the remaining low bits are zero.
+
+use crate::*;
+pub use crate::color::DigitalYCbCr48Color;
+pub use crate::yuv422i::YUVComponent;
+use crate::color::quantize_msb;
+
+/// High bit depth YUV Pixel buffer without alpha channel, half resolution color subsampling
+#[derive(Debug, PartialEq)]
+pub struct Y210PixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: [Vec<YUVComponent>; 2],
+    bits: u32,
+    data: Vec<u8>
+}
+
+impl<'a> Y210PixelBuffer<'a> {
+    fn decode_bits(fourcc:&'a str) -> u32 {
+        match fourcc {
+            "Y216" => 16,
+            "Y210" | _ => 10,
+        }
+    }
+
+    /// Component order of the even and odd pixel of a macropixel, both codes use `YUYV`
+    fn decode_component_order(fourcc:&'a str) -> [Vec<YUVComponent>; 2] {
+        match fourcc {
+            "Y210" | "Y216" | _ => [
+                vec![YUVComponent::Y, YUVComponent::U, YUVComponent::Ignore, YUVComponent::V],
+                vec![YUVComponent::Ignore, YUVComponent::U, YUVComponent::Y, YUVComponent::V]
+            ],
+        }
+    }
+
+    /// Default stride in bytes, 8 bytes per macropixel of two pixels
+    fn default_stride(width: usize) -> usize {
+        (width + 1) / 2 * 8
+    }
+
+    /// Offset of the macropixel containing the pixel at `x`, `y`
+    fn macropixel_offset(&self, x: usize, y: usize) -> usize {
+        x / 2 * 8 + y * self.stride
+    }
+
+    fn read(&self, offset: usize) -> u16 {
+        u16::from_le_bytes([self.data[offset], self.data[offset + 1]])
+    }
+
+    fn write(&mut self, offset: usize, value: u16) {
+        let bytes = quantize_msb(value, self.bits).to_le_bytes();
+        self.data[offset] = bytes[0];
+        self.data[offset + 1] = bytes[1];
+    }
+}
+
+
+impl<'a> PixelBuffer<'a> for Y210PixelBuffer<'a> {
+    type ColorType = DigitalYCbCr48Color;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `4 * width`, `width` rounded up to the next even number
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `Y210` (default), 10 significant bits
+    /// * `Y216`, 16 significant bits
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `Y210PixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("Y210");
+        let line_width = stride.unwrap_or(Y210PixelBuffer::default_stride(width));
+
+        Y210PixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data: vec![0; line_width * height],
+            fourcc: f,
+            component_order: Y210PixelBuffer::decode_component_order(f),
+            bits: Y210PixelBuffer::decode_bits(f)
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `4 * width`, `width` rounded up to the next even number
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `Y210` (default), 10 significant bits
+    /// * `Y216`, 16 significant bits
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `Y210PixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("Y210");
+        let line_width = stride.unwrap_or(Y210PixelBuffer::default_stride(width));
+
+        if data.len() < line_width * height {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            Y210PixelBuffer {
+                width,
+                height,
+                stride: line_width,
+                data,
+                fourcc: f,
+                component_order: Y210PixelBuffer::decode_component_order(f),
+                bits: Y210PixelBuffer::decode_bits(f)
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the buffer in bytes if it differs from the
+    ///   default: `4 * width`, `width` rounded up to the next even number
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `Y210` (default), 10 significant bits
+    /// * `Y216`, 16 significant bits
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `Y210PixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let mut buffer = Y210PixelBuffer::new(width, height, stride, fourcc);
+        let macropixel_width = Y210PixelBuffer::default_stride(width);
+
+        let mut representation: Vec<u8> = Vec::with_capacity(8);
+        for component in buffer.component_order[0].iter() {
+            let value = match component {
+                YUVComponent::Y | YUVComponent::Alpha | YUVComponent::Ignore => color.y,
+                YUVComponent::U => color.cb,
+                YUVComponent::V => color.cr
+            };
+            representation.extend_from_slice(&quantize_msb(value, buffer.bits).to_le_bytes());
+        }
+
+        let mut line = representation.repeat(macropixel_width / 8);
+        if buffer.stride > macropixel_width {
+            line.extend([0].repeat(buffer.stride - macropixel_width));
+        }
+        buffer.data = line.repeat(height);
+
+        buffer
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = self.macropixel_offset(x, y);
+        // the first pixel of a chroma block sets the chroma, the others average into it
+        let first = x % 2 == 0;
+
+        for i in 0..4 {
+            let offset = start + i * 2;
+            let value = match self.component_order[x % 2][i] {
+                YUVComponent::Y => {
+                    self.write(offset, color.y);
+                    continue;
+                },
+                YUVComponent::U => color.cb,
+                YUVComponent::V => color.cr,
+                YUVComponent::Alpha | YUVComponent::Ignore => continue
+            };
+            if first {
+                self.write(offset, value);
+            } else {
+                let average = ((self.read(offset) as u32 + value as u32) >> 1) as u16;
+                self.write(offset, average);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = self.macropixel_offset(x, y);
+        let mut color = DigitalYCbCr48Color { y: 0, cb: 0, cr: 0 };
+
+        for i in 0..4 {
+            let value = self.read(start + i * 2);
+            match self.component_order[x % 2][i] {
+                YUVComponent::Y => color.y = value,
+                YUVComponent::U => color.cb = value,
+                YUVComponent::V => color.cr = value,
+                YUVComponent::Alpha | YUVComponent::Ignore => ()
+            }
+        }
+
+        Ok(color)
+    }
+}
+
+pub mod iter;
+mod tests;
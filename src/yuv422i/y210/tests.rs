@@ -0,0 +1,138 @@
+//
+// Tests
+//
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::yuv422i::Y210PixelBuffer;
+pub use crate::v210::V210PixelBuffer;
+pub use crate::color::DigitalYCbCr48Color;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCbCr48Color{ y: 0xffc0, cb: 0x4000, cr: 0 };
+        let buffer = Y210PixelBuffer::new_with_background(3, 2, color, None, Some("Y210"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod y210 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = Y210PixelBuffer::new(2, 2, None, Some("Y210"));
+        assert_eq!(buffer.data.len(), 16);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 8);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 8);
+    }
+
+    #[test]
+    fn odd_size_buffer() {
+        let buffer = Y210PixelBuffer::new(3, 1, None, Some("Y210"));
+        assert_eq!(buffer.stride, 16);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 15];
+        match Y210PixelBuffer::new_with_data(2, 2, data, None, Some("Y210")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = Y210PixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCr48Color{ y: 0x1234, cb: 0xffff, cr: 0x8000 },
+            Some(10), Some("Y210")
+        );
+
+        assert_eq!(buffer.data.len(), 20);
+        for y in 0..2 {
+            assert_eq!(
+                buffer.data[y * 10..y * 10 + 10],
+                [0x40, 0x12, 0xc0, 0xff, 0x40, 0x12, 0x00, 0x80, 0, 0],
+                "y: {}", y
+            );
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = Y210PixelBuffer::new(2, 2, None, Some("Y210"));
+
+        match buffer.set_pixel(1, 1, DigitalYCbCr48Color{ y: 0xffff, cb: 0x8000, cr: 0x4000 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCbCr48Color{ y: 0, cb: 0, cr: 0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[8..16], [0, 0, 0x00, 0x40, 0xc0, 0xff, 0x00, 0x20]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            0x40, 0x00, 0x80, 0x00, 0xc0, 0x00, 0x00, 0x01,
+        ];
+        let buffer = Y210PixelBuffer::new_with_data(2, 1, data, None, Some("Y210")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCr48Color{ y: 0x0040, cb: 0x0080, cr: 0x0100 })
+        }
+
+        match buffer.get_pixel(1, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCr48Color{ y: 0x00c0, cb: 0x0080, cr: 0x0100 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod y216 {
+    use super::*;
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = Y210PixelBuffer::new(2, 1, None, Some("Y216"));
+        buffer.set_pixel(0, 0, DigitalYCbCr48Color{ y: 0x1239, cb: 0, cr: 0 }).unwrap();
+
+        assert_eq!(buffer.data[0..2], [0x39, 0x12]);
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn v210() {
+        let color = DigitalYCbCr48Color{ y: 0xffc0, cb: 0x8000, cr: 0x4000 };
+        let v210 = V210PixelBuffer::new_with_background(6, 2, color, None, None);
+
+        let y210: Y210PixelBuffer = v210.into();
+        for (x, y, c) in y210 {
+            assert_eq!(c.y, color.y, "x: {}, y: {}", x, y);
+            assert_eq!(c.cb, color.cb, "x: {}, y: {}", x, y);
+            assert_eq!(c.cr, color.cr, "x: {}, y: {}", x, y);
+        }
+    }
+}
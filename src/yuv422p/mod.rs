@@ -4,7 +4,7 @@ use crate::*;
 pub use grapho_color::DigitalYCbCrColor;
 pub use crate::yuv422i::YUVComponent;
 
-/// YUV Pixel buffer without alpha channel, half resolution color subsampling
+/// YUV Pixel buffer without alpha channel, half horizontal resolution color subsampling
 #[derive(Debug, PartialEq)]
 pub struct YUV422pPixelBuffer<'a> {
     width: usize,
@@ -18,11 +18,31 @@ pub struct YUV422pPixelBuffer<'a> {
 impl<'a> YUV422pPixelBuffer<'a> {
     fn decode_component_order(fourcc:&'a str) -> Vec<YUVComponent> {
         match fourcc {
-            "YV21" | "YVU" =>
+            "YV16" | "YVU" | "YV21" =>
                 vec![YUVComponent::V, YUVComponent::U],
-            "YV12" | "YUV" | _ =>
+            "I422" | "YUV" | "YV12" | _ =>
                 vec![YUVComponent::U, YUVComponent::V],
-        }       
+        }
+    }
+
+    /// Line width of the chroma planes
+    fn chroma_stride(stride: usize) -> usize {
+        (stride + 1) / 2
+    }
+
+    /// Number of bytes needed for a buffer with given stride and height
+    fn buffer_size(stride: usize, height: usize) -> usize {
+        (stride + YUV422pPixelBuffer::chroma_stride(stride) * 2) * height
+    }
+
+    /// Offsets of the Y sample and both chroma samples of the pixel at `x`, `y`
+    fn offsets(&self, x: usize, y: usize) -> (usize, usize, usize) {
+        let chroma_stride = YUV422pPixelBuffer::chroma_stride(self.stride);
+        let start = x + y * self.stride;
+        let plane1 = self.stride * self.height + x / 2 + y * chroma_stride;
+        let plane2 = plane1 + chroma_stride * self.height;
+
+        (start, plane1, plane2)
     }
 }
 
@@ -31,73 +51,76 @@ impl<'a> PixelBuffer<'a> for YUV422pPixelBuffer<'a> {
     type ColorType = DigitalYCbCrColor;
 
     /// Create a new pixel buffer with given dimensions
-    /// 
+    ///
     /// # Arguments
-    /// 
+    ///
     /// * `width` - The width of the buffer
     /// * `height` - The height of the buffer
-    /// * `stride` - optional, the line-width of the buffer if it differs from the
-    ///   default: `<length of color type representation> * width`
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width`, the chroma planes use half of it
     /// * `fourcc` - optional, data representation format
-    /// 
+    ///
     /// # Defined fourcc codes
-    /// 
-    /// * `YV12`, `YUV` (default)
-    /// * `YV21`, `YVU`
-    /// 
+    ///
+    /// * `I422`, `YUV` (default)
+    /// * `YV16`, `YVU`
+    /// * `YV12`, `YV21`, legacy codes from before the 4:2:0 split, same as `I422` and `YV16` respectively
+    ///
     /// # Returns
-    /// 
+    ///
     /// This returns a new instance of `YUV422pPixelBuffer` with it's contents set to zero
     fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
-        let f = fourcc.unwrap_or("YV12");
+        let f = fourcc.unwrap_or("I422");
         let component_order = YUV422pPixelBuffer::decode_component_order(f);
-        let line_width = stride.unwrap_or(width * 2);
+        let line_width = stride.unwrap_or(width);
 
         YUV422pPixelBuffer {
             width,
             height,
-            stride: stride.unwrap_or(width),
-            data: vec![0; line_width * height],
+            stride: line_width,
+            data: vec![0; YUV422pPixelBuffer::buffer_size(line_width, height)],
             fourcc: f,
             component_order
         }
     }
 
     /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
-    /// 
+    ///
     /// # Arguments
-    /// 
+    ///
     /// * `width` - The width of the buffer
     /// * `height` - The height of the buffer
-    /// * `stride` - optional, the line-width of the buffer if it differs from the
-    ///   default: `<length of color type representation> * width`
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width`, the chroma planes use half of it
     /// * `fourcc` - optional, data representation format
     /// * `data` - the data to consume
-    /// 
+    ///
     /// # Defined fourcc codes
-    /// 
-    /// * `YV12`, `YUV` (default)
-    /// * `YV21`, `YVU`
-    /// 
+    ///
+    /// * `I422`, `YUV` (default)
+    /// * `YV16`, `YVU`
+    /// * `YV12`, `YV21`, legacy codes from before the 4:2:0 split, same as `I422` and `YV16` respectively
+    ///
     /// # Returns
-    /// 
+    ///
     /// This returns a `Result` with either a new instance of `YUV422pPixelBuffer`
     /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
     /// the requested dimensions
     fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let line_width = stride.unwrap_or(width);
 
-        if data.len() < stride.unwrap_or(width) * height {
+        if data.len() < YUV422pPixelBuffer::buffer_size(line_width, height) {
             return Err(PixelBufferError::BufferTooSmall);
         }
 
-        let f = fourcc.unwrap_or("YV12");
+        let f = fourcc.unwrap_or("I422");
         let component_order = YUV422pPixelBuffer::decode_component_order(f);
 
         Ok(
             YUV422pPixelBuffer {
                 width,
                 height,
-                stride: stride.unwrap_or(width),
+                stride: line_width,
                 data,
                 fourcc: f,
                 component_order
@@ -106,41 +129,46 @@ impl<'a> PixelBuffer<'a> for YUV422pPixelBuffer<'a> {
     }
 
     /// Create a new pixel buffer with given dimensions and fill color
-    /// 
+    ///
     /// # Arguments
-    /// 
+    ///
     /// * `width` - The width of the buffer
     /// * `height` - The height of the buffer
-    /// * `stride` - optional, the line-width of the buffer if it differs from the
-    ///   default: `<length of color type representation> * width`
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width`, the chroma planes use half of it
     /// * `fourcc` - optional, data representation format
     /// * `color` - fill color to use
-    /// 
+    ///
     /// # Defined fourcc codes
-    /// 
-    /// * `YV12`, `YUV` (default)
-    /// * `YV21`, `YVU`
-    /// 
+    ///
+    /// * `I422`, `YUV` (default)
+    /// * `YV16`, `YVU`
+    /// * `YV12`, `YV21`, legacy codes from before the 4:2:0 split, same as `I422` and `YV16` respectively
+    ///
     /// # Returns
-    /// 
-    /// This returns a new instance of `YUV422iPixelBuffer` with it's contents set to the
+    ///
+    /// This returns a new instance of `YUV422pPixelBuffer` with it's contents set to the
     /// defined color. If stride is bigger than needed width the padding is filled with
     /// zeroes.
     fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
-        let f = fourcc.unwrap_or("YV12");
+        let f = fourcc.unwrap_or("I422");
         let component_order = YUV422pPixelBuffer::decode_component_order(f);
         let line_width = stride.unwrap_or(width);
-        
-        let mut data: Vec<u8> = Vec::with_capacity(line_width * height);
+        let chroma_line_width = YUV422pPixelBuffer::chroma_stride(line_width);
+        let chroma_width = (width + 1) / 2;
+
+        let mut data: Vec<u8> = Vec::with_capacity(YUV422pPixelBuffer::buffer_size(line_width, height));
 
         let mut y = [color.y].repeat(width);
-        let mut u = [color.cb].repeat(width / 2);
-        let mut v = [color.cr].repeat(width / 2);
+        let mut u = [color.cb].repeat(chroma_width);
+        let mut v = [color.cr].repeat(chroma_width);
 
         if line_width > width {
             y.extend([0].repeat(line_width - width));
-            u.extend([0].repeat((line_width - width) / 2));
-            v.extend([0].repeat((line_width - width) / 2));
+        }
+        if chroma_line_width > chroma_width {
+            u.extend([0].repeat(chroma_line_width - chroma_width));
+            v.extend([0].repeat(chroma_line_width - chroma_width));
         }
 
         data.extend(y.repeat(height));
@@ -152,7 +180,7 @@ impl<'a> PixelBuffer<'a> for YUV422pPixelBuffer<'a> {
             data.extend(v.repeat(height));
             data.extend(u.repeat(height));
         }
-         
+
         YUV422pPixelBuffer {
             width,
             height,
@@ -180,34 +208,33 @@ impl<'a> PixelBuffer<'a> for YUV422pPixelBuffer<'a> {
     }
 
     fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
-        if (x >= self.width) || (y >= self.width) {
+        if (x >= self.width) || (y >= self.height) {
             return Err(PixelBufferError::RequestOutOfBounds);
         }
 
-        let start = x + y * self.stride;
-        let plane1 = (self.stride * self.height) + x / 2 + y * self.stride / 2;
-        let plane2 = plane1 + (self.stride * self.height / 2);
+        let (start, plane1, plane2) = self.offsets(x, y);
+
+        let (u, v) = if self.component_order[0] == YUVComponent::U { (plane1, plane2) } else { (plane2, plane1) };
 
         self.data[start] = color.y;
-        if self.component_order[0] == YUVComponent::U {
-            self.data[plane1] = (self.data[plane1] + color.cb) >> 1;
-            self.data[plane2] = (self.data[plane2] + color.cr) >> 1;
+        // the first pixel of a chroma block sets the chroma, the others average into it
+        if x % 2 == 0 {
+            self.data[u] = color.cb;
+            self.data[v] = color.cr;
         } else {
-            self.data[plane2] = (self.data[plane2] + color.cb) >> 1;
-            self.data[plane1] = (self.data[plane1] + color.cr) >> 1;
+            self.data[u] = ((self.data[u] as u16 + color.cb as u16) >> 1) as u8;
+            self.data[v] = ((self.data[v] as u16 + color.cr as u16) >> 1) as u8;
         }
-   
+
         Ok(())
     }
 
     fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
-        if (x >= self.width) || (y >= self.width) {
+        if (x >= self.width) || (y >= self.height) {
             return Err(PixelBufferError::RequestOutOfBounds);
         }
 
-        let start = x + y * self.stride;
-        let plane1 = (self.stride * self.height) + x / 2 + y * self.stride / 2;
-        let plane2 = plane1 + (self.stride * self.height / 2);
+        let (start, plane1, plane2) = self.offsets(x, y);
 
         let u: u8;
         let v: u8;
@@ -219,7 +246,7 @@ impl<'a> PixelBuffer<'a> for YUV422pPixelBuffer<'a> {
             v = self.data[plane1];
             u = self.data[plane2];
         }
- 
+
         Ok(DigitalYCbCrColor {
             y: self.data[start], cb: u, cr: v
         })
@@ -0,0 +1,295 @@
+//! This module describes a semi-planar YUV pixel buffer, a Y plane followed by an interleaved U/V plane
+//! with 4:2:0 (`NV12`), 4:2:2 (`NV16`) or 4:4:4 (`NV24`) color subsampling
+
+use crate::*;
+pub use grapho_color::DigitalYCbCrColor;
+pub use crate::yuv422i::YUVComponent;
+
+/// YUV Pixel buffer without alpha channel, one Y plane and one interleaved, optionally subsampled U/V plane
+#[derive(Debug, PartialEq)]
+pub struct YUV422piPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    component_order: Vec<YUVComponent>,
+    subsampling: (usize, usize),
+    data: Vec<u8>
+}
+
+impl<'a> YUV422piPixelBuffer<'a> {
+    fn decode_component_order(fourcc:&'a str) -> Vec<YUVComponent> {
+        match fourcc {
+            "NV21" | "NV61" | "NV42" =>
+                vec![YUVComponent::V, YUVComponent::U],
+            "NV12" | "NV16" | "NV24" | _ =>
+                vec![YUVComponent::U, YUVComponent::V],
+        }
+    }
+
+    /// Horizontal and vertical chroma subsampling factors
+    fn decode_subsampling(fourcc:&'a str) -> (usize, usize) {
+        match fourcc {
+            "NV16" | "NV61" => (2, 1),
+            "NV24" | "NV42" => (1, 1),
+            "NV12" | "NV21" | _ => (2, 2),
+        }
+    }
+
+    /// Default stride, the chroma plane needs a full U/V pair for every subsampled pixel
+    fn default_stride(width: usize, subsampling: (usize, usize)) -> usize {
+        (width + subsampling.0 - 1) / subsampling.0 * subsampling.0
+    }
+
+    /// Line width of the interleaved chroma plane, rounded up to whole U/V pairs
+    fn chroma_stride(stride: usize, subsampling: (usize, usize)) -> usize {
+        (stride + subsampling.0 - 1) / subsampling.0 * 2
+    }
+
+    /// Number of bytes needed for a buffer with given stride and height
+    fn buffer_size(stride: usize, height: usize, subsampling: (usize, usize)) -> usize {
+        let chroma_height = (height + subsampling.1 - 1) / subsampling.1;
+
+        stride * height + YUV422piPixelBuffer::chroma_stride(stride, subsampling) * chroma_height
+    }
+
+    /// Offset of the first chroma sample (U for `NV12`, V for `NV21`) of the pixel at `x`, `y`
+    fn chroma_offset(&self, x: usize, y: usize) -> usize {
+        self.stride * self.height
+            + (y / self.subsampling.1) * YUV422piPixelBuffer::chroma_stride(self.stride, self.subsampling)
+            + (x / self.subsampling.0) * 2
+    }
+}
+
+
+impl<'a> PixelBuffer<'a> for YUV422piPixelBuffer<'a> {
+    type ColorType = DigitalYCbCrColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width` rounded up to the next even number for subsampled formats,
+    ///   the chroma plane uses the same stride (`NV12`, `NV16`, rounded up to an even
+    ///   number) or twice of it (`NV24`)
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `NV12` (default), 2x2 subsampled
+    /// * `NV21`, 2x2 subsampled
+    /// * `NV16`, horizontally subsampled
+    /// * `NV61`, horizontally subsampled
+    /// * `NV24`, no subsampling
+    /// * `NV42`, no subsampling
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YUV422piPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("NV12");
+        let component_order = YUV422piPixelBuffer::decode_component_order(f);
+        let subsampling = YUV422piPixelBuffer::decode_subsampling(f);
+        let line_width = stride.unwrap_or(YUV422piPixelBuffer::default_stride(width, subsampling));
+
+        YUV422piPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data: vec![0; YUV422piPixelBuffer::buffer_size(line_width, height, subsampling)],
+            fourcc: f,
+            component_order,
+            subsampling
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width` rounded up to the next even number for subsampled formats,
+    ///   the chroma plane uses the same stride (`NV12`, `NV16`, rounded up to an even
+    ///   number) or twice of it (`NV24`)
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `NV12` (default), 2x2 subsampled
+    /// * `NV21`, 2x2 subsampled
+    /// * `NV16`, horizontally subsampled
+    /// * `NV61`, horizontally subsampled
+    /// * `NV24`, no subsampling
+    /// * `NV42`, no subsampling
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `YUV422piPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("NV12");
+        let component_order = YUV422piPixelBuffer::decode_component_order(f);
+        let subsampling = YUV422piPixelBuffer::decode_subsampling(f);
+        let line_width = stride.unwrap_or(YUV422piPixelBuffer::default_stride(width, subsampling));
+
+        if data.len() < YUV422piPixelBuffer::buffer_size(line_width, height, subsampling) {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            YUV422piPixelBuffer {
+                width,
+                height,
+                stride: line_width,
+                data,
+                fourcc: f,
+                component_order,
+                subsampling
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y plane if it differs from the
+    ///   default: `width` rounded up to the next even number for subsampled formats,
+    ///   the chroma plane uses the same stride (`NV12`, `NV16`, rounded up to an even
+    ///   number) or twice of it (`NV24`)
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `NV12` (default), 2x2 subsampled
+    /// * `NV21`, 2x2 subsampled
+    /// * `NV16`, horizontally subsampled
+    /// * `NV61`, horizontally subsampled
+    /// * `NV24`, no subsampling
+    /// * `NV42`, no subsampling
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YUV422piPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("NV12");
+        let component_order = YUV422piPixelBuffer::decode_component_order(f);
+        let subsampling = YUV422piPixelBuffer::decode_subsampling(f);
+        let line_width = stride.unwrap_or(YUV422piPixelBuffer::default_stride(width, subsampling));
+        let chroma_line_width = YUV422piPixelBuffer::chroma_stride(line_width, subsampling);
+        let chroma_width = (width + subsampling.0 - 1) / subsampling.0 * 2;
+        let chroma_height = (height + subsampling.1 - 1) / subsampling.1;
+
+        let mut data: Vec<u8> = Vec::with_capacity(YUV422piPixelBuffer::buffer_size(line_width, height, subsampling));
+
+        let representation =
+            if component_order[0] == YUVComponent::U {
+                vec![color.cb, color.cr]
+            } else {
+                vec![color.cr, color.cb]
+            };
+
+        let mut y = [color.y].repeat(width);
+        let mut uv = representation.repeat(chroma_width / 2);
+
+        if line_width > width {
+            y.extend([0].repeat(line_width - width));
+        }
+        if chroma_line_width > chroma_width {
+            uv.extend([0].repeat(chroma_line_width - chroma_width));
+        }
+
+        data.extend(y.repeat(height));
+        data.extend(uv.repeat(chroma_height));
+
+        YUV422piPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data,
+            fourcc: f,
+            component_order,
+            subsampling
+        }
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x + y * self.stride;
+        let chroma = self.chroma_offset(x, y);
+
+        self.data[start] = color.y;
+        // the first pixel of a chroma block sets the chroma, the others average into it
+        let first = x % self.subsampling.0 == 0 && y % self.subsampling.1 == 0;
+        for i in 0..2 {
+            let value = match self.component_order[i] {
+                YUVComponent::U => color.cb,
+                _ => color.cr
+            };
+            if first {
+                self.data[chroma + i] = value;
+            } else {
+                self.data[chroma + i] = ((self.data[chroma + i] as u16 + value as u16) >> 1) as u8;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let start = x + y * self.stride;
+        let chroma = self.chroma_offset(x, y);
+
+        let mut u: u8 = 0;
+        let mut v: u8 = 0;
+
+        for i in 0..2 {
+            match self.component_order[i] {
+                YUVComponent::U => u = self.data[chroma + i],
+                _ => v = self.data[chroma + i]
+            }
+        }
+
+        Ok(DigitalYCbCrColor {
+            y: self.data[start], cb: u, cr: v
+        })
+    }
+}
+
+pub mod iter;
+mod tests;
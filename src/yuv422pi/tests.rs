@@ -0,0 +1,434 @@
+//
+// Tests
+//
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::yuv422pi::YUV422piPixelBuffer;
+pub use grapho_color::DigitalYCbCrColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 };
+        let buffer = YUV422piPixelBuffer::new_with_background(2, 2, color, None, Some("NV12"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+
+    #[test]
+    fn iter_coordinates() {
+        let buffer = YUV422piPixelBuffer::new(3, 2, None, Some("NV12"));
+        let coordinates: Vec<(usize, usize)> = buffer.into_iter().map(|p| (p.0, p.1)).collect();
+
+        assert_eq!(coordinates, vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+    }
+}
+
+#[cfg(test)]
+mod nv12 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUV422piPixelBuffer::new(2, 2, None, Some("NV12"));
+        assert_eq!(buffer.data.len(), 6);
+        assert_eq!(buffer.data[0], 0);
+        assert_eq!(buffer.data[1], 0);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2);
+    }
+
+    #[test]
+    fn odd_size_buffer() {
+        let buffer = YUV422piPixelBuffer::new(3, 3, None, Some("NV12"));
+        assert_eq!(buffer.stride, 4);
+        assert_eq!(buffer.data.len(), 4 * 3 + 4 * 2);
+    }
+
+    #[test]
+    fn odd_stride() {
+        let mut buffer = YUV422piPixelBuffer::new(3, 2, Some(3), Some("NV12"));
+        assert_eq!(buffer.data.len(), 3 * 2 + 4);
+
+        for y in 0..2 {
+            match buffer.set_pixel(2, y, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+                Err(_error) => assert!(false),
+                _ => assert!(true)
+            }
+        }
+
+        assert_eq!(buffer.data[5], 64);
+        assert_eq!(buffer.data[8], 255);
+        assert_eq!(buffer.data[9], 128);
+        assert_eq!(buffer.get_pixel(2, 1).unwrap().y, 64);
+    }
+
+    #[test]
+    fn prefilled_buffer() {
+        let data = vec![255, 64, 128, 64, 255, 64];
+        let copy = data.clone();
+        let buffer = YUV422piPixelBuffer::new_with_data(2, 2, data, None, Some("NV12")).unwrap();
+        assert_eq!(buffer.data.len(), 6);
+        for x in 0..buffer.data.len() {
+            assert_eq!(copy[x], buffer.data[x]);
+        }
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![255, 64, 128, 64, 255];
+        match YUV422piPixelBuffer::new_with_data(2, 2, data, None, Some("NV12")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YUV422piPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            None, Some("NV12")
+        );
+        assert_eq!(buffer.data.len(), 6);
+        for x in 0..4 {
+            assert_eq!(buffer.data[x], 64, "Y, x: {}", x);
+        }
+        assert_eq!(buffer.data[4], 255);
+        assert_eq!(buffer.data[5], 128);
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YUV422piPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            Some(4), Some("NV12")
+        );
+
+        assert_eq!(buffer.data.len(), 12);
+        for y in 0..2 {
+            assert_eq!(buffer.data[y * 4 + 0], 64, "Y, x: {}, y: {}", 0, y);
+            assert_eq!(buffer.data[y * 4 + 1], 64, "Y, x: {}, y: {}", 1, y);
+            assert_eq!(buffer.data[y * 4 + 2], 0, "Y padding, y: {}", y);
+            assert_eq!(buffer.data[y * 4 + 3], 0, "Y padding, y: {}", y);
+        }
+        assert_eq!(buffer.data[8], 255);
+        assert_eq!(buffer.data[9], 128);
+        assert_eq!(buffer.data[10], 0);
+        assert_eq!(buffer.data[11], 0);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV422piPixelBuffer::new(2, 2, None, Some("NV12"));
+
+        match buffer.set_pixel(1, 1, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[3], 64);
+        assert_eq!(buffer.data[4], 127);
+        assert_eq!(buffer.data[5], 64);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            1, 2, 3, 4,  // Y, y = 0
+            5, 6, 7, 8,  // Y, y = 1
+            9, 10, 11, 12,  // Y, y = 2
+            13, 14, 15, 16,  // Y, y = 3
+            20, 21, 22, 23,  // UV, y = 0, 1
+            24, 25, 26, 27,  // UV, y = 2, 3
+        ];
+        let buffer = YUV422piPixelBuffer::new_with_data(4, 4, data, None, Some("NV12")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 1, cb: 20, cr: 21 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 6, cb: 20, cr: 21 })
+        }
+
+        match buffer.get_pixel(2, 3) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 15, cb: 26, cr: 27 })
+        }
+
+        match buffer.get_pixel(0, 4) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod nv21 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUV422piPixelBuffer::new(2, 2, None, Some("NV21"));
+        assert_eq!(buffer.data.len(), 6);
+        assert_eq!(buffer.data[0], 0);
+        assert_eq!(buffer.data[1], 0);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2);
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YUV422piPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            None, Some("NV21")
+        );
+        assert_eq!(buffer.data.len(), 6);
+        for x in 0..4 {
+            assert_eq!(buffer.data[x], 64, "Y, x: {}", x);
+        }
+        assert_eq!(buffer.data[4], 128);
+        assert_eq!(buffer.data[5], 255);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV422piPixelBuffer::new(2, 2, None, Some("NV21"));
+
+        match buffer.set_pixel(1, 1, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        assert_eq!(buffer.data[3], 64);
+        assert_eq!(buffer.data[4], 64);
+        assert_eq!(buffer.data[5], 127);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            1, 2, 0, 0,  // Y, y = 0
+            3, 4, 0, 0,  // Y, y = 1
+            5, 6, 0, 0,  // VU, y = 0, 1
+        ];
+        let buffer = YUV422piPixelBuffer::new_with_data(2, 2, data, Some(4), Some("NV21")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 1, cb: 6, cr: 5 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 4, cb: 6, cr: 5 })
+        }
+
+        match buffer.get_pixel(2, 0) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod nv16 {
+    use super::*;
+
+    #[test]
+    fn odd_stride() {
+        let mut buffer = YUV422piPixelBuffer::new(3, 1, Some(3), Some("NV16"));
+        assert_eq!(buffer.data.len(), 3 + 4);
+
+        match buffer.set_pixel(2, 0, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        assert_eq!(buffer.data[5], 255);
+        assert_eq!(buffer.data[6], 128);
+    }
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUV422piPixelBuffer::new(2, 2, None, Some("NV16"));
+        assert_eq!(buffer.data.len(), 8);
+        assert_eq!(buffer.stride, 2);
+        assert_eq!(buffer.get_stride(), 2);
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YUV422piPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            Some(4), Some("NV16")
+        );
+
+        assert_eq!(buffer.data.len(), 16);
+        for y in 0..2 {
+            assert_eq!(buffer.data[y * 4 + 0], 64, "Y, x: {}, y: {}", 0, y);
+            assert_eq!(buffer.data[y * 4 + 1], 64, "Y, x: {}, y: {}", 1, y);
+            assert_eq!(buffer.data[y * 4 + 2], 0, "Y padding, y: {}", y);
+            assert_eq!(buffer.data[8 + y * 4 + 0], 255, "U, y: {}", y);
+            assert_eq!(buffer.data[8 + y * 4 + 1], 128, "V, y: {}", y);
+            assert_eq!(buffer.data[8 + y * 4 + 2], 0, "UV padding, y: {}", y);
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV422piPixelBuffer::new(2, 2, None, Some("NV16"));
+
+        match buffer.set_pixel(1, 1, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        assert_eq!(buffer.data[3], 64);
+        assert_eq!(buffer.data[4], 0);
+        assert_eq!(buffer.data[5], 0);
+        assert_eq!(buffer.data[6], 127);
+        assert_eq!(buffer.data[7], 64);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            1, 2,  // Y, y = 0
+            3, 4,  // Y, y = 1
+            5, 6,  // UV, y = 0
+            7, 8,  // UV, y = 1
+        ];
+        let buffer = YUV422piPixelBuffer::new_with_data(2, 2, data, None, Some("NV16")).unwrap();
+
+        match buffer.get_pixel(1, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 2, cb: 5, cr: 6 })
+        }
+
+        match buffer.get_pixel(0, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 3, cb: 7, cr: 8 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod nv61 {
+    use super::*;
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let buffer = YUV422piPixelBuffer::new_with_data(2, 2, data, None, Some("NV61")).unwrap();
+
+        match buffer.get_pixel(0, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 3, cb: 8, cr: 7 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod nv24 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUV422piPixelBuffer::new(3, 2, None, Some("NV24"));
+        assert_eq!(buffer.data.len(), 3 * 2 + 6 * 2);
+        assert_eq!(buffer.stride, 3);
+        assert_eq!(buffer.get_stride(), 3);
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YUV422piPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            None, Some("NV24")
+        );
+        assert_eq!(buffer.data.len(), 12);
+        for x in 0..4 {
+            assert_eq!(buffer.data[x], 64, "Y, x: {}", x);
+        }
+        for x in (4..12).step_by(2) {
+            assert_eq!(buffer.data[x], 255, "U, x: {}", x);
+            assert_eq!(buffer.data[x + 1], 128, "V, x: {}", x);
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV422piPixelBuffer::new(2, 2, None, Some("NV24"));
+
+        match buffer.set_pixel(1, 1, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        assert_eq!(buffer.data[3], 64);
+        assert_eq!(buffer.data[10], 255);
+        assert_eq!(buffer.data[11], 128);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            1, 2,  // Y, y = 0
+            3, 4,  // Y, y = 1
+            5, 6, 7, 8,  // UV, y = 0
+            9, 10, 11, 12,  // UV, y = 1
+        ];
+        let buffer = YUV422piPixelBuffer::new_with_data(2, 2, data, None, Some("NV24")).unwrap();
+
+        match buffer.get_pixel(1, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 2, cb: 7, cr: 8 })
+        }
+
+        match buffer.get_pixel(0, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 3, cb: 9, cr: 10 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod nv42 {
+    use super::*;
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let buffer = YUV422piPixelBuffer::new_with_data(2, 2, data, None, Some("NV42")).unwrap();
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 4, cb: 12, cr: 11 })
+        }
+    }
+}
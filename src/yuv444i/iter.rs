@@ -23,14 +23,15 @@ impl<'a> Iterator for YUV444iPixelIterator<'a> {
             return None;
         }
 
-        let color = self.base.get_pixel(self.x, self.y).unwrap();
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
         self.x += 1;
         if self.x >= self.base.width {
             self.x = 0;
             self.y += 1;
         }
 
-        Some((self.x, self.y, color))
+        Some((x, y, color))
     }
 
     fn count(self) -> usize {
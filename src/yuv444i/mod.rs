@@ -1,10 +1,13 @@
-//! This module describes an interleaved YUV pixel buffer with full resolution
+//! This module describes an interleaved YUV pixel buffer with full resolution, optionally
+//! with an alpha or padding byte per pixel (`AYUV`, `XYUV`)
 
 use crate::*;
 pub use grapho_color::DigitalYCbCrColor;
 pub use crate::yuv422i::YUVComponent;
+use crate::rgb::RGBPixelBuffer;
+use crate::yuvap::YUVApPixelBuffer;
 
-/// RGB Pixel buffer without alpha channel
+/// YUV Pixel buffer with optional alpha channel or padding byte
 #[derive(Debug, PartialEq)]
 pub struct YUV444iPixelBuffer<'a> {
     width: usize,
@@ -21,9 +24,93 @@ impl<'a> YUV444iPixelBuffer<'a> {
             "VUY" => vec![YUVComponent::V, YUVComponent::U, YUVComponent::Y],
             "YVU" => vec![YUVComponent::Y, YUVComponent::V, YUVComponent::U],
             "UVY" => vec![YUVComponent::U, YUVComponent::V, YUVComponent::Y],
+            "AYUV" | "VUYA" => vec![YUVComponent::V, YUVComponent::U, YUVComponent::Y, YUVComponent::Alpha],
+            "XYUV" | "VUYX" => vec![YUVComponent::V, YUVComponent::U, YUVComponent::Y, YUVComponent::Ignore],
             "YUV" | "YUV444" | "YUV 4:4:4" | _ => vec![YUVComponent::Y, YUVComponent::U, YUVComponent::V],
         }       
     }
+
+    /// Byte value of a component for background fills, alpha is written opaque and padding bytes as zero
+    fn component_value(color: DigitalYCbCrColor, component: YUVComponent) -> u8 {
+        match component {
+            YUVComponent::Y => color.y,
+            YUVComponent::U => color.cb,
+            YUVComponent::V => color.cr,
+            YUVComponent::Alpha => 255,
+            YUVComponent::Ignore => 0
+        }
+    }
+
+    /// Whether the buffer format stores an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        self.component_order.contains(&YUVComponent::Alpha)
+    }
+
+    /// Offset of the alpha byte of the pixel at `x`, `y`, `None` for formats without alpha
+    fn alpha_offset(&self, x: usize, y: usize) -> Option<usize> {
+        self.component_order.iter()
+            .position(|c| *c == YUVComponent::Alpha)
+            .map(|i| x * self.component_order.len() + y * self.stride + i)
+    }
+
+    /// Get the alpha value of a pixel
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either the alpha value, `255` for formats without
+    /// an alpha channel, or `PixelBufferError::RequestOutOfBounds`
+    pub fn get_alpha(&self, x: usize, y: usize) -> Result<u8, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        Ok(self.alpha_offset(x, y).map_or(255, |offset| self.data[offset]))
+    }
+
+    /// Set the alpha value of a pixel, this is a no-op for formats without an alpha channel
+    ///
+    /// `set_pixel` leaves the alpha value of a pixel untouched
+    pub fn set_alpha(&mut self, x: usize, y: usize, alpha: u8) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        if let Some(offset) = self.alpha_offset(x, y) {
+            self.data[offset] = alpha;
+        }
+
+        Ok(())
+    }
+
+    /// Convert a RGB buffer to an `AYUV` buffer, keeping the alpha channel of `buffer`
+    ///
+    /// # Returns
+    ///
+    /// A new `AYUV` buffer, pixels of buffers without alpha channel are opaque
+    pub fn from_rgba(buffer: RGBPixelBuffer<'_>) -> Self {
+        let mut result = YUV444iPixelBuffer::new(buffer.get_width(), buffer.get_height(), None, Some("AYUV"));
+        for (x, y, color) in buffer {
+            result.set_pixel(x, y, color.into()).unwrap();
+            result.set_alpha(x, y, color.a).unwrap();
+        }
+
+        result
+    }
+
+    /// Convert a planar YUV buffer with alpha plane to an `AYUV` buffer
+    ///
+    /// # Returns
+    ///
+    /// A new `AYUV` buffer with the alpha values of `buffer`
+    pub fn from_yuva(buffer: YUVApPixelBuffer<'_>) -> Self {
+        let mut result = YUV444iPixelBuffer::new(buffer.get_width(), buffer.get_height(), None, Some("AYUV"));
+        for (x, y, color) in buffer {
+            result.set_pixel(x, y, color.into()).unwrap();
+            result.set_alpha(x, y, color.a).unwrap();
+        }
+
+        result
+    }
 }
 
 impl<'a> PixelBuffer<'a> for YUV444iPixelBuffer<'a> {
@@ -45,24 +132,35 @@ impl<'a> PixelBuffer<'a> for YUV444iPixelBuffer<'a> {
     /// * `YVU`
     /// * `VUY`
     /// * `UVY`
+    /// * `AYUV`, `VUYA`, 4 bytes per pixel with alpha
+    /// * `XYUV`, `VUYX`, 4 bytes per pixel with padding
     /// 
     /// # Returns
     /// 
-    /// This returns a new instance of `YUV444iPixelBuffer` with it's contents set to zero
+    /// This returns a new instance of `YUV444iPixelBuffer` with it's contents set to zero,
+    /// the alpha channel of `AYUV` and `VUYA` is set opaque
 
     fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
         let f = fourcc.unwrap_or("YUV444");
         let component_order = YUV444iPixelBuffer::decode_component_order(f);
-        let line_width = stride.unwrap_or(width * 3);
+        let line_width = stride.unwrap_or(width * component_order.len());
 
-        YUV444iPixelBuffer {
+        let mut buffer = YUV444iPixelBuffer {
             width,
             height,
             data: vec![0; line_width * height],
             stride: line_width,
             fourcc: f,
             component_order
+        };
+
+        for y in 0..height {
+            for x in 0..width {
+                buffer.set_alpha(x, y, 255).unwrap();
+            }
         }
+
+        buffer
     }
 
     /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
@@ -82,6 +180,8 @@ impl<'a> PixelBuffer<'a> for YUV444iPixelBuffer<'a> {
     /// * `YVU`
     /// * `VUY`
     /// * `UVY`
+    /// * `AYUV`, `VUYA`, 4 bytes per pixel with alpha
+    /// * `XYUV`, `VUYX`, 4 bytes per pixel with padding
     /// 
     /// # Returns
     /// 
@@ -92,7 +192,9 @@ impl<'a> PixelBuffer<'a> for YUV444iPixelBuffer<'a> {
         let f = fourcc.unwrap_or("YUV444");
         let component_order = YUV444iPixelBuffer::decode_component_order(f);
 
-        if data.len() < stride.unwrap_or(width * 3) * height {
+        let line_width = stride.unwrap_or(width * component_order.len());
+
+        if data.len() < line_width * height {
             return Err(PixelBufferError::BufferTooSmall);
         }
 
@@ -102,7 +204,7 @@ impl<'a> PixelBuffer<'a> for YUV444iPixelBuffer<'a> {
                 width,
                 height,
                 data,
-                stride: stride.unwrap_or(width * 3),
+                stride: line_width,
                 fourcc: f,
                 component_order
            }
@@ -126,6 +228,8 @@ impl<'a> PixelBuffer<'a> for YUV444iPixelBuffer<'a> {
     /// * `YVU`
     /// * `VUY`
     /// * `UVY`
+    /// * `AYUV`, `VUYA`, 4 bytes per pixel with alpha
+    /// * `XYUV`, `VUYX`, 4 bytes per pixel with padding
     /// 
     /// # Returns
     /// 
@@ -135,19 +239,17 @@ impl<'a> PixelBuffer<'a> for YUV444iPixelBuffer<'a> {
     fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
         let f = fourcc.unwrap_or("YUV444");
         let component_order = YUV444iPixelBuffer::decode_component_order(f);
-        let rep: [u8; 3] = color.into();
-        let line_width = stride.unwrap_or(width * component_order.len());
+        let pixel_size = component_order.len();
+        let line_width = stride.unwrap_or(width * pixel_size);
         let data:Vec<u8>;
 
-        let representation = vec![
-            rep[component_order[0] as usize],
-            rep[component_order[1] as usize],
-            rep[component_order[2] as usize],
-        ];
+        let representation: Vec<u8> = component_order.iter()
+            .map(|c| YUV444iPixelBuffer::component_value(color, *c))
+            .collect();
  
-        if line_width > width * 3 {
+        if line_width > width * pixel_size {
             let mut line = representation.repeat(width);
-            line.extend([0].repeat(line_width - width * 3));
+            line.extend([0].repeat(line_width - width * pixel_size));
             data = line.repeat(height);
         } else {
             data = representation.repeat(width * height);
@@ -180,29 +282,34 @@ impl<'a> PixelBuffer<'a> for YUV444iPixelBuffer<'a> {
     }
 
     fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
-        if (x >= self.width) || (y >= self.width) {
+        if (x >= self.width) || (y >= self.height) {
             return Err(PixelBufferError::RequestOutOfBounds);
         }
 
         let start = x * self.component_order.len() + y * self.stride;
-        let repr: [u8; 3] = color.into();
 
         for i in 0..self.component_order.len() {
-            self.data[start + i] = repr[self.component_order[i] as usize];
+            match self.component_order[i] {
+                YUVComponent::Alpha | YUVComponent::Ignore => (),
+                component => self.data[start + i] = YUV444iPixelBuffer::component_value(color, component)
+            }
         }
  
         Ok(())
     }
 
     fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
-        if (x >= self.width) || (y >= self.width) {
+        if (x >= self.width) || (y >= self.height) {
             return Err(PixelBufferError::RequestOutOfBounds);
         }
 
-        let start = x * 3 + y * self.stride;
+        let start = x * self.component_order.len() + y * self.stride;
         let mut color: [u8; 3] = [0, 0, 0];
         for i in 0..self.component_order.len() {
-            color[self.component_order[i] as usize] = self.data[start + i];
+            match self.component_order[i] {
+                YUVComponent::Alpha | YUVComponent::Ignore => (),
+                component => color[component as usize] = self.data[start + i]
+            }
         }
         
         Ok(DigitalYCbCrColor::from(color))
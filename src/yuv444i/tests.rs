@@ -4,7 +4,10 @@
 
 pub use crate::{ PixelBuffer, PixelBufferError };
 pub use crate::yuv444i::YUV444iPixelBuffer;
-pub use grapho_color::DigitalYCbCrColor;
+pub use grapho_color::{ DigitalYCbCrColor, DigitalRGBAColor };
+pub use crate::color::DigitalYCbCrAColor;
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::yuvap::YUVApPixelBuffer;
 
 #[cfg(test)]
 mod iter {
@@ -19,6 +22,18 @@ mod iter {
             assert_eq!(pixel.2, color);
         }
     }
+
+    #[test]
+    fn iter_coordinates() {
+        let color = DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 };
+        let mut buffer = YUV444iPixelBuffer::new(2, 2, None, Some("YUV444"));
+        buffer.set_pixel(1, 0, color).unwrap();
+
+        let pixels: Vec<_> = buffer.into_iter().collect();
+        let coordinates: Vec<(usize, usize)> = pixels.iter().map(|pixel| (pixel.0, pixel.1)).collect();
+        assert_eq!(coordinates, vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+        assert_eq!(pixels[1].2, color);
+    }
 }
 
 #[cfg(test)]
@@ -129,6 +144,29 @@ mod yuv {
             Ok(_color) => assert!(false)
         }
     }
+
+    #[test]
+    fn non_square_bounds() {
+        let mut wide = YUV444iPixelBuffer::new(4, 2, None, Some("YUV444"));
+        match wide.set_pixel(0, 3, DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+        match wide.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+
+        let mut tall = YUV444iPixelBuffer::new(2, 4, None, Some("YUV444"));
+        match tall.set_pixel(1, 3, DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+        match tall.get_pixel(1, 3) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -460,3 +498,218 @@ mod uvy {
         }
     }
 }
+
+#[cfg(test)]
+mod vuya {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUV444iPixelBuffer::new(2, 2, None, Some("VUYA"));
+        assert_eq!(buffer.data.len(), 16);
+        assert_eq!(buffer.stride, 2 * 4);
+        assert_eq!(buffer.get_stride(), 2 * 4);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 15];
+        match YUV444iPixelBuffer::new_with_data(2, 2, data, None, Some("VUYA")) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YUV444iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 },
+            Some(12), Some("VUYA")
+        );
+
+        assert_eq!(buffer.data.len(), 24);
+        for y in 0..2 {
+            for x in 0..2 {
+                assert_eq!(buffer.data[x * 4 + y * 12 + 0], 0, "x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[x * 4 + y * 12 + 1], 64, "x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[x * 4 + y * 12 + 2], 255, "x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[x * 4 + y * 12 + 3], 255, "x: {}, y: {}", x, y);
+            }
+            for x in 8..12 {
+                assert_eq!(buffer.data[x + y * 12], 0, "x: {}, y: {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV444iPixelBuffer::new(2, 2, None, Some("VUYA"));
+
+        match buffer.set_pixel(1, 1, DigitalYCbCrColor{ y: 255, cb: 64, cr: 1 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCbCrColor{ y: 255, cb: 64, cr: 1 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[12], 1);
+        assert_eq!(buffer.data[13], 64);
+        assert_eq!(buffer.data[14], 255);
+        // new buffers are opaque, set_pixel leaves alpha untouched
+        assert_eq!(buffer.data[15], 255);
+        assert_eq!(buffer.data[3], 255);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![2, 1, 0, 128, 6, 5, 4, 255, 10, 9, 8, 0, 14, 13, 12, 64];
+        let buffer = YUV444iPixelBuffer::new_with_data(2, 2, data, None, Some("VUYA")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 0, cb: 1, cr: 2 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 12, cb: 13, cr: 14 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn alpha() {
+        let mut buffer = YUV444iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 },
+            None, Some("AYUV")
+        );
+        assert_eq!(buffer.get_alpha(1, 0), Ok(255));
+
+        match buffer.set_alpha(1, 0, 32) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+        assert_eq!(buffer.data[7], 32);
+        assert_eq!(buffer.get_alpha(1, 0), Ok(32));
+        assert_eq!(buffer.get_pixel(1, 0), Ok(DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 }));
+
+        buffer.set_pixel(1, 0, DigitalYCbCrColor{ y: 16, cb: 128, cr: 128 }).unwrap();
+        assert_eq!(buffer.get_alpha(1, 0), Ok(32));
+
+        match buffer.set_alpha(0, 2, 32) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod vuyx {
+    use super::*;
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YUV444iPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 255, cb: 64, cr: 1 },
+            None, Some("XYUV")
+        );
+        assert_eq!(buffer.data.len(), 16);
+        for x in (0..16).step_by(4) {
+            assert_eq!(buffer.data[x + 0], 1);
+            assert_eq!(buffer.data[x + 1], 64);
+            assert_eq!(buffer.data[x + 2], 255);
+            assert_eq!(buffer.data[x + 3], 0);
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV444iPixelBuffer::new_with_data(1, 1, vec![0, 0, 0, 42], None, Some("VUYX")).unwrap();
+
+        match buffer.set_pixel(0, 0, DigitalYCbCrColor{ y: 255, cb: 64, cr: 1 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        assert_eq!(buffer.data, vec![1, 64, 255, 42]);
+        assert_eq!(buffer.get_pixel(0, 0), Ok(DigitalYCbCrColor{ y: 255, cb: 64, cr: 1 }));
+        assert_eq!(buffer.get_alpha(0, 0), Ok(255));
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgba_roundtrip() {
+        let mut rgb = RGBPixelBuffer::new(2, 1, None, Some("RGBA"));
+        rgb.set_pixel(0, 0, DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 0 }).unwrap();
+        rgb.set_pixel(1, 0, DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 200 }).unwrap();
+
+        let ayuv = YUV444iPixelBuffer::from_rgba(rgb);
+        assert_eq!(ayuv.get_fourcc(), "AYUV");
+        assert_eq!(ayuv.get_alpha(0, 0), Ok(0));
+        assert_eq!(ayuv.get_alpha(1, 0), Ok(200));
+
+        let back: RGBPixelBuffer = ayuv.into();
+        assert_eq!(back.get_fourcc(), "RGBA");
+        assert_eq!(back.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 0 });
+        assert_eq!(back.get_pixel(1, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 200 });
+    }
+
+    #[test]
+    fn rgb_opaque() {
+        let rgb = RGBPixelBuffer::new(2, 1, None, None);
+
+        let yuv: YUV444iPixelBuffer = rgb.into();
+        assert_eq!(yuv.get_fourcc(), "YUV444");
+
+        let back: RGBPixelBuffer = yuv.into();
+        assert_eq!(back.get_fourcc(), "RGB");
+
+        // the conversion keeps its 3 byte output for sources with alpha
+        let rgba = RGBPixelBuffer::new(2, 1, None, Some("RGBA"));
+        let yuv: YUV444iPixelBuffer = rgba.into();
+        assert_eq!(yuv.get_fourcc(), "YUV444");
+        assert_eq!(yuv.get_stride(), 6);
+    }
+
+    #[test]
+    fn set_pixel_opaque() {
+        let mut ayuv = YUV444iPixelBuffer::new(1, 1, None, Some("AYUV"));
+        ayuv.set_pixel(0, 0, DigitalYCbCrColor{ y: 235, cb: 128, cr: 128 }).unwrap();
+
+        let rgb: RGBPixelBuffer = ayuv.into();
+        assert_eq!(rgb.get_pixel(0, 0).unwrap().a, 255);
+    }
+
+    #[test]
+    fn yuva_roundtrip() {
+        let color = DigitalYCbCrAColor{ y: 235, cb: 64, cr: 200, a: 100 };
+        let mut yuva = YUVApPixelBuffer::new_with_background(2, 2, color, None, Some("YUVA444P"));
+        yuva.set_pixel(1, 1, DigitalYCbCrAColor{ a: 255, ..color }).unwrap();
+
+        let ayuv = YUV444iPixelBuffer::from_yuva(yuva);
+        assert_eq!(ayuv.get_fourcc(), "AYUV");
+        assert_eq!(ayuv.get_pixel(0, 0), Ok(DigitalYCbCrColor{ y: 235, cb: 64, cr: 200 }));
+        assert_eq!(ayuv.get_alpha(0, 0), Ok(100));
+        assert_eq!(ayuv.get_alpha(1, 1), Ok(255));
+
+        let back: YUVApPixelBuffer = ayuv.into();
+        for (x, y, c) in back {
+            let a = if x == 1 && y == 1 { 255 } else { 100 };
+            assert_eq!(c, DigitalYCbCrAColor{ a, ..color }, "x: {}, y: {}", x, y);
+        }
+    }
+}
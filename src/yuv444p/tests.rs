@@ -0,0 +1,181 @@
+//
+// Tests
+//
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::yuv444p::YUV444pPixelBuffer;
+pub use grapho_color::DigitalYCbCrColor;
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCbCrColor{ y: 255, cb: 64, cr: 0 };
+        let buffer = YUV444pPixelBuffer::new_with_background(2, 2, color, None, Some("I444"));
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod i444 {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUV444pPixelBuffer::new(2, 2, None, Some("I444"));
+        assert_eq!(buffer.data.len(), 12);
+        assert_eq!(buffer.data[0], 0);
+        assert_eq!(buffer.data[1], 0);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2);
+    }
+
+    #[test]
+    fn prefilled_buffer() {
+        let data = vec![255, 64, 128, 64, 255, 64, 128, 64, 1, 2, 3, 4];
+        let copy = data.clone();
+        let buffer = YUV444pPixelBuffer::new_with_data(2, 2, data, None, Some("I444")).unwrap();
+        assert_eq!(buffer.data.len(), 12);
+        for x in 0..buffer.data.len() {
+            assert_eq!(copy[x], buffer.data[x]);
+        }
+    }
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YUV444pPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            None, Some("I444")
+        );
+        assert_eq!(buffer.data.len(), 12);
+        for x in 0..4 {
+            assert_eq!(buffer.data[x], 64, "Y, x: {}", x);
+            assert_eq!(buffer.data[4 + x], 255, "U, x: {}", x);
+            assert_eq!(buffer.data[8 + x], 128, "V, x: {}", x);
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YUV444pPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            Some(4), Some("I444")
+        );
+
+        assert_eq!(buffer.data.len(), 24);
+        for y in 0..2 {
+            for x in 0..2 {
+                assert_eq!(buffer.data[y * 4 + x], 64, "Y, x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[8 + y * 4 + x], 255, "U, x: {}, y: {}", x, y);
+                assert_eq!(buffer.data[16 + y * 4 + x], 128, "V, x: {}, y: {}", x, y);
+            }
+            for p in 2..4 {
+                assert_eq!(buffer.data[y * 4 + p], 0);
+                assert_eq!(buffer.data[8 + y * 4 + p], 0);
+                assert_eq!(buffer.data[16 + y * 4 + p], 0);
+            }
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV444pPixelBuffer::new(2, 2, None, Some("I444"));
+
+        match buffer.set_pixel(1, 1, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data[3], 64);
+        assert_eq!(buffer.data[7], 255);
+        assert_eq!(buffer.data[11], 128);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            1, 2, 0, 0,  // Y, y = 0
+            3, 4, 0, 0,  // Y, y = 1
+            5, 6, 0, 0,  // U, y = 0
+            7, 8, 0, 0,  // U, y = 1
+            9, 10, 0, 0,  // V, y = 0
+            11, 12, 0, 0,  // V, y = 1
+        ];
+        let buffer = YUV444pPixelBuffer::new_with_data(2, 2, data, Some(4), Some("I444")).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 1, cb: 5, cr: 9 })
+        }
+
+        match buffer.get_pixel(1, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 4, cb: 8, cr: 12 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod yv24 {
+    use super::*;
+
+    #[test]
+    fn bg_buffer() {
+        let buffer = YUV444pPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            None, Some("YV24")
+        );
+        assert_eq!(buffer.data.len(), 12);
+        for x in 0..4 {
+            assert_eq!(buffer.data[x], 64, "Y, x: {}", x);
+            assert_eq!(buffer.data[4 + x], 128, "V, x: {}", x);
+            assert_eq!(buffer.data[8 + x], 255, "U, x: {}", x);
+        }
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUV444pPixelBuffer::new(2, 2, None, Some("YV24"));
+
+        match buffer.set_pixel(1, 1, DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        assert_eq!(buffer.data[3], 64);
+        assert_eq!(buffer.data[7], 128);
+        assert_eq!(buffer.data[11], 255);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let buffer = YUV444pPixelBuffer::new_with_data(2, 2, data, None, Some("YV24")).unwrap();
+
+        match buffer.get_pixel(1, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrColor{ y: 2, cb: 10, cr: 6 })
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Create iterator for YUVApPixelBuffer
+
+use crate::{ Pixel, PixelBuffer };
+use super::YUVApPixelBuffer;
+use crate::color::DigitalYCbCrAColor;
+
+
+/// Pixel iterator for `YUVApPixelBuffer`
+/// 
+/// Items it will generate are of type `Pixel<DigitalYCbCrAColor>`
+#[derive(Debug, PartialEq)]
+pub struct YUVApPixelIterator<'a> {
+    base: YUVApPixelBuffer<'a>,
+    x: usize,
+    y: usize
+}
+
+impl<'a> Iterator for YUVApPixelIterator<'a> {
+    type Item = Pixel<DigitalYCbCrAColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.base.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let color = self.base.get_pixel(x, y).unwrap();
+        self.x += 1;
+        if self.x >= self.base.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, color))
+    }
+
+    fn count(self) -> usize {
+        self.base.width * self.base.height
+    }
+}
+
+impl<'a> IntoIterator for YUVApPixelBuffer<'a> {
+    type Item = Pixel<DigitalYCbCrAColor>;
+    type IntoIter = YUVApPixelIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        YUVApPixelIterator {
+            base: self,
+            x: 0,
+            y: 0
+        }
+    }
+}
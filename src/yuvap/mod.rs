@@ -0,0 +1,249 @@
+//! This module describes a planar YUV pixel buffer with a full resolution alpha plane, with
+//! 4:2:0 (`YUVA420P`) or 4:4:4 (`YUVA444P`) color subsampling
+//!
+//! The planes follow each other in the order Y, U, V, A. The Y and alpha planes use the
+//! stride of the buffer, the chroma planes half of it for 4:2:0.
+
+use crate::*;
+pub use crate::color::DigitalYCbCrAColor;
+
+/// YUV Pixel buffer with alpha channel, optionally 2x2 subsampled color
+#[derive(Debug, PartialEq)]
+pub struct YUVApPixelBuffer<'a> {
+    width: usize,
+    height: usize,
+    stride: usize,
+    fourcc: &'a str,
+    subsampling: usize,
+    data: Vec<u8>
+}
+
+impl<'a> YUVApPixelBuffer<'a> {
+    /// Horizontal and vertical chroma subsampling factor
+    fn decode_subsampling(fourcc:&'a str) -> usize {
+        match fourcc {
+            "YUVA444P" => 1,
+            "YUVA420P" | _ => 2,
+        }
+    }
+
+    /// Line width of the chroma planes
+    fn chroma_stride(stride: usize, subsampling: usize) -> usize {
+        (stride + subsampling - 1) / subsampling
+    }
+
+    /// Number of lines of the chroma planes
+    fn chroma_height(height: usize, subsampling: usize) -> usize {
+        (height + subsampling - 1) / subsampling
+    }
+
+    /// Number of bytes needed for a buffer with given stride and height
+    fn buffer_size(stride: usize, height: usize, subsampling: usize) -> usize {
+        stride * height * 2
+            + YUVApPixelBuffer::chroma_stride(stride, subsampling) * YUVApPixelBuffer::chroma_height(height, subsampling) * 2
+    }
+
+    /// Offsets of the Y sample, both chroma samples and the alpha sample of the pixel at `x`, `y`
+    fn offsets(&self, x: usize, y: usize) -> (usize, usize, usize, usize) {
+        let chroma_stride = YUVApPixelBuffer::chroma_stride(self.stride, self.subsampling);
+        let chroma_size = chroma_stride * YUVApPixelBuffer::chroma_height(self.height, self.subsampling);
+        let start = x + y * self.stride;
+        let u = self.stride * self.height + x / self.subsampling + (y / self.subsampling) * chroma_stride;
+        let v = u + chroma_size;
+        let a = self.stride * self.height + chroma_size * 2 + start;
+
+        (start, u, v, a)
+    }
+}
+
+
+impl<'a> PixelBuffer<'a> for YUVApPixelBuffer<'a> {
+    type ColorType = DigitalYCbCrAColor;
+
+    /// Create a new pixel buffer with given dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y and alpha planes if it differs from the
+    ///   default: `width`, the chroma planes use half of it for `YUVA420P`
+    /// * `fourcc` - optional, data representation format
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YUVA420P` (default), 2x2 subsampled
+    /// * `YUVA444P`, no subsampling
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YUVApPixelBuffer` with it's contents set to zero
+    fn new(width: usize, height: usize, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YUVA420P");
+        let subsampling = YUVApPixelBuffer::decode_subsampling(f);
+        let line_width = stride.unwrap_or(width);
+
+        YUVApPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data: vec![0; YUVApPixelBuffer::buffer_size(line_width, height, subsampling)],
+            fourcc: f,
+            subsampling
+        }
+    }
+
+    /// Create a new pixel buffer with given dimensions from a `Vec<u8>`
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y and alpha planes if it differs from the
+    ///   default: `width`, the chroma planes use half of it for `YUVA420P`
+    /// * `fourcc` - optional, data representation format
+    /// * `data` - the data to consume
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YUVA420P` (default), 2x2 subsampled
+    /// * `YUVA444P`, no subsampling
+    ///
+    /// # Returns
+    ///
+    /// This returns a `Result` with either a new instance of `YUVApPixelBuffer`
+    /// or `PixelBufferError::BufferTooSmall` if the buffer is too small for
+    /// the requested dimensions
+    fn new_with_data(width: usize, height: usize, data: Vec<u8>, stride: Option<usize>, fourcc: Option<&'a str>) -> Result<Self, PixelBufferError> {
+        let f = fourcc.unwrap_or("YUVA420P");
+        let subsampling = YUVApPixelBuffer::decode_subsampling(f);
+        let line_width = stride.unwrap_or(width);
+
+        if data.len() < YUVApPixelBuffer::buffer_size(line_width, height, subsampling) {
+            return Err(PixelBufferError::BufferTooSmall);
+        }
+
+        Ok(
+            YUVApPixelBuffer {
+                width,
+                height,
+                stride: line_width,
+                data,
+                fourcc: f,
+                subsampling
+           }
+        )
+    }
+
+    /// Create a new pixel buffer with given dimensions and fill color
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the buffer
+    /// * `height` - The height of the buffer
+    /// * `stride` - optional, the line-width of the Y and alpha planes if it differs from the
+    ///   default: `width`, the chroma planes use half of it for `YUVA420P`
+    /// * `fourcc` - optional, data representation format
+    /// * `color` - fill color to use
+    ///
+    /// # Defined fourcc codes
+    ///
+    /// * `YUVA420P` (default), 2x2 subsampled
+    /// * `YUVA444P`, no subsampling
+    ///
+    /// # Returns
+    ///
+    /// This returns a new instance of `YUVApPixelBuffer` with it's contents set to the
+    /// defined color. If stride is bigger than needed width the padding is filled with
+    /// zeroes.
+    fn new_with_background(width: usize, height: usize, color: Self::ColorType, stride: Option<usize>, fourcc: Option<&'a str>) -> Self {
+        let f = fourcc.unwrap_or("YUVA420P");
+        let subsampling = YUVApPixelBuffer::decode_subsampling(f);
+        let line_width = stride.unwrap_or(width);
+        let chroma_line_width = YUVApPixelBuffer::chroma_stride(line_width, subsampling);
+        let chroma_width = (width + subsampling - 1) / subsampling;
+        let chroma_height = YUVApPixelBuffer::chroma_height(height, subsampling);
+
+        let mut data: Vec<u8> = Vec::with_capacity(YUVApPixelBuffer::buffer_size(line_width, height, subsampling));
+
+        let mut y = [color.y].repeat(width);
+        let mut u = [color.cb].repeat(chroma_width);
+        let mut v = [color.cr].repeat(chroma_width);
+        let mut a = [color.a].repeat(width);
+
+        if line_width > width {
+            y.extend([0].repeat(line_width - width));
+            a.extend([0].repeat(line_width - width));
+        }
+        if chroma_line_width > chroma_width {
+            u.extend([0].repeat(chroma_line_width - chroma_width));
+            v.extend([0].repeat(chroma_line_width - chroma_width));
+        }
+
+        data.extend(y.repeat(height));
+        data.extend(u.repeat(chroma_height));
+        data.extend(v.repeat(chroma_height));
+        data.extend(a.repeat(height));
+
+        YUVApPixelBuffer {
+            width,
+            height,
+            stride: line_width,
+            data,
+            fourcc: f,
+            subsampling
+        }
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn get_fourcc(&self) -> &'a str {
+        self.fourcc
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: Self::ColorType) -> Result<(), PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let (start, u, v, a) = self.offsets(x, y);
+
+        self.data[start] = color.y;
+        self.data[a] = color.a;
+        // the first pixel of a chroma block sets the chroma, the others average into it
+        if x % self.subsampling == 0 && y % self.subsampling == 0 {
+            self.data[u] = color.cb;
+            self.data[v] = color.cr;
+        } else {
+            self.data[u] = ((self.data[u] as u16 + color.cb as u16) >> 1) as u8;
+            self.data[v] = ((self.data[v] as u16 + color.cr as u16) >> 1) as u8;
+        }
+
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Result<Self::ColorType, PixelBufferError> {
+        if (x >= self.width) || (y >= self.height) {
+            return Err(PixelBufferError::RequestOutOfBounds);
+        }
+
+        let (start, u, v, a) = self.offsets(x, y);
+
+        Ok(DigitalYCbCrAColor {
+            y: self.data[start], cb: self.data[u], cr: self.data[v], a: self.data[a]
+        })
+    }
+}
+
+pub mod iter;
+mod tests;
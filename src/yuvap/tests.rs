@@ -0,0 +1,211 @@
+//
+// Tests
+//
+
+pub use crate::{ PixelBuffer, PixelBufferError };
+pub use crate::yuvap::YUVApPixelBuffer;
+pub use crate::yuv420p::YUV420pPixelBuffer;
+pub use crate::rgb::RGBPixelBuffer;
+pub use crate::palette::PalettePixelBuffer;
+pub use crate::color::DigitalYCbCrAColor;
+pub use grapho_color::{ DigitalRGBAColor, DigitalYCbCrColor };
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_buffer() {
+        let color = DigitalYCbCrAColor{ y: 255, cb: 64, cr: 0, a: 128 };
+        let buffer = YUVApPixelBuffer::new_with_background(2, 2, color, None, None);
+
+        for pixel in buffer {
+            assert_eq!(pixel.2, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod yuva420p {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUVApPixelBuffer::new(2, 2, None, None);
+        assert_eq!(buffer.data.len(), 10);
+        assert_eq!(buffer.width, 2);
+        assert_eq!(buffer.height, 2);
+        assert_eq!(buffer.stride, 2);
+        assert_eq!(buffer.get_width(), 2);
+        assert_eq!(buffer.get_height(), 2);
+        assert_eq!(buffer.get_stride(), 2);
+        assert_eq!(buffer.get_fourcc(), "YUVA420P");
+    }
+
+    #[test]
+    fn odd_size_buffer() {
+        let buffer = YUVApPixelBuffer::new(3, 3, None, None);
+        assert_eq!(buffer.data.len(), 3 * 3 * 2 + 2 * 2 * 2);
+    }
+
+    #[test]
+    fn prefilled_buffer_too_small() {
+        let data = vec![0; 9];
+        match YUVApPixelBuffer::new_with_data(2, 2, data, None, None) {
+            Err(error) => assert_eq!(error, PixelBufferError::BufferTooSmall),
+            Ok(_buffer) => assert!(false)
+        }
+    }
+
+    #[test]
+    fn bg_buffer_stride() {
+        let buffer = YUVApPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrAColor{ y: 64, cb: 255, cr: 128, a: 32 },
+            Some(4), None
+        );
+
+        assert_eq!(buffer.data, vec![
+            64, 64, 0, 0,  // Y, y = 0
+            64, 64, 0, 0,  // Y, y = 1
+            255, 0,        // U
+            128, 0,        // V
+            32, 32, 0, 0,  // A, y = 0
+            32, 32, 0, 0,  // A, y = 1
+        ]);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUVApPixelBuffer::new(2, 2, None, None);
+
+        match buffer.set_pixel(1, 1, DigitalYCbCrAColor{ y: 64, cb: 255, cr: 128, a: 32 }) {
+            Err(_error) => assert!(false),
+            _ => assert!(true)
+        }
+
+        match buffer.set_pixel(2, 0, DigitalYCbCrAColor{ y: 64, cb: 255, cr: 128, a: 32 }) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            _ => assert!(false)
+        }
+
+        assert_eq!(buffer.data, vec![0, 0, 0, 64, 127, 64, 0, 0, 0, 32]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![
+            1, 2, 3, 4,      // Y, y = 0
+            5, 6, 7, 8,      // Y, y = 1
+            20, 21,          // U, y = 0, 1
+            30, 31,          // V, y = 0, 1
+            40, 41, 42, 43,  // A, y = 0
+            44, 45, 46, 47,  // A, y = 1
+        ];
+        let buffer = YUVApPixelBuffer::new_with_data(4, 2, data, None, None).unwrap();
+
+        match buffer.get_pixel(0, 0) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrAColor{ y: 1, cb: 20, cr: 30, a: 40 })
+        }
+
+        match buffer.get_pixel(3, 1) {
+            Err(_error) => assert!(false),
+            Ok(color) => assert_eq!(color, DigitalYCbCrAColor{ y: 8, cb: 21, cr: 31, a: 47 })
+        }
+
+        match buffer.get_pixel(0, 2) {
+            Err(error) => assert_eq!(error, PixelBufferError::RequestOutOfBounds),
+            Ok(_color) => assert!(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod yuva444p {
+    use super::*;
+
+    #[test]
+    fn empty_buffer() {
+        let buffer = YUVApPixelBuffer::new(2, 2, None, Some("YUVA444P"));
+        assert_eq!(buffer.data.len(), 16);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut buffer = YUVApPixelBuffer::new(2, 1, None, Some("YUVA444P"));
+        buffer.set_pixel(1, 0, DigitalYCbCrAColor{ y: 64, cb: 255, cr: 128, a: 32 }).unwrap();
+
+        assert_eq!(buffer.data, vec![0, 64, 0, 255, 0, 128, 0, 32]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let buffer = YUVApPixelBuffer::new_with_data(2, 1, data, None, Some("YUVA444P")).unwrap();
+
+        assert_eq!(buffer.get_pixel(1, 0).unwrap(), DigitalYCbCrAColor{ y: 2, cb: 4, cr: 6, a: 8 });
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn rgba_roundtrip() {
+        let mut rgb = RGBPixelBuffer::new(2, 1, None, Some("RGBA"));
+        rgb.set_pixel(0, 0, DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 0 }).unwrap();
+        rgb.set_pixel(1, 0, DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 200 }).unwrap();
+
+        let yuva: YUVApPixelBuffer = rgb.into();
+        assert_eq!(yuva.data[4..6], [0, 200]);
+
+        let back: RGBPixelBuffer = yuva.into();
+        assert_eq!(back.get_fourcc(), "RGBA");
+        assert_eq!(back.get_pixel(0, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 0 });
+        assert_eq!(back.get_pixel(1, 0).unwrap(), DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 200 });
+    }
+
+    #[test]
+    fn rgba_roundtrip_block() {
+        let color = DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 128 };
+        let rgb = RGBPixelBuffer::new_with_background(4, 4, color, None, Some("RGBA"));
+
+        let yuva: YUVApPixelBuffer = rgb.into();
+        assert_eq!(yuva.get_fourcc(), "YUVA420P");
+
+        let back: RGBPixelBuffer = yuva.into();
+        for (x, y, c) in back {
+            assert_eq!(c, color, "x: {}, y: {}", x, y);
+        }
+    }
+
+    #[test]
+    fn yuv() {
+        let yuv = YUV420pPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrColor{ y: 64, cb: 255, cr: 128 },
+            None, None
+        );
+
+        // YUV without alpha is opaque
+        let yuva: YUVApPixelBuffer = yuv.into();
+        for (_x, _y, color) in yuva {
+            assert_eq!(color, DigitalYCbCrAColor{ y: 64, cb: 255, cr: 128, a: 255 });
+        }
+    }
+
+    #[test]
+    fn palette() {
+        let yuva = YUVApPixelBuffer::new_with_background(
+            2, 2,
+            DigitalYCbCrAColor{ y: 235, cb: 128, cr: 128, a: 100 },
+            None, Some("YUVA444P")
+        );
+
+        let palette: PalettePixelBuffer = yuva.into();
+        assert_eq!(palette.get_palette().len(), 1);
+        assert_eq!(palette.get_palette()[0].a, 100);
+    }
+}